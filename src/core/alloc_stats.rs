@@ -0,0 +1,164 @@
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::sync::atomic::{AtomicBool, AtomicIsize, AtomicUsize, Ordering};
+
+/**
+ * A counting global allocator.
+ *
+ * It forwards every request to the system allocator, and
+ * when counting is enabled it also counts the allocations,
+ * the allocated bytes and the peak of live (allocated and
+ * not yet freed) bytes.
+ *
+ * A global allocator can be installed only by the binary:
+ * #[global_allocator]
+ * static GLOBAL: CountingAllocator = CountingAllocator;
+ *
+ * Counting is opt-in (AllocStats::enable()), when disabled
+ * the overhead is a single atomic load per request.
+ *
+ * Note: the counters are global, allocations of all the
+ * threads are counted.
+ */
+pub struct CountingAllocator;
+
+static ENABLED: AtomicBool = AtomicBool::new(false);
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+static BYTES: AtomicUsize = AtomicUsize::new(0);
+/**
+ * Live bytes since the last reset. It can become negative
+ * when memory allocated before the reset is freed.
+ */
+static LIVE_BYTES: AtomicIsize = AtomicIsize::new(0);
+static PEAK_LIVE_BYTES: AtomicIsize = AtomicIsize::new(0);
+
+impl CountingAllocator {
+    fn on_alloc(size: usize) {
+        if !ENABLED.load(Ordering::Relaxed) {
+            return;
+        }
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        BYTES.fetch_add(size, Ordering::Relaxed);
+        let live: isize =
+            LIVE_BYTES.fetch_add(size as isize, Ordering::Relaxed) + size as isize;
+        PEAK_LIVE_BYTES.fetch_max(live, Ordering::Relaxed);
+    }
+
+    fn on_dealloc(size: usize) {
+        if !ENABLED.load(Ordering::Relaxed) {
+            return;
+        }
+        LIVE_BYTES.fetch_sub(size as isize, Ordering::Relaxed);
+    }
+}
+
+/**
+ * GlobalAlloc is an unsafe trait: the implementation must
+ * return memory which fits the layout. It's the case here
+ * since all the requests are forwarded to System.
+ *
+ * Note: the counting code must not allocate, otherwise it
+ * would call itself recursively. Atomics do not allocate.
+ */
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr: *mut u8 = System.alloc(layout);
+        if !ptr.is_null() {
+            CountingAllocator::on_alloc(layout.size());
+        }
+        return ptr;
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr: *mut u8 = System.alloc_zeroed(layout);
+        if !ptr.is_null() {
+            CountingAllocator::on_alloc(layout.size());
+        }
+        return ptr;
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        CountingAllocator::on_dealloc(layout.size());
+    }
+
+    /**
+     * A reallocation is counted as freeing the old block and
+     * allocating the new one (e.g. a Vec growing).
+     */
+    unsafe fn realloc(
+        &self,
+        ptr: *mut u8,
+        layout: Layout,
+        new_size: usize,
+    ) -> *mut u8 {
+        let new_ptr: *mut u8 = System.realloc(ptr, layout, new_size);
+        if !new_ptr.is_null() {
+            CountingAllocator::on_dealloc(layout.size());
+            CountingAllocator::on_alloc(new_size);
+        }
+        return new_ptr;
+    }
+}
+
+/**
+ * A snapshot of the allocation counters.
+ */
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub struct AllocStats {
+    pub allocations: usize,
+    pub bytes: usize,
+    pub peak_live_bytes: usize,
+}
+
+impl AllocStats {
+    /**
+     * Starts counting. It has an effect only when the
+     * CountingAllocator is the global allocator.
+     */
+    pub fn enable() {
+        ENABLED.store(true, Ordering::Relaxed);
+    }
+
+    pub fn is_enabled() -> bool {
+        return ENABLED.load(Ordering::Relaxed);
+    }
+
+    /**
+     * Zeroes the counters, the live bytes are counted from
+     * now on.
+     */
+    pub fn reset() {
+        ALLOCATIONS.store(0, Ordering::Relaxed);
+        BYTES.store(0, Ordering::Relaxed);
+        LIVE_BYTES.store(0, Ordering::Relaxed);
+        PEAK_LIVE_BYTES.store(0, Ordering::Relaxed);
+    }
+
+    /**
+     * @return the counters since the last reset.
+     */
+    pub fn snapshot() -> AllocStats {
+        return AllocStats {
+            allocations: ALLOCATIONS.load(Ordering::Relaxed),
+            bytes: BYTES.load(Ordering::Relaxed),
+            peak_live_bytes: std::cmp::max(
+                PEAK_LIVE_BYTES.load(Ordering::Relaxed),
+                0,
+            ) as usize,
+        };
+    }
+
+    /**
+     * Runs a function and counts its allocations.
+     * @return the function's return value and its counters,
+     * None when counting is disabled.
+     */
+    pub fn measure<R, F: FnOnce() -> R>(func: F) -> (R, Option<AllocStats>) {
+        if !AllocStats::is_enabled() {
+            return (func(), None);
+        }
+        AllocStats::reset();
+        let res: R = func();
+        return (res, Some(AllocStats::snapshot()));
+    }
+}
@@ -0,0 +1,53 @@
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+/**
+ * Counts the assertions checked by the examples.
+ *
+ * The examples assert with pg_assert!/pg_assert_eq! instead
+ * of assert!/assert_eq!. The macros behave the same, but
+ * they also count each checked assertion, so the runner can
+ * report how many assertions an example hit.
+ */
+static ASSERTIONS: AtomicUsize = AtomicUsize::new(0);
+
+pub struct Assertions;
+
+impl Assertions {
+    /**
+     * Called by the macros before the assertion is checked.
+     */
+    pub fn hit() {
+        ASSERTIONS.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /**
+     * @return the number of assertions checked since the
+     * process started. The runner subtracts the count before
+     * an example from the count after it.
+     */
+    pub fn count() -> usize {
+        return ASSERTIONS.load(Ordering::Relaxed);
+    }
+}
+
+/**
+ * assert! replacement which is counted by Assertions.
+ */
+#[macro_export]
+macro_rules! pg_assert {
+    ($($arg:tt)*) => {{
+        $crate::core::assertions::Assertions::hit();
+        assert!($($arg)*);
+    }};
+}
+
+/**
+ * assert_eq! replacement which is counted by Assertions.
+ */
+#[macro_export]
+macro_rules! pg_assert_eq {
+    ($($arg:tt)*) => {{
+        $crate::core::assertions::Assertions::hit();
+        assert_eq!($($arg)*);
+    }};
+}
@@ -0,0 +1,530 @@
+use crate::core::return_code::RC;
+use std::cmp::Ordering;
+
+/**
+ * An arbitrary precision unsigned integer.
+ *
+ * It's the idea of leet_code::add_two_nums (a number is a
+ * list of digits, least significant first, added with a
+ * carry) with larger digits: each limb holds 9 decimal
+ * digits (base 10^9), so
+ * - a limb fits in a u32 and the product of two limbs plus
+ *   a carry fits in a u64.
+ * - Display and parsing are cheap, a limb is 9 decimal
+ *   characters.
+ *
+ * Usage example:
+ * let pi: BigUint = "31415926535".parse::<BigUint>()?;
+ * (&pi * &pi).to_string() == "986960440052517106225"
+ *
+ * The operators (+, -, *, /, %) behave like the ones of the
+ * primitive integers: a subtraction below zero and a
+ * division by zero panic, checked_sub and checked_div_rem
+ * return None instead.
+ */
+#[derive(Clone, PartialEq, Eq, Debug, Default)]
+pub struct BigUint {
+    /**
+     * Least significant limb first, without leading zero
+     * limbs (so zero has no limbs and each value has a
+     * single representation, which the derived PartialEq
+     * relies on).
+     */
+    m_limbs: Vec<u32>,
+}
+
+impl BigUint {
+    const BASE: u64 = 1_000_000_000;
+    /** The number of decimal digits of a limb. */
+    const LIMB_DIGITS: usize = 9;
+
+    pub fn zero() -> BigUint {
+        return BigUint {
+            m_limbs: Vec::new(),
+        };
+    }
+
+    pub fn is_zero(&self) -> bool {
+        return self.m_limbs.is_empty();
+    }
+
+    /**
+     * @return None when the value does not fit in a u128.
+     */
+    pub fn to_u128(&self) -> Option<u128> {
+        let mut res: u128 = 0;
+        for &limb in self.m_limbs.iter().rev() {
+            res = res
+                .checked_mul(BigUint::BASE as u128)?
+                .checked_add(limb as u128)?;
+        }
+        return Some(res);
+    }
+
+    /**
+     * @return None when other is greater than self.
+     */
+    pub fn checked_sub(&self, other: &BigUint) -> Option<BigUint> {
+        if *self < *other {
+            return None;
+        }
+        let mut limbs: Vec<u32> = Vec::with_capacity(self.m_limbs.len());
+        let mut borrow: i64 = 0;
+        for (idx, &limb) in self.m_limbs.iter().enumerate() {
+            let mut diff: i64 = limb as i64
+                - other.m_limbs.get(idx).copied().unwrap_or(0) as i64
+                - borrow;
+            borrow = 0;
+            if diff < 0 {
+                diff += BigUint::BASE as i64;
+                borrow = 1;
+            }
+            limbs.push(diff as u32);
+        }
+        return Some(BigUint::from_limbs(limbs));
+    }
+
+    /**
+     * Long division, a quotient limb at a time (like the
+     * division by hand, with limbs instead of digits).
+     * Each quotient limb is found by a binary search of the
+     * largest q such that divisor * q <= remainder.
+     * @return the quotient and the remainder, None when
+     * divisor is zero.
+     */
+    pub fn checked_div_rem(
+        &self,
+        divisor: &BigUint,
+    ) -> Option<(BigUint, BigUint)> {
+        if divisor.is_zero() {
+            return None;
+        }
+        if let [single] = divisor.m_limbs[..] {
+            return Some(self.div_rem_limb(single));
+        }
+        let mut quotient: Vec<u32> = vec![0; self.m_limbs.len()];
+        let mut remainder: BigUint = BigUint::zero();
+        for idx in (0..self.m_limbs.len()).rev() {
+            /* remainder = remainder * BASE + limb. */
+            remainder.m_limbs.insert(0, self.m_limbs[idx]);
+            remainder = BigUint::from_limbs(remainder.m_limbs);
+            let (mut low, mut high): (u64, u64) = (0, BigUint::BASE - 1);
+            while low < high {
+                let mid: u64 = (low + high).div_ceil(2);
+                if divisor.mul_limb(mid as u32) <= remainder {
+                    low = mid;
+                } else {
+                    high = mid - 1;
+                }
+            }
+            quotient[idx] = low as u32;
+            remainder = remainder
+                .checked_sub(&divisor.mul_limb(low as u32))
+                .unwrap_or_default();
+        }
+        return Some((BigUint::from_limbs(quotient), remainder));
+    }
+
+    fn div_rem_limb(&self, divisor: u32) -> (BigUint, BigUint) {
+        let mut quotient: Vec<u32> = vec![0; self.m_limbs.len()];
+        let mut remainder: u64 = 0;
+        for idx in (0..self.m_limbs.len()).rev() {
+            let current: u64 =
+                remainder * BigUint::BASE + self.m_limbs[idx] as u64;
+            quotient[idx] = (current / divisor as u64) as u32;
+            remainder = current % divisor as u64;
+        }
+        return (BigUint::from_limbs(quotient), BigUint::from(remainder));
+    }
+
+    fn mul_limb(&self, factor: u32) -> BigUint {
+        let mut limbs: Vec<u32> = Vec::with_capacity(self.m_limbs.len() + 1);
+        let mut carry: u64 = 0;
+        for &limb in self.m_limbs.iter() {
+            let product: u64 = limb as u64 * factor as u64 + carry;
+            limbs.push((product % BigUint::BASE) as u32);
+            carry = product / BigUint::BASE;
+        }
+        limbs.push(carry as u32);
+        return BigUint::from_limbs(limbs);
+    }
+
+    /**
+     * Drops the leading zero limbs.
+     */
+    fn from_limbs(mut limbs: Vec<u32>) -> BigUint {
+        while limbs.last() == Some(&0) {
+            limbs.pop();
+        }
+        return BigUint { m_limbs: limbs };
+    }
+}
+
+impl From<u64> for BigUint {
+    fn from(num: u64) -> BigUint {
+        return BigUint::from(num as u128);
+    }
+}
+
+impl From<u128> for BigUint {
+    fn from(mut num: u128) -> BigUint {
+        let mut limbs: Vec<u32> = Vec::new();
+        while num != 0 {
+            limbs.push((num % BigUint::BASE as u128) as u32);
+            num /= BigUint::BASE as u128;
+        }
+        return BigUint { m_limbs: limbs };
+    }
+}
+
+/**
+ * Parses decimal digits, leading zeros are allowed ("007").
+ * @return RC::CParse for an empty string or a non digit
+ * character (a sign included).
+ */
+impl std::str::FromStr for BigUint {
+    type Err = RC;
+
+    fn from_str(text: &str) -> Result<BigUint, RC> {
+        if text.is_empty() {
+            return Err(RC::parse(text, 0, "expected decimal digits"));
+        }
+        if let Some(position) = text.find(|ch: char| !ch.is_ascii_digit()) {
+            return Err(RC::parse(text, position, "expected a decimal digit"));
+        }
+        /* The last (least significant) 9 digits are the first limb. */
+        let mut limbs: Vec<u32> = Vec::new();
+        let mut end: usize = text.len();
+        while end > 0 {
+            let start: usize = end.saturating_sub(BigUint::LIMB_DIGITS);
+            limbs.push(text[start..end].parse::<u32>().unwrap_or(0));
+            end = start;
+        }
+        return Ok(BigUint::from_limbs(limbs));
+    }
+}
+
+impl std::fmt::Display for BigUint {
+    /**
+     * The most significant limb is written as is, the other
+     * ones are padded with zeros to 9 digits.
+     */
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let mut text: String = match self.m_limbs.last() {
+            Some(limb) => limb.to_string(),
+            None => String::from("0"),
+        };
+        for limb in self.m_limbs.iter().rev().skip(1) {
+            text.push_str(&format!("{limb:09}"));
+        }
+        return f.pad_integral(true, "", &text);
+    }
+}
+
+impl PartialOrd for BigUint {
+    fn partial_cmp(&self, other: &BigUint) -> Option<Ordering> {
+        return Some(self.cmp(other));
+    }
+}
+
+/**
+ * More limbs is a larger number (there are no leading zero
+ * limbs), otherwise the limbs are compared from the most
+ * significant one.
+ */
+impl Ord for BigUint {
+    fn cmp(&self, other: &BigUint) -> Ordering {
+        return self.m_limbs.len().cmp(&other.m_limbs.len()).then_with(|| {
+            self.m_limbs.iter().rev().cmp(other.m_limbs.iter().rev())
+        });
+    }
+}
+
+impl std::ops::Add<&BigUint> for &BigUint {
+    type Output = BigUint;
+
+    fn add(self, other: &BigUint) -> BigUint {
+        let len: usize = std::cmp::max(self.m_limbs.len(), other.m_limbs.len());
+        let mut limbs: Vec<u32> = Vec::with_capacity(len + 1);
+        let mut carry: u64 = 0;
+        for idx in 0..len {
+            let sum: u64 = self.m_limbs.get(idx).copied().unwrap_or(0) as u64
+                + other.m_limbs.get(idx).copied().unwrap_or(0) as u64
+                + carry;
+            limbs.push((sum % BigUint::BASE) as u32);
+            carry = sum / BigUint::BASE;
+        }
+        limbs.push(carry as u32);
+        return BigUint::from_limbs(limbs);
+    }
+}
+
+impl std::ops::Sub<&BigUint> for &BigUint {
+    type Output = BigUint;
+
+    fn sub(self, other: &BigUint) -> BigUint {
+        match self.checked_sub(other) {
+            Some(diff) => return diff,
+            None => panic!("attempt to subtract with overflow"),
+        }
+    }
+}
+
+/**
+ * Schoolbook multiplication, O(n * m) limb products. A
+ * product of limbs plus the current limb and the carry is
+ * below BASE^2 + 2 * BASE, it fits in a u64.
+ */
+impl std::ops::Mul<&BigUint> for &BigUint {
+    type Output = BigUint;
+
+    fn mul(self, other: &BigUint) -> BigUint {
+        let mut limbs: Vec<u32> =
+            vec![0; self.m_limbs.len() + other.m_limbs.len()];
+        for (idx1, &limb1) in self.m_limbs.iter().enumerate() {
+            let mut carry: u64 = 0;
+            for (idx2, &limb2) in other.m_limbs.iter().enumerate() {
+                let current: u64 = limbs[idx1 + idx2] as u64
+                    + limb1 as u64 * limb2 as u64
+                    + carry;
+                limbs[idx1 + idx2] = (current % BigUint::BASE) as u32;
+                carry = current / BigUint::BASE;
+            }
+            limbs[idx1 + other.m_limbs.len()] = carry as u32;
+        }
+        return BigUint::from_limbs(limbs);
+    }
+}
+
+impl std::ops::Div<&BigUint> for &BigUint {
+    type Output = BigUint;
+
+    fn div(self, divisor: &BigUint) -> BigUint {
+        match self.checked_div_rem(divisor) {
+            Some((quotient, _)) => return quotient,
+            None => panic!("attempt to divide by zero"),
+        }
+    }
+}
+
+impl std::ops::Rem<&BigUint> for &BigUint {
+    type Output = BigUint;
+
+    fn rem(self, divisor: &BigUint) -> BigUint {
+        match self.checked_div_rem(divisor) {
+            Some((_, remainder)) => return remainder,
+            None => {
+                panic!(
+                    "attempt to calculate the remainder with a divisor of zero"
+                )
+            }
+        }
+    }
+}
+
+/**
+ * The operators on values forward to the ones on
+ * references, so both a + b and &a + &b compile.
+ */
+macro_rules! forward_val_ops {
+    ($($op:ident $method:ident),*) => {
+        $(
+            impl std::ops::$op<BigUint> for BigUint {
+                type Output = BigUint;
+
+                fn $method(self, other: BigUint) -> BigUint {
+                    return std::ops::$op::$method(&self, &other);
+                }
+            }
+        )*
+    };
+}
+
+forward_val_ops!(Add add, Sub sub, Mul mul, Div div, Rem rem);
+
+//_____________________________________________________________________________
+/**
+ * Compares BigUint with u128 on the values both can hold,
+ * see core::fuzz. x = a * b covers the whole u128 range, y
+ * = c the u64 one; the operations overflowing a u128 are
+ * None on both sides (the BigUint result does not fit in a
+ * u128).
+ */
+pub struct BigUintFuzz;
+
+/**
+ * (x as text, x + y, x - y, x * y, x / y and x % y, x cmp
+ * y, (x * y) / y == x and (x * y) % y == 0).
+ */
+type BigUintSummary = (
+    String,
+    Option<u128>,
+    Option<u128>,
+    Option<u128>,
+    Option<(u128, u128)>,
+    Ordering,
+    bool,
+);
+
+impl crate::core::fuzz::Differential for BigUintFuzz {
+    type Input = (u64, u64, u64);
+    type Output = BigUintSummary;
+
+    fn name(&self) -> &'static str {
+        return "big_uint";
+    }
+
+    fn reference((a, b, c): (u64, u64, u64)) -> BigUintSummary {
+        let x: u128 = a as u128 * b as u128;
+        let y: u128 = c as u128;
+        let div_rem: Option<(u128, u128)> = match y {
+            0 => None,
+            _ => Some((x / y, x % y)),
+        };
+        return (
+            x.to_string(),
+            x.checked_add(y),
+            x.checked_sub(y),
+            x.checked_mul(y),
+            div_rem,
+            x.cmp(&y),
+            true,
+        );
+    }
+
+    fn candidate((a, b, c): (u64, u64, u64)) -> BigUintSummary {
+        /* Parsed from text, so parsing is compared as well. */
+        let x: BigUint = match (a as u128 * b as u128).to_string().parse() {
+            Ok(x) => x,
+            Err(rc) => panic!("{rc}"),
+        };
+        let y: BigUint = BigUint::from(c);
+        let product: BigUint = &x * &y;
+        let div_rem: Option<(u128, u128)> =
+            x.checked_div_rem(&y).and_then(|(quotient, remainder)| {
+                return Some((quotient.to_u128()?, remainder.to_u128()?));
+            });
+        let is_product_divisible: bool =
+            y.is_zero() || (&product / &y == x && (&product % &y).is_zero());
+        return (
+            x.to_string(),
+            (&x + &y).to_u128(),
+            x.checked_sub(&y).and_then(|diff| diff.to_u128()),
+            product.to_u128(),
+            div_rem,
+            x.cmp(&y),
+            is_product_divisible,
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /** Parses a number known to be valid. */
+    fn big(text: &str) -> BigUint {
+        return text.parse::<BigUint>().unwrap();
+    }
+
+    /** @return the position and the message of the parse error. */
+    fn parse_error(text: &str) -> (usize, String) {
+        match text.parse::<BigUint>() {
+            Err(RC::CParse {
+                position, message, ..
+            }) => return (position, message),
+            res => panic!("{text:?}: expected a parse error, got {res:?}"),
+        }
+    }
+
+    #[test]
+    fn parse_and_display() {
+        assert_eq!(big("0"), BigUint::zero());
+        assert_eq!(big("000"), BigUint::zero());
+        assert_eq!(big("0").to_string(), "0");
+        assert_eq!(big("000123").to_string(), "123");
+        assert_eq!(big("1000000000"), BigUint::from(1_000_000_000u64));
+        let text: &str = "12345678901234567890123456789012345678901234567890";
+        assert_eq!(big(text).to_string(), text);
+        assert_eq!(big(text).to_u128(), None);
+        assert_eq!(BigUint::from(u128::MAX).to_u128(), Some(u128::MAX));
+    }
+
+    #[test]
+    fn parse_errors() {
+        let digit: String = String::from("expected a decimal digit");
+        assert_eq!(
+            parse_error(""),
+            (0, String::from("expected decimal digits"))
+        );
+        assert_eq!(parse_error("12a3"), (2, digit.clone()));
+        assert_eq!(parse_error("-1"), (0, digit.clone()));
+        assert_eq!(parse_error("1 "), (1, digit.clone()));
+        assert_eq!(parse_error("1_000"), (1, digit.clone()));
+        assert_eq!(parse_error("١٢"), (0, digit));
+    }
+
+    #[test]
+    fn division_by_zero_is_none() {
+        assert_eq!(big("42").checked_div_rem(&BigUint::zero()), None);
+        assert_eq!(BigUint::zero().checked_div_rem(&BigUint::zero()), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "attempt to divide by zero")]
+    fn division_by_zero_panics() {
+        let _ = big("42") / BigUint::zero();
+    }
+
+    #[test]
+    #[should_panic(expected = "with a divisor of zero")]
+    fn remainder_by_zero_panics() {
+        let _ = big("42") % BigUint::zero();
+    }
+
+    #[test]
+    fn long_division() {
+        /* 5 limbs divisor, 89 digits (> u128) dividend. */
+        let divisor: BigUint = big("10000000000000000000000000000000000000007");
+        let quotient: BigUint =
+            big("100000000000000000000000000000000000000000123456789");
+        let remainder: BigUint =
+            big("1000000000000000000000000000000000000005");
+        let dividend: BigUint = &(&quotient * &divisor) + &remainder;
+        assert_eq!(
+            dividend.to_string(),
+            "1000000000000000000000000000000000000000701234567891\
+             000000000000000000000000000000864197528"
+        );
+        assert_eq!(
+            dividend.checked_div_rem(&divisor),
+            Some((quotient.clone(), remainder.clone()))
+        );
+        assert_eq!(&dividend / &divisor, quotient);
+        assert_eq!(&dividend % &divisor, remainder);
+    }
+
+    #[test]
+    fn long_division_with_large_quotient_limbs() {
+        /* The quotient limbs are found near BASE - 1. */
+        let dividend: BigUint = big(&"9".repeat(80));
+        let divisor: BigUint = big(&"9".repeat(36));
+        let (quotient, remainder): (BigUint, BigUint) =
+            dividend.checked_div_rem(&divisor).unwrap();
+        assert_eq!(
+            quotient.to_string(),
+            "100000000000000000000000000000000000100000000"
+        );
+        assert_eq!(remainder.to_string(), "99999999");
+        assert_eq!(&(&quotient * &divisor) + &remainder, dividend);
+        assert_eq!(
+            divisor.checked_div_rem(&dividend),
+            Some((BigUint::zero(), divisor.clone()))
+        );
+    }
+
+    #[test]
+    fn matches_u128() {
+        crate::core::fuzz::assert_agree(&BigUintFuzz, 2000);
+    }
+}
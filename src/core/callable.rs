@@ -0,0 +1,43 @@
+use crate::core::return_code::RC;
+
+/**
+ * A function which can be called from the REPL with its
+ * arguments written as leet code literals, e.g.
+ * "two_sum [2,7,11,15] 9" or
+ * "two_sum nums = [2,7,11,15], target = 9".
+ *
+ * Each leet code module implements it for its unit struct
+ * (the same struct implementing Example) and registers it in
+ * the callables() function of src/leet_code/mod.rs.
+ */
+pub trait Callable {
+    /**
+     * The name used in the REPL, by convention it's the
+     * name of the called function.
+     */
+    fn name(&self) -> &'static str;
+
+    /**
+     * Other names accepted by the REPL, e.g. the module name
+     * when it differs from the function name.
+     */
+    fn aliases(&self) -> &'static [&'static str] {
+        return &[];
+    }
+
+    /**
+     * The parameters, for the REPL help, for example
+     * "nums: [i32], target: i32".
+     */
+    fn signature(&self) -> &'static str;
+
+    /**
+     * Parses the arguments and calls the function.
+     * @param args the arguments as written by the user, they
+     * are parsed with leet_code::literal::Literal.
+     * @return the function's result formatted as a literal,
+     * or RC::CParse when the arguments do not match the
+     * signature.
+     */
+    fn call(&self, args: &str) -> Result<String, RC>;
+}
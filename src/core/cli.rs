@@ -0,0 +1,875 @@
+use crate::core::alloc_stats::AllocStats;
+use crate::core::complexity::{Complexity, ComplexityReport, ComplexityTarget};
+use crate::core::example::{Category, Example};
+use crate::core::fuzz::{Fuzz, FuzzReport, FuzzTarget};
+use crate::core::golden::{Golden, GoldenStatus};
+use crate::core::json::JsonValue;
+use crate::core::notes::Notes;
+use crate::core::registry::Registry;
+use crate::core::repl::Repl;
+use crate::core::reporter::{Reporter, Style};
+use crate::core::return_code::RC;
+use crate::core::runner::{RunReport, Runner};
+use crate::leet_code::judge::{Judge, JudgeReport, Judged};
+use crate::leet_code::progress::Progress;
+use crate::leet_code::scaffold::{Scaffold, ScaffoldReport};
+
+const USAGE: &str = "\
+usage:
+  rust_pg list                       list the examples
+  rust_pg run <name>...              run examples by name
+  rust_pg run --all                  run all the examples
+  rust_pg run --category <category>  run a category (examples, leet_code)
+  rust_pg run --format json ...      print a JSON line per event
+  rust_pg run --stats ...            count allocations, print a summary
+  rust_pg verify [<selection>]       compare the output with golden files
+  rust_pg bless [<selection>]        rewrite the golden files
+  rust_pg repl                       interactive examples and leet code calls
+  rust_pg judge [<problem>...]       run the judge cases of leet code problems
+  rust_pg progress                   summarize the judged leet code problems
+  rust_pg fuzz [<target>...]         compare solutions with reference ones
+  rust_pg complexity [<target>...]   measure the time complexity of functions
+  rust_pg new-problem <slug>         generate a leet code problem skeleton
+  rust_pg notes search <word>...     search the notes (doc comments)
+  rust_pg notes show <topic>         print the notes of a topic (file)
+  rust_pg notes topics               list the topics of the notes
+
+<selection> is the same as for run, the default is --all.
+verify and bless accept --golden-dir <dir> (default data/golden).
+judge accepts --data-dir <dir> (default data/leet_code) and
+--timeout-ms <ms> (overrides the per case timeout) and
+--no-progress (does not record the results in the progress).
+judge and progress accept --progress-file <file> (default
+~/.local/share/rust_pg/progress.txt).
+fuzz accepts --seed <seed>, --cases <count> (default 1000) and
+--replay <case seed> (runs the single case of a failure).
+complexity accepts --max-size <size> (default 1048576), the
+largest input size (the sizes double from 64).
+new-problem accepts the slug (two-sum) or the problem URL,
+--src-dir <dir> (default src) and --data-dir <dir>.
+
+A name which exists in several categories can be qualified,
+for example: leet_code/get_nth_word";
+
+/**
+ * The command line front end of the examples registry.
+ */
+pub struct Cli;
+
+impl Cli {
+    /**
+     * @param args the command line arguments, without the
+     * program name.
+     * @return RC::CInvalidArgument when the arguments
+     * cannot be parsed and RC::CNotFound when they name an
+     * unknown example.
+     */
+    pub fn run(args: &[String]) -> Result<(), RC> {
+        let registry: Registry = Registry::new();
+        match args.first().map(|arg| arg.as_str()) {
+            None | Some("help") | Some("--help") | Some("-h") => {
+                println!("{USAGE}");
+                return Ok(());
+            }
+            Some("list") => {
+                Cli::list(&registry);
+                return Ok(());
+            }
+            Some("run") => {
+                let mut args: Vec<String> = args[1..].to_vec();
+                let format: Option<String> =
+                    Cli::take_option(&mut args, "--format")?;
+                if Cli::take_flag(&mut args, "--stats") {
+                    AllocStats::enable();
+                }
+                let examples: Vec<&dyn Example> =
+                    Cli::select(&registry, &args)?;
+                match format.as_deref() {
+                    None | Some("text") => return Cli::run_text(&examples),
+                    Some("json") => return Cli::run_json(&examples),
+                    Some(format) => {
+                        return Err(RC::invalid_argument(
+                            "--format",
+                            &format!(
+                                "unknown format '{format}', expected \
+                                 text or json"
+                            ),
+                        ));
+                    }
+                }
+            }
+            Some("verify") => {
+                let mut args: Vec<String> = args[1..].to_vec();
+                let golden: Golden = Cli::golden(&mut args)?;
+                let examples: Vec<&dyn Example> =
+                    Cli::select_or_all(&registry, &args)?;
+                return Cli::verify(&golden, &examples);
+            }
+            Some("bless") => {
+                let mut args: Vec<String> = args[1..].to_vec();
+                let golden: Golden = Cli::golden(&mut args)?;
+                let examples: Vec<&dyn Example> =
+                    Cli::select_or_all(&registry, &args)?;
+                for example in examples {
+                    if golden.bless(example)? {
+                        println!("blessed {}", golden.path(example).display());
+                    } else {
+                        println!(
+                            "skipped {} (not deterministic)",
+                            example.name()
+                        );
+                    }
+                }
+                return Ok(());
+            }
+            Some("repl") => {
+                let repl: Repl = Repl::new();
+                return repl.run(std::io::stdin().lock(), std::io::stdout());
+            }
+            Some("notes") => return Cli::notes(&args[1..]),
+            Some("fuzz") => {
+                let mut args: Vec<String> = args[1..].to_vec();
+                let seed: u64 = match Cli::take_option(&mut args, "--seed")? {
+                    Some(seed) => Cli::parse_number("--seed", &seed)?,
+                    None => Fuzz::time_seed(),
+                };
+                let cases: usize = match Cli::take_option(&mut args, "--cases")?
+                {
+                    Some(cases) => Cli::parse_number("--cases", &cases)?,
+                    None => Fuzz::DEFAULT_CASES,
+                };
+                let replay: Option<u64> =
+                    match Cli::take_option(&mut args, "--replay")? {
+                        Some(seed) => {
+                            Some(Cli::parse_number("--replay", &seed)?)
+                        }
+                        None => None,
+                    };
+                return Cli::fuzz(&Fuzz::new(seed, cases), replay, &args);
+            }
+            Some("judge") => {
+                let mut args: Vec<String> = args[1..].to_vec();
+                let dir: String = Cli::take_option(&mut args, "--data-dir")?
+                    .unwrap_or_else(|| String::from(Judge::DEFAULT_DIR));
+                let timeout: Option<std::time::Duration> =
+                    match Cli::take_option(&mut args, "--timeout-ms")? {
+                        Some(ms) => Some(std::time::Duration::from_millis(
+                            Cli::parse_number("--timeout-ms", &ms)?,
+                        )),
+                        None => None,
+                    };
+                let progress_file: Option<String> =
+                    Cli::take_option(&mut args, "--progress-file")?;
+                let mut progress: Option<Progress> =
+                    match Cli::take_flag(&mut args, "--no-progress") {
+                        true => None,
+                        false => Some(Cli::progress_of(progress_file)?),
+                    };
+                let judge: Judge =
+                    Judge::new(std::path::Path::new(&dir), timeout);
+                return Cli::judge(&judge, progress.as_mut(), &args);
+            }
+            Some("progress") => {
+                let mut args: Vec<String> = args[1..].to_vec();
+                let progress: Progress = Cli::progress_of(Cli::take_option(
+                    &mut args,
+                    "--progress-file",
+                )?)?;
+                if !args.is_empty() {
+                    return Err(RC::invalid_argument(
+                        "progress",
+                        &format!("unexpected argument '{}'", args[0]),
+                    ));
+                }
+                let slugs: Vec<&str> = crate::leet_code::problems()
+                    .iter()
+                    .map(|problem| problem.slug())
+                    .collect();
+                progress.print(&Reporter::new(), &slugs);
+                return Ok(());
+            }
+            Some("complexity") => {
+                let mut args: Vec<String> = args[1..].to_vec();
+                let max_size: usize =
+                    match Cli::take_option(&mut args, "--max-size")? {
+                        Some(size) => Cli::parse_number("--max-size", &size)?,
+                        None => Complexity::DEFAULT_MAX_SIZE,
+                    };
+                return Cli::complexity(&Complexity::new(max_size), &args);
+            }
+            Some("new-problem") => {
+                let mut args: Vec<String> = args[1..].to_vec();
+                let src_dir: String = Cli::take_option(&mut args, "--src-dir")?
+                    .unwrap_or_else(|| String::from(Scaffold::DEFAULT_SRC_DIR));
+                let data_dir: String =
+                    Cli::take_option(&mut args, "--data-dir")?
+                        .unwrap_or_else(|| String::from(Judge::DEFAULT_DIR));
+                if args.len() != 1 {
+                    return Err(RC::invalid_argument(
+                        "new-problem",
+                        "expected a single slug",
+                    ));
+                }
+                let scaffold: Scaffold = Scaffold::new(
+                    std::path::Path::new(&src_dir),
+                    std::path::Path::new(&data_dir),
+                );
+                return Cli::new_problem(&scaffold, &args[0]);
+            }
+            Some(command) => {
+                eprintln!("{USAGE}");
+                return Err(RC::invalid_argument(
+                    "command",
+                    &format!("unknown command '{command}'"),
+                ));
+            }
+        }
+    }
+
+    /**
+     * Fuzzes the given targets (all of them when none is
+     * given), stops at the first failing target.
+     * @return RC::CMismatch with the counterexample.
+     */
+    fn fuzz(
+        fuzz: &Fuzz,
+        replay: Option<u64>,
+        names: &[String],
+    ) -> Result<(), RC> {
+        let mut targets: Vec<Box<dyn FuzzTarget>> =
+            crate::examples::fuzz_targets();
+        targets.extend(crate::leet_code::fuzz_targets());
+        for name in names {
+            if !targets.iter().any(|target| target.name() == name) {
+                return Err(RC::not_found(&format!("fuzz target '{name}'")));
+            }
+        }
+        let reporter: Reporter = Reporter::new();
+        for target in targets.iter() {
+            if !names.is_empty()
+                && !names.iter().any(|name| name == target.name())
+            {
+                continue;
+            }
+            let res: Result<FuzzReport, RC> = match replay {
+                Some(case_seed) => target.replay(fuzz, case_seed),
+                None => target.fuzz(fuzz),
+            };
+            match res {
+                Ok(report) => reporter.line(&format!(
+                    "{} {} ({} cases, seed {})",
+                    reporter.paint("ok    ", Style::Success),
+                    report.name,
+                    report.cases,
+                    fuzz.seed()
+                )),
+                Err(rc) => {
+                    reporter.line(&format!(
+                        "{} {}",
+                        reporter.paint("failed", Style::Failure),
+                        target.name()
+                    ));
+                    return Err(
+                        rc.context(&format!("fuzzing {}", target.name()))
+                    );
+                }
+            }
+        }
+        return Ok(());
+    }
+
+    /**
+     * Parses a numeric option value.
+     */
+    fn parse_number<T: std::str::FromStr>(
+        option: &str,
+        value: &str,
+    ) -> Result<T, RC>
+    where
+        T::Err: std::fmt::Display,
+    {
+        return value
+            .parse::<T>()
+            .map_err(|err| RC::invalid_argument(option, &err.to_string()));
+    }
+
+    /**
+     * Judges the given problems (all of them when none is
+     * given), all the problems are judged before returning.
+     * @return RC::CMismatch when a case fails.
+     */
+    fn judge(
+        judge: &Judge,
+        mut progress: Option<&mut Progress>,
+        names: &[String],
+    ) -> Result<(), RC> {
+        let problems: Vec<Box<dyn Judged>> = crate::leet_code::problems();
+        for name in names {
+            if !problems.iter().any(|problem| problem.slug() == name) {
+                return Err(RC::not_found(&format!("problem '{name}'")));
+            }
+        }
+        let reporter: Reporter = Reporter::new();
+        let mut failures: usize = 0;
+        for problem in problems.iter() {
+            if !names.is_empty()
+                && !names.iter().any(|name| name == problem.slug())
+            {
+                continue;
+            }
+            reporter.section(problem.slug());
+            let report: JudgeReport = problem.judge(judge)?;
+            Judge::print(&reporter, &report);
+            failures += report.cases.len() - report.passed();
+            if let Some(progress) = progress.as_deref_mut() {
+                progress.record(problem.as_ref(), &report, Progress::now());
+            }
+        }
+        if let Some(progress) = progress {
+            progress.save()?;
+        }
+        if failures != 0 {
+            return Err(RC::mismatch(
+                "all the judge cases to pass",
+                &format!("{failures} cases failed"),
+            ));
+        }
+        return Ok(());
+    }
+
+    /**
+     * Loads the progress from the given file, or from the
+     * default one.
+     */
+    fn progress_of(file: Option<String>) -> Result<Progress, RC> {
+        let path: std::path::PathBuf = match file {
+            Some(file) => std::path::PathBuf::from(file),
+            None => Progress::default_path(),
+        };
+        return Progress::load(&path);
+    }
+
+    /**
+     * Measures the given targets (all of them when none is
+     * given), all the targets are measured before returning.
+     * @return RC::CMismatch when a measured complexity is
+     * not the claimed one.
+     */
+    fn complexity(complexity: &Complexity, names: &[String]) -> Result<(), RC> {
+        let mut targets: Vec<Box<dyn ComplexityTarget>> =
+            crate::examples::complexity_targets();
+        targets.extend(crate::leet_code::complexity_targets());
+        for name in names {
+            if !targets.iter().any(|target| target.name() == name) {
+                return Err(RC::not_found(&format!(
+                    "complexity target '{name}'"
+                )));
+            }
+        }
+        let reporter: Reporter = Reporter::new();
+        let mut unverified: Vec<&str> = Vec::new();
+        for target in targets.iter() {
+            if !names.is_empty()
+                && !names.iter().any(|name| name == target.name())
+            {
+                continue;
+            }
+            reporter.section(target.name());
+            let report: ComplexityReport =
+                target.measure(complexity).map_err(|rc| {
+                    rc.context(&format!("measuring {}", target.name()))
+                })?;
+            report.print(&reporter);
+            if !report.is_claim_verified() {
+                unverified.push(target.name());
+            }
+        }
+        if !unverified.is_empty() {
+            return Err(RC::mismatch(
+                "the claimed complexities",
+                &format!("other complexities for {}", unverified.join(", ")),
+            ));
+        }
+        return Ok(());
+    }
+
+    /**
+     * Generates a new problem and prints what is left to do.
+     */
+    fn new_problem(scaffold: &Scaffold, slug: &str) -> Result<(), RC> {
+        let report: ScaffoldReport = scaffold.create(slug)?;
+        let reporter: Reporter = Reporter::new();
+        for path in report.created.iter() {
+            reporter.line(&format!(
+                "{} {}",
+                reporter.paint("created", Style::Success),
+                path.display()
+            ));
+        }
+        for path in report.updated.iter() {
+            reporter.line(&format!(
+                "{} {}",
+                reporter.paint("updated", Style::Success),
+                path.display()
+            ));
+        }
+        reporter.line(&format!(
+            "next: implement Solution::{0}, add the judge cases, then run \
+             rust_pg bless leet_code/{0}",
+            report.module
+        ));
+        return Ok(());
+    }
+
+    /**
+     * The notes subcommands: search, show and topics.
+     */
+    fn notes(args: &[String]) -> Result<(), RC> {
+        let notes: Notes = Notes::new();
+        match args.first().map(|arg| arg.as_str()) {
+            Some("search") if args.len() > 1 => {
+                notes.print_search(&args[1..].join(" "));
+                return Ok(());
+            }
+            Some("show") if args.len() == 2 => {
+                return notes.print_topic(&args[1]);
+            }
+            Some("topics") => {
+                let pairs: Vec<(&str, String)> = notes
+                    .topics()
+                    .into_iter()
+                    .map(|(topic, count)| (topic, format!("{count} notes")))
+                    .collect();
+                Reporter::new().key_values(&pairs);
+                return Ok(());
+            }
+            _ => {
+                eprintln!("{USAGE}");
+                return Err(RC::invalid_argument(
+                    "notes",
+                    "expected search <word>..., show <topic> or topics",
+                ));
+            }
+        }
+    }
+
+    fn list(registry: &Registry) {
+        let reporter: Reporter = Reporter::new();
+        for category in Category::all() {
+            reporter.section(category.as_str());
+            let pairs: Vec<(&str, String)> = registry
+                .by_category(category)
+                .iter()
+                .map(|example| {
+                    (example.name(), String::from(example.description()))
+                })
+                .collect();
+            reporter.nested().key_values(&pairs);
+        }
+    }
+
+    /**
+     * Runs the examples, their output goes directly to
+     * stdout.
+     * A failure does not stop the run: the next examples are
+     * still run and each failure is reported under its
+     * example, except the first one which is returned (and
+     * rendered by main).
+     * @return the error of the first failing example.
+     */
+    fn run_text(examples: &[&dyn Example]) -> Result<(), RC> {
+        let reporter: Reporter = Reporter::new();
+        let runner: Runner = Runner::new(false);
+        let mut reports: Vec<RunReport> = Vec::new();
+        let mut first_failure: Option<RC> = None;
+        let mut failures: usize = 0;
+        for &example in examples {
+            reporter.section(&Cli::full_name(example));
+            let mut report: RunReport = runner.run(example);
+            Cli::print_scenarios(&reporter, &report);
+            let result: Result<(), RC> =
+                std::mem::replace(&mut report.result, Ok(()));
+            reports.push(report);
+            if let Err(rc) = result {
+                /* The run goes on (like run_json). */
+                let rc: RC = rc.context(&format!(
+                    "running example '{}'",
+                    Cli::full_name(example)
+                ));
+                failures += 1;
+                if first_failure.is_none() {
+                    /* Rendered once, by main, with its exit code. */
+                    reporter.line(&reporter.paint(
+                        "failed, the error is reported at the end",
+                        Style::Failure,
+                    ));
+                    first_failure = Some(rc);
+                } else {
+                    for line in rc.render().lines() {
+                        reporter.line(&reporter.paint(line, Style::Failure));
+                    }
+                }
+            }
+        }
+        if AllocStats::is_enabled() {
+            Cli::print_stats(&reporter, &reports);
+        }
+        if failures > 0 {
+            reporter.separator();
+            reporter.line(&format!(
+                "{failures} of {} examples failed",
+                examples.len()
+            ));
+        }
+        match first_failure {
+            Some(rc) => return Err(rc),
+            None => return Ok(()),
+        }
+    }
+
+    /**
+     * Prints the panic scenarios of an example, e.g.
+     * panicked double_borrow_mut: RefCell already borrowed at
+     *   src/examples/core_cell.rs:380:14
+     */
+    fn print_scenarios(reporter: &Reporter, report: &RunReport) {
+        if report.scenarios.is_empty() {
+            return;
+        }
+        reporter.subsection("panic scenarios");
+        for scenario in report.scenarios.iter() {
+            let style: Style = if scenario.is_ok() {
+                Style::Success
+            } else {
+                Style::Failure
+            };
+            let status: String = reporter
+                .paint(&format!("{:<13}", scenario.status_str()), style);
+            let description: String = scenario
+                .panic_description()
+                .unwrap_or_else(|| format!("expected '{}'", scenario.expected));
+            reporter
+                .nested()
+                .line(&format!("{status} {}: {description}", scenario.name));
+        }
+    }
+
+    /**
+     * Prints the time and allocation counters of the
+     * examples which were run.
+     */
+    fn print_stats(reporter: &Reporter, reports: &[RunReport]) {
+        reporter.section("stats");
+        let mut rows: Vec<Vec<String>> = Vec::new();
+        for report in reports {
+            let alloc: AllocStats = report.alloc.unwrap_or_default();
+            rows.push(vec![
+                format!("{}/{}", report.category.as_str(), report.name),
+                alloc.allocations.to_string(),
+                alloc.bytes.to_string(),
+                alloc.peak_live_bytes.to_string(),
+                format!("{:.3}", report.duration.as_secs_f64() * 1000.0),
+            ]);
+        }
+        reporter.table(
+            &[
+                "example",
+                "allocations",
+                "bytes",
+                "peak live bytes",
+                "time (ms)",
+            ],
+            &rows,
+        );
+    }
+
+    /**
+     * Runs all the examples and prints JSON lines events:
+     * {"event":"start","name":..,"category":..}
+     * {"event":"finish","name":..,"category":..,"status":..,
+     *  "output":[lines],"assertions":..,"duration_us":..,
+     *  "message":..,"scenarios":[..]}
+     * and a last {"event":"summary",...} line.
+     * @return the error of the first failing example.
+     */
+    fn run_json(examples: &[&dyn Example]) -> Result<(), RC> {
+        let runner: Runner = Runner::new(true);
+        let mut first_failure: Option<RC> = None;
+        let mut failures: usize = 0;
+        for &example in examples {
+            let start_event: JsonValue = JsonValue::object()
+                .with_field("event", JsonValue::from("start"))
+                .with_field("name", JsonValue::from(example.name()))
+                .with_field(
+                    "category",
+                    JsonValue::from(example.category().as_str()),
+                );
+            println!("{start_event}");
+
+            let report: RunReport = runner.run(example);
+            println!("{}", Cli::finish_event(&report));
+            if let Err(rc) = report.result {
+                failures += 1;
+                if first_failure.is_none() {
+                    first_failure = Some(rc.context(&format!(
+                        "running example '{}'",
+                        Cli::full_name(example)
+                    )));
+                }
+            }
+        }
+        let summary_event: JsonValue = JsonValue::object()
+            .with_field("event", JsonValue::from("summary"))
+            .with_field("total", JsonValue::from(examples.len()))
+            .with_field("ok", JsonValue::from(examples.len() - failures))
+            .with_field("failed", JsonValue::from(failures));
+        println!("{summary_event}");
+        match first_failure {
+            Some(rc) => return Err(rc),
+            None => return Ok(()),
+        }
+    }
+
+    fn finish_event(report: &RunReport) -> JsonValue {
+        let output: Vec<&str> = report.output.lines().collect();
+        let message: Option<String> = match report.result {
+            Ok(()) => None,
+            Err(ref rc) => Some(rc.to_string()),
+        };
+        let mut event: JsonValue = JsonValue::object()
+            .with_field("event", JsonValue::from("finish"))
+            .with_field("name", JsonValue::from(report.name))
+            .with_field("category", JsonValue::from(report.category.as_str()))
+            .with_field("status", JsonValue::from(report.status()))
+            .with_field("output", JsonValue::from(output))
+            .with_field("assertions", JsonValue::from(report.assertions))
+            .with_field(
+                "duration_us",
+                JsonValue::from(report.duration.as_micros() as u64),
+            )
+            .with_field("message", JsonValue::from(message));
+        if !report.scenarios.is_empty() {
+            let scenarios: Vec<JsonValue> = report
+                .scenarios
+                .iter()
+                .map(|scenario| {
+                    return JsonValue::object()
+                        .with_field("name", JsonValue::from(scenario.name))
+                        .with_field(
+                            "expected",
+                            JsonValue::from(scenario.expected),
+                        )
+                        .with_field(
+                            "status",
+                            JsonValue::from(scenario.status_str()),
+                        )
+                        .with_field(
+                            "panic",
+                            JsonValue::from(scenario.panic_description()),
+                        );
+                })
+                .collect();
+            event = event.with_field("scenarios", JsonValue::from(scenarios));
+        }
+        if let Some(alloc) = report.alloc {
+            event = event
+                .with_field("allocations", JsonValue::from(alloc.allocations))
+                .with_field("bytes", JsonValue::from(alloc.bytes))
+                .with_field(
+                    "peak_live_bytes",
+                    JsonValue::from(alloc.peak_live_bytes),
+                );
+        }
+        return event;
+    }
+
+    /**
+     * @return <category>/<name>, e.g. leet_code/two_sum.
+     */
+    fn full_name(example: &dyn Example) -> String {
+        return format!("{}/{}", example.category().as_str(), example.name());
+    }
+
+    /**
+     * Verifies the examples against their golden files, all
+     * the examples are verified before returning.
+     * @return RC::CMismatch when an example does not match
+     * its golden file or does not have one.
+     */
+    fn verify(golden: &Golden, examples: &[&dyn Example]) -> Result<(), RC> {
+        let reporter: Reporter = Reporter::new();
+        let mut failures: usize = 0;
+        for &example in examples {
+            let name: String = Cli::full_name(example);
+            match golden.verify(example)? {
+                GoldenStatus::Match => reporter.line(&format!(
+                    "{} {name}",
+                    reporter.paint("ok      ", Style::Success)
+                )),
+                GoldenStatus::Skipped => reporter.line(&format!(
+                    "{} {name} (not deterministic)",
+                    reporter.paint("skipped ", Style::Dim)
+                )),
+                GoldenStatus::Missing => {
+                    failures += 1;
+                    reporter.line(&format!(
+                        "{} {name} (run bless to create {})",
+                        reporter.paint("missing ", Style::Warning),
+                        golden.path(example).display()
+                    ));
+                }
+                GoldenStatus::Mismatch(diff) => {
+                    failures += 1;
+                    reporter.line(&format!(
+                        "{} {name}",
+                        reporter.paint("mismatch", Style::Failure)
+                    ));
+                    for line in diff.lines() {
+                        reporter.nested().line(line);
+                    }
+                }
+            }
+        }
+        if failures != 0 {
+            return Err(RC::mismatch(
+                "all examples to match their golden files",
+                &format!("{failures} examples do not match"),
+            ));
+        }
+        return Ok(());
+    }
+
+    /**
+     * Removes the "--golden-dir <dir>" option from args.
+     * @return the golden files of the given (or default)
+     * directory.
+     */
+    fn golden(args: &mut Vec<String>) -> Result<Golden, RC> {
+        let dir: String = Cli::take_option(args, "--golden-dir")?
+            .unwrap_or_else(|| String::from(Golden::DEFAULT_DIR));
+        return Ok(Golden::new(std::path::Path::new(&dir)));
+    }
+
+    /**
+     * Removes a "<name> <value>" option from the arguments.
+     * @return the option's value, None when the option is
+     * not given.
+     */
+    fn take_option(
+        args: &mut Vec<String>,
+        name: &str,
+    ) -> Result<Option<String>, RC> {
+        let idx: usize = match args.iter().position(|arg| arg == name) {
+            Some(idx) => idx,
+            None => return Ok(None),
+        };
+        if idx + 1 == args.len() {
+            return Err(RC::invalid_argument(name, "expects a value"));
+        }
+        let value: String = args.remove(idx + 1);
+        args.remove(idx);
+        return Ok(Some(value));
+    }
+
+    /**
+     * Removes a flag (an option without a value) from the
+     * arguments.
+     * @return true when the flag was given.
+     */
+    fn take_flag(args: &mut Vec<String>, name: &str) -> bool {
+        match args.iter().position(|arg| arg == name) {
+            Some(idx) => {
+                args.remove(idx);
+                return true;
+            }
+            None => return false,
+        }
+    }
+
+    /**
+     * Like select(), but an empty selection means all the
+     * examples.
+     */
+    fn select_or_all<'a>(
+        registry: &'a Registry,
+        args: &[String],
+    ) -> Result<Vec<&'a dyn Example>, RC> {
+        if args.is_empty() {
+            return Cli::select(registry, &[String::from("--all")]);
+        }
+        return Cli::select(registry, args);
+    }
+
+    /**
+     * Selects the examples the "run" command refers to.
+     * @param registry the examples registry.
+     * @param args the "run" arguments: "--all",
+     * "--category <category>" or a list of names.
+     */
+    fn select<'a>(
+        registry: &'a Registry,
+        args: &[String],
+    ) -> Result<Vec<&'a dyn Example>, RC> {
+        let mut res: Vec<&dyn Example> = Vec::new();
+        match args.first().map(|arg| arg.as_str()) {
+            None => {
+                eprintln!("{USAGE}");
+                return Err(RC::invalid_argument(
+                    "run",
+                    "expects at least one example",
+                ));
+            }
+            Some("--all") => {
+                for example in registry.iter() {
+                    res.push(example.as_ref());
+                }
+            }
+            Some("--category") => {
+                let name: &str = match args.get(1) {
+                    Some(name) => name,
+                    None => {
+                        return Err(RC::invalid_argument(
+                            "--category",
+                            "expects a category name",
+                        ));
+                    }
+                };
+                match Category::from_name(name) {
+                    Some(category) => {
+                        res.extend(registry.by_category(category));
+                    }
+                    None => {
+                        return Err(RC::invalid_argument(
+                            "--category",
+                            &format!("unknown category '{name}'"),
+                        ));
+                    }
+                }
+            }
+            Some(_) => {
+                for name in args {
+                    let found: Vec<&dyn Example> = registry.find(name);
+                    match found.len() {
+                        0 => {
+                            return Err(RC::not_found(&format!(
+                                "example '{name}'"
+                            )));
+                        }
+                        1 => res.push(found[0]),
+                        _ => {
+                            return Err(RC::invalid_argument(
+                                name,
+                                &format!(
+                                    "ambiguous, qualify it with its \
+                                     category, e.g. {}/{name}",
+                                    found[0].category().as_str()
+                                ),
+                            ));
+                        }
+                    }
+                }
+            }
+        }
+        return Ok(res);
+    }
+}
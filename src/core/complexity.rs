@@ -0,0 +1,507 @@
+use crate::core::fuzz::Rng;
+use crate::core::reporter::Reporter;
+use crate::core::return_code::RC;
+use std::time::{Duration, Instant};
+
+/*
+ * Empirical time complexity.
+ *
+ * A target is run at doubling input sizes (64, 128, 256,
+ * ...) and timed, then each growth model is fitted to the
+ * times and the model with the smallest error is reported.
+ * Comparing it with the complexity the target claims (e.g.
+ * "A Vec.len^2 time complexity solution.") verifies the
+ * comments of the solutions.
+ *
+ * The fit is done on the logarithms of the times:
+ * log(time) = log(c) + log(model(n)), so the large times of
+ * the large sizes do not hide the small ones. For each
+ * model c is the mean of log(time) - log(model(n)) and the
+ * error is the standard deviation of the residuals.
+ *
+ * Note: the times are noisy (caches, frequency scaling,
+ * other processes), O(n) and O(n log n) only differ by a
+ * log factor, so the sizes have to grow large enough.
+ */
+
+/**
+ * The growth models, from the slowest growing.
+ */
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Growth {
+    Constant,
+    Linear,
+    Linearithmic,
+    Quadratic,
+    Cubic,
+}
+
+impl Growth {
+    pub fn all() -> [Growth; 5] {
+        return [
+            Growth::Constant,
+            Growth::Linear,
+            Growth::Linearithmic,
+            Growth::Quadratic,
+            Growth::Cubic,
+        ];
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match *self {
+            Growth::Constant => "O(1)",
+            Growth::Linear => "O(n)",
+            Growth::Linearithmic => "O(n log n)",
+            Growth::Quadratic => "O(n^2)",
+            Growth::Cubic => "O(n^3)",
+        }
+    }
+
+    /**
+     * @return the model at size n, up to a constant factor.
+     */
+    pub fn model(&self, size: usize) -> f64 {
+        let n: f64 = size as f64;
+        match *self {
+            Growth::Constant => return 1.0,
+            Growth::Linear => return n,
+            Growth::Linearithmic => return n * n.log2(),
+            Growth::Quadratic => return n * n,
+            Growth::Cubic => return n * n * n,
+        }
+    }
+}
+
+/**
+ * A function whose running time depends on the size of its
+ * input.
+ */
+pub trait Scalable {
+    type Input;
+
+    fn name(&self) -> &'static str;
+
+    /**
+     * The complexity the implementation claims (in its
+     * comments), checked by the measure.
+     */
+    fn claimed(&self) -> Growth;
+
+    /**
+     * @return an input of the given size. It's not timed, the
+     * worst case input should be returned (e.g. a two sum
+     * without a solution, so the whole input is searched).
+     */
+    fn input(&self, size: usize, rng: &mut Rng) -> Self::Input;
+
+    /**
+     * The timed function, the input is consumed so
+     * functions sorting in place get a fresh input for each
+     * run.
+     */
+    fn run(&self, input: Self::Input);
+}
+
+/**
+ * A Scalable with its input type erased, so targets with
+ * different inputs can be registered in the same list (see
+ * examples::complexity_targets()).
+ */
+pub trait ComplexityTarget {
+    fn name(&self) -> &'static str;
+
+    fn claimed(&self) -> Growth;
+
+    fn measure(&self, complexity: &Complexity) -> Result<ComplexityReport, RC>;
+}
+
+impl<S: Scalable> ComplexityTarget for S {
+    fn name(&self) -> &'static str {
+        return Scalable::name(self);
+    }
+
+    fn claimed(&self) -> Growth {
+        return Scalable::claimed(self);
+    }
+
+    fn measure(&self, complexity: &Complexity) -> Result<ComplexityReport, RC> {
+        return complexity.measure(self);
+    }
+}
+
+/**
+ * The time of a single run at a size, measured over
+ * several runs for the small sizes.
+ */
+pub struct Sample {
+    pub size: usize,
+    pub runs: usize,
+    pub time: Duration,
+}
+
+/**
+ * A growth model fitted to the samples.
+ */
+pub struct Fit {
+    pub growth: Growth,
+    /** time(n) ~ coefficient * model(n) nanoseconds. */
+    pub coefficient: f64,
+    /** The standard deviation of the log residuals. */
+    pub error: f64,
+}
+
+pub struct ComplexityReport {
+    pub name: &'static str,
+    pub claimed: Growth,
+    pub samples: Vec<Sample>,
+    /** Sorted by error, the best fit first. */
+    pub fits: Vec<Fit>,
+}
+
+impl ComplexityReport {
+    /**
+     * The largest distance of the exponent from 1 which
+     * still accepts O(n) for O(n log n) (and the opposite),
+     * see is_claim_verified.
+     */
+    pub const LINEAR_EXPONENT_TOLERANCE: f64 = 0.3;
+
+    pub fn best(&self) -> &Fit {
+        return &self.fits[0];
+    }
+
+    /**
+     * @return true when the best fit is the claimed growth,
+     * or when O(n) and O(n log n) are confused for each other
+     * with an exponent close to 1. They only differ by a log
+     * factor, which the cache misses of the large sizes
+     * easily add to a linear function (a hash map measures an
+     * exponent of about 1.15 in a release build), so the
+     * fits alone cannot tell them apart.
+     */
+    pub fn is_claim_verified(&self) -> bool {
+        let best: Growth = self.best().growth;
+        if best == self.claimed {
+            return true;
+        }
+        let near_linear = |growth: Growth| -> bool {
+            return matches!(growth, Growth::Linear | Growth::Linearithmic);
+        };
+        return near_linear(self.claimed)
+            && near_linear(best)
+            && (self.exponent() - 1.0).abs()
+                <= ComplexityReport::LINEAR_EXPONENT_TOLERANCE;
+    }
+
+    /**
+     * The slope of log(time) over log(size), e.g. ~2 for a
+     * quadratic function: doubling the size multiplies the
+     * time by 2^2.
+     */
+    pub fn exponent(&self) -> f64 {
+        let points: Vec<(f64, f64)> = self
+            .samples
+            .iter()
+            .map(|sample| {
+                (
+                    (sample.size as f64).ln(),
+                    Complexity::nanos(sample.time).ln(),
+                )
+            })
+            .collect();
+        let count: f64 = points.len() as f64;
+        let mean_x: f64 = points.iter().map(|p| p.0).sum::<f64>() / count;
+        let mean_y: f64 = points.iter().map(|p| p.1).sum::<f64>() / count;
+        let covariance: f64 =
+            points.iter().map(|p| (p.0 - mean_x) * (p.1 - mean_y)).sum();
+        let variance: f64 = points.iter().map(|p| (p.0 - mean_x).powi(2)).sum();
+        return covariance / variance;
+    }
+
+    /**
+     * Prints a table with a row per size (plot friendly:
+     * the columns are numbers), then the fits.
+     *
+     * size | runs | ns/run | ratio | best fit ns
+     * -----+------+--------+-------+------------
+     *   64 | 4096 |   1520 |       |        1491
+     *  128 | 2048 |   6012 |  3.96 |        5964
+     */
+    pub fn print(&self, reporter: &Reporter) {
+        let best: &Fit = self.best();
+        let mut rows: Vec<Vec<String>> = Vec::new();
+        let mut previous: Option<&Sample> = None;
+        for sample in self.samples.iter() {
+            let ratio: String = match previous {
+                Some(previous) => format!(
+                    "{:.2}",
+                    Complexity::nanos(sample.time)
+                        / Complexity::nanos(previous.time)
+                ),
+                None => String::new(),
+            };
+            rows.push(vec![
+                sample.size.to_string(),
+                sample.runs.to_string(),
+                format!("{:.0}", Complexity::nanos(sample.time)),
+                ratio,
+                format!(
+                    "{:.0}",
+                    best.coefficient * best.growth.model(sample.size)
+                ),
+            ]);
+            previous = Some(sample);
+        }
+        reporter
+            .table(&["size", "runs", "ns/run", "ratio", "best fit ns"], &rows);
+        let fits: Vec<Vec<String>> = self
+            .fits
+            .iter()
+            .map(|fit| {
+                vec![
+                    String::from(fit.growth.as_str()),
+                    format!("{:.3}", fit.error),
+                ]
+            })
+            .collect();
+        reporter.table(&["model", "error"], &fits);
+        let tolerated: &str =
+            if best.growth != self.claimed && self.is_claim_verified() {
+                ", accepted: the exponent is close to 1"
+            } else {
+                ""
+            };
+        reporter.line(&format!(
+            "claimed {}, measured {} (exponent {:.2}){tolerated}",
+            self.claimed.as_str(),
+            best.growth.as_str(),
+            self.exponent()
+        ));
+    }
+}
+
+pub struct Complexity {
+    m_min_size: usize,
+    m_max_size: usize,
+    /** A sample runs the function at least this long. */
+    m_sample_time: Duration,
+    /** The sizes stop doubling once a run takes this long. */
+    m_max_run_time: Duration,
+}
+
+impl Complexity {
+    pub const DEFAULT_MIN_SIZE: usize = 64;
+    pub const DEFAULT_MAX_SIZE: usize = 1 << 20;
+    /** The fit needs a few points. */
+    const MIN_SAMPLES: usize = 4;
+
+    pub fn new(max_size: usize) -> Complexity {
+        return Complexity {
+            m_min_size: Complexity::DEFAULT_MIN_SIZE,
+            m_max_size: max_size,
+            m_sample_time: Duration::from_millis(20),
+            m_max_run_time: Duration::from_millis(100),
+        };
+    }
+
+    /**
+     * Samples the doubling sizes and fits the models.
+     * @return RC::CInvalidArgument when there are too few
+     * sizes to fit the models (a max size too small).
+     */
+    pub fn measure<S: Scalable + ?Sized>(
+        &self,
+        target: &S,
+    ) -> Result<ComplexityReport, RC> {
+        let mut samples: Vec<Sample> = Vec::new();
+        let mut size: usize = self.m_min_size;
+        while size <= self.m_max_size {
+            let sample: Sample = self.sample(target, size);
+            let time: Duration = sample.time;
+            samples.push(sample);
+            if time >= self.m_max_run_time {
+                break;
+            }
+            size *= 2;
+        }
+        if samples.len() < Complexity::MIN_SAMPLES {
+            return Err(RC::invalid_argument(
+                "max size",
+                &format!(
+                    "{} sizes were sampled, at least {} are needed",
+                    samples.len(),
+                    Complexity::MIN_SAMPLES
+                ),
+            ));
+        }
+        let mut fits: Vec<Fit> = Growth::all()
+            .iter()
+            .map(|&growth| Complexity::fit(&samples, growth))
+            .collect();
+        fits.sort_by(|fit1, fit2| fit1.error.total_cmp(&fit2.error));
+        return Ok(ComplexityReport {
+            name: target.name(),
+            claimed: target.claimed(),
+            samples,
+            fits,
+        });
+    }
+
+    /**
+     * Doubles the number of runs till they take at least
+     * the sample time. The inputs are generated before
+     * starting the clock, the last batch gives the time.
+     */
+    fn sample<S: Scalable + ?Sized>(&self, target: &S, size: usize) -> Sample {
+        let mut rng: Rng = Rng::new(size as u64);
+        let mut runs: usize = 1;
+        loop {
+            let inputs: Vec<S::Input> =
+                (0..runs).map(|_| target.input(size, &mut rng)).collect();
+            let start: Instant = Instant::now();
+            for input in inputs {
+                target.run(input);
+            }
+            let elapsed: Duration = start.elapsed();
+            if elapsed >= self.m_sample_time {
+                return Sample {
+                    size,
+                    runs,
+                    time: elapsed / runs as u32,
+                };
+            }
+            runs *= 2;
+        }
+    }
+
+    fn fit(samples: &[Sample], growth: Growth) -> Fit {
+        let residuals: Vec<f64> = samples
+            .iter()
+            .map(|sample| {
+                Complexity::nanos(sample.time).ln()
+                    - growth.model(sample.size).ln()
+            })
+            .collect();
+        let count: f64 = residuals.len() as f64;
+        let log_coefficient: f64 = residuals.iter().sum::<f64>() / count;
+        let variance: f64 = residuals
+            .iter()
+            .map(|residual| (residual - log_coefficient).powi(2))
+            .sum::<f64>()
+            / count;
+        return Fit {
+            growth,
+            coefficient: log_coefficient.exp(),
+            error: variance.sqrt(),
+        };
+    }
+
+    fn nanos(time: Duration) -> f64 {
+        /* A 0 time would be -inf in the log space. */
+        return f64::max(time.as_nanos() as f64, 1.0);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /**
+     * @return the exact times of coefficient * model(n)
+     * nanoseconds at the doubling sizes 64..65536.
+     */
+    fn samples(growth: Growth, coefficient: f64) -> Vec<Sample> {
+        return (6..=16)
+            .map(|log_size: u32| {
+                let size: usize = 1 << log_size;
+                let nanos: f64 = coefficient * growth.model(size);
+                Sample {
+                    size,
+                    runs: 1,
+                    time: Duration::from_nanos(nanos.round() as u64),
+                }
+            })
+            .collect();
+    }
+
+    /**
+     * @return the report of the samples, fitted and sorted as
+     * Complexity::measure does.
+     */
+    fn report(claimed: Growth, samples: Vec<Sample>) -> ComplexityReport {
+        let mut fits: Vec<Fit> = Growth::all()
+            .iter()
+            .map(|&growth| Complexity::fit(&samples, growth))
+            .collect();
+        fits.sort_by(|fit1, fit2| fit1.error.total_cmp(&fit2.error));
+        return ComplexityReport {
+            name: "synthetic",
+            claimed,
+            samples,
+            fits,
+        };
+    }
+
+    #[test]
+    fn fit_recovers_the_model_and_coefficient() {
+        for growth in [Growth::Linear, Growth::Linearithmic, Growth::Quadratic]
+        {
+            let samples: Vec<Sample> = samples(growth, 3.0);
+            let fit: Fit = Complexity::fit(&samples, growth);
+            assert_eq!(fit.growth, growth);
+            assert!(fit.error < 1e-3, "{}: {}", growth.as_str(), fit.error);
+            assert!((fit.coefficient - 3.0).abs() < 1e-2);
+            for other in Growth::all() {
+                if other != growth {
+                    assert!(Complexity::fit(&samples, other).error > 0.1);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn best_fit_and_exponent() {
+        let linear: ComplexityReport =
+            report(Growth::Linear, samples(Growth::Linear, 3.0));
+        assert_eq!(linear.best().growth, Growth::Linear);
+        assert!((linear.exponent() - 1.0).abs() < 1e-3);
+        let linearithmic: ComplexityReport =
+            report(Growth::Linearithmic, samples(Growth::Linearithmic, 3.0));
+        assert_eq!(linearithmic.best().growth, Growth::Linearithmic);
+        assert!((linearithmic.exponent() - 1.14).abs() < 0.01);
+        let quadratic: ComplexityReport =
+            report(Growth::Quadratic, samples(Growth::Quadratic, 3.0));
+        assert_eq!(quadratic.best().growth, Growth::Quadratic);
+        assert!((quadratic.exponent() - 2.0).abs() < 1e-3);
+    }
+
+    #[test]
+    fn claims_matching_the_best_fit_are_verified() {
+        for growth in [Growth::Linear, Growth::Linearithmic, Growth::Quadratic]
+        {
+            assert!(report(growth, samples(growth, 3.0)).is_claim_verified());
+        }
+    }
+
+    #[test]
+    fn linear_and_linearithmic_are_accepted_for_each_other() {
+        assert!(report(Growth::Linearithmic, samples(Growth::Linear, 3.0))
+            .is_claim_verified());
+        assert!(report(Growth::Linear, samples(Growth::Linearithmic, 3.0))
+            .is_claim_verified());
+    }
+
+    #[test]
+    fn other_claims_are_rejected() {
+        let quadratic = || samples(Growth::Quadratic, 3.0);
+        assert!(!report(Growth::Linear, quadratic()).is_claim_verified());
+        assert!(!report(Growth::Linearithmic, quadratic()).is_claim_verified());
+        assert!(!report(Growth::Cubic, quadratic()).is_claim_verified());
+        assert!(!report(Growth::Constant, samples(Growth::Linear, 3.0))
+            .is_claim_verified());
+        assert!(
+            !report(Growth::Quadratic, samples(Growth::Linearithmic, 3.0))
+                .is_claim_verified()
+        );
+    }
+}
@@ -0,0 +1,148 @@
+/**
+ * A line diff between an expected and an actual text.
+ *
+ * The diff is computed with the classic longest common
+ * subsequence (LCS) table: lines which are part of the LCS
+ * are the same in both texts, the rest were removed from
+ * the expected text or added in the actual text.
+ *
+ * Time and memory are O(expected_lines * actual_lines),
+ * which is fine for example outputs.
+ */
+#[derive(PartialEq, Eq, Debug)]
+pub enum DiffLine<'a> {
+    Same(&'a str),
+    /** Exists in the expected text only. */
+    Removed(&'a str),
+    /** Exists in the actual text only. */
+    Added(&'a str),
+}
+
+pub struct LineDiff;
+
+impl LineDiff {
+    /**
+     * @param expected the expected text.
+     * @param actual the actual text.
+     * @return the diff, line by line, in text order.
+     */
+    pub fn diff<'a>(expected: &'a str, actual: &'a str) -> Vec<DiffLine<'a>> {
+        let exp_lines: Vec<&str> = expected.lines().collect();
+        let act_lines: Vec<&str> = actual.lines().collect();
+        let rows: usize = exp_lines.len();
+        let cols: usize = act_lines.len();
+
+        /*
+         * lcs[i][j] is the LCS length of exp_lines[i..] and
+         * act_lines[j..]. A single vector is used as a 2D
+         * table of (rows + 1) * (cols + 1).
+         */
+        let width: usize = cols + 1;
+        let mut lcs: Vec<usize> = vec![0; (rows + 1) * width];
+        for i in (0..rows).rev() {
+            for j in (0..cols).rev() {
+                lcs[i * width + j] = if exp_lines[i] == act_lines[j] {
+                    lcs[(i + 1) * width + j + 1] + 1
+                } else {
+                    std::cmp::max(
+                        lcs[(i + 1) * width + j],
+                        lcs[i * width + j + 1],
+                    )
+                };
+            }
+        }
+
+        /* Walk the table from the top left corner. */
+        let mut res: Vec<DiffLine<'a>> = Vec::new();
+        let mut i: usize = 0;
+        let mut j: usize = 0;
+        while i < rows && j < cols {
+            if exp_lines[i] == act_lines[j] {
+                res.push(DiffLine::Same(exp_lines[i]));
+                i += 1;
+                j += 1;
+            } else if lcs[(i + 1) * width + j] >= lcs[i * width + j + 1] {
+                res.push(DiffLine::Removed(exp_lines[i]));
+                i += 1;
+            } else {
+                res.push(DiffLine::Added(act_lines[j]));
+                j += 1;
+            }
+        }
+        for &line in &exp_lines[i..] {
+            res.push(DiffLine::Removed(line));
+        }
+        for &line in &act_lines[j..] {
+            res.push(DiffLine::Added(line));
+        }
+        return res;
+    }
+
+    /**
+     * @return true when the texts have the same lines.
+     */
+    pub fn is_same(diff: &[DiffLine]) -> bool {
+        return diff.iter().all(|line| matches!(line, DiffLine::Same(_)));
+    }
+
+    /**
+     * Renders the changed lines of a diff with a few
+     * unchanged lines around them, like "diff -u":
+     * @@ expected line 3, actual line 3 @@
+     *   same line
+     * - expected line
+     * + actual line
+     *
+     * @param diff a diff returned by LineDiff::diff().
+     * @param context the number of unchanged lines to show
+     * before and after each change.
+     */
+    pub fn render(diff: &[DiffLine], context: usize) -> String {
+        /* Mark the lines which need to be displayed. */
+        let mut shown: Vec<bool> = vec![false; diff.len()];
+        for (idx, line) in diff.iter().enumerate() {
+            if !matches!(line, DiffLine::Same(_)) {
+                let first: usize = idx.saturating_sub(context);
+                let last: usize = std::cmp::min(idx + context, diff.len() - 1);
+                for show in &mut shown[first..=last] {
+                    *show = true;
+                }
+            }
+        }
+
+        let mut res: String = String::new();
+        /* Line numbers (1 based) in the expected/actual texts. */
+        let mut exp_line_num: usize = 1;
+        let mut act_line_num: usize = 1;
+        let mut in_hunk: bool = false;
+        for (idx, line) in diff.iter().enumerate() {
+            if shown[idx] {
+                if !in_hunk {
+                    res.push_str(&format!(
+                        "@@ expected line {exp_line_num}, \
+                         actual line {act_line_num} @@\n"
+                    ));
+                    in_hunk = true;
+                }
+                match *line {
+                    DiffLine::Same(text) => res.push_str(&format!("  {text}\n")),
+                    DiffLine::Removed(text) => {
+                        res.push_str(&format!("- {text}\n"))
+                    }
+                    DiffLine::Added(text) => res.push_str(&format!("+ {text}\n")),
+                }
+            } else {
+                in_hunk = false;
+            }
+            match *line {
+                DiffLine::Same(_) => {
+                    exp_line_num += 1;
+                    act_line_num += 1;
+                }
+                DiffLine::Removed(_) => exp_line_num += 1,
+                DiffLine::Added(_) => act_line_num += 1,
+            }
+        }
+        return res;
+    }
+}
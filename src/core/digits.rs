@@ -0,0 +1,434 @@
+use crate::core::return_code::RC;
+
+/*
+ * Digit utilities for all the primitive integer types, in
+ * any radix from 2 to 36.
+ *
+ * Usage example:
+ * Digits::new(-907).max() == 9
+ * Digits::with_radix(255u8, 16)?.len() == 2
+ * Digits::new(1534236469i32).reversed() == None (overflow)
+ *
+ * The digits of a negative number are the digits of its
+ * absolute value (the sign is not a digit). The absolute
+ * value is computed as a u128 magnitude: i64::MIN (and even
+ * i128::MIN) has no positive counterpart in its own type,
+ * but its magnitude fits in a u128.
+ */
+
+/**
+ * The primitive integers, seen as a sign and a magnitude.
+ */
+pub trait Integer: Copy + std::fmt::Debug {
+    fn is_negative(self) -> bool;
+
+    /** The absolute value, exact for the MIN values too. */
+    fn magnitude(self) -> u128;
+
+    /**
+     * @return None when the value does not fit in the type,
+     * e.g. a negative magnitude for an unsigned type.
+     */
+    fn from_magnitude(negative: bool, magnitude: u128) -> Option<Self>;
+}
+
+macro_rules! impl_integer_for_signed {
+    ($($int:ty),*) => {
+        $(
+            impl Integer for $int {
+                fn is_negative(self) -> bool {
+                    return self < 0;
+                }
+
+                fn magnitude(self) -> u128 {
+                    return self.unsigned_abs() as u128;
+                }
+
+                fn from_magnitude(
+                    negative: bool,
+                    magnitude: u128,
+                ) -> Option<$int> {
+                    if !negative || magnitude == 0 {
+                        return <$int>::try_from(magnitude).ok();
+                    }
+                    /*
+                     * -magnitude = -(magnitude - 1) - 1, so MIN
+                     * (whose magnitude is MAX + 1) does not
+                     * overflow.
+                     */
+                    let below: $int = <$int>::try_from(magnitude - 1).ok()?;
+                    return Some(-below - 1);
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! impl_integer_for_unsigned {
+    ($($int:ty),*) => {
+        $(
+            impl Integer for $int {
+                fn is_negative(self) -> bool {
+                    return false;
+                }
+
+                fn magnitude(self) -> u128 {
+                    return self as u128;
+                }
+
+                fn from_magnitude(
+                    negative: bool,
+                    magnitude: u128,
+                ) -> Option<$int> {
+                    if negative && magnitude != 0 {
+                        return None;
+                    }
+                    return <$int>::try_from(magnitude).ok();
+                }
+            }
+        )*
+    };
+}
+
+impl_integer_for_signed!(i8, i16, i32, i64, i128, isize);
+impl_integer_for_unsigned!(u8, u16, u32, u64, u128, usize);
+
+/**
+ * The order the digits are iterated in.
+ */
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Order {
+    /** 907 -> 9, 0, 7 (the written order). */
+    MostSignificantFirst,
+    /** 907 -> 7, 0, 9. */
+    LeastSignificantFirst,
+}
+
+/**
+ * A number and the radix its digits are taken in.
+ */
+pub struct Digits<T: Integer> {
+    m_num: T,
+    m_radix: u32,
+}
+
+impl<T: Integer> Digits<T> {
+    pub const MIN_RADIX: u32 = 2;
+    pub const MAX_RADIX: u32 = 36;
+
+    /**
+     * The decimal digits of num.
+     */
+    pub fn new(num: T) -> Digits<T> {
+        return Digits {
+            m_num: num,
+            m_radix: 10,
+        };
+    }
+
+    /**
+     * @return RC::CInvalidArgument when the radix is not in
+     * [2, 36] (the digits of radix 36 are 0-9 and a-z).
+     */
+    pub fn with_radix(num: T, radix: u32) -> Result<Digits<T>, RC> {
+        if !(Digits::<T>::MIN_RADIX..=Digits::<T>::MAX_RADIX).contains(&radix) {
+            return Err(RC::invalid_argument(
+                "radix",
+                &format!("{radix} is not in [2, 36]"),
+            ));
+        }
+        return Ok(Digits {
+            m_num: num,
+            m_radix: radix,
+        });
+    }
+
+    pub fn num(&self) -> T {
+        return self.m_num;
+    }
+
+    pub fn radix(&self) -> u32 {
+        return self.m_radix;
+    }
+
+    pub fn iter(&self, order: Order) -> DigitIter {
+        let magnitude: u128 = self.m_num.magnitude();
+        let radix: u128 = self.m_radix as u128;
+        let len: usize = self.len();
+        /* radix^(len - 1) <= magnitude, so it cannot overflow. */
+        let divisor: u128 = match order {
+            Order::MostSignificantFirst => radix.pow(len as u32 - 1),
+            Order::LeastSignificantFirst => 1,
+        };
+        return DigitIter {
+            m_rest: magnitude,
+            m_radix: radix,
+            m_order: order,
+            m_divisor: divisor,
+            m_len: len,
+        };
+    }
+
+    /**
+     * @return the number of digits, 1 for 0.
+     */
+    #[allow(clippy::len_without_is_empty)]
+    pub fn len(&self) -> usize {
+        let radix: u128 = self.m_radix as u128;
+        let mut magnitude: u128 = self.m_num.magnitude() / radix;
+        let mut len: usize = 1;
+        while magnitude != 0 {
+            magnitude /= radix;
+            len += 1;
+        }
+        return len;
+    }
+
+    pub fn max(&self) -> u32 {
+        return self.least_first().max().unwrap_or(0);
+    }
+
+    pub fn min(&self) -> u32 {
+        return self.least_first().min().unwrap_or(0);
+    }
+
+    /**
+     * The sum cannot overflow: a u128 has at most 128
+     * digits (in radix 2), each at most 35.
+     */
+    pub fn sum(&self) -> u32 {
+        return self.least_first().sum();
+    }
+
+    /**
+     * The number with its digits reversed, the sign is kept
+     * and the leading zeros of the result are dropped
+     * (120 -> 21, -123 -> -321).
+     * @return None when the reversed number overflows T,
+     * e.g. 1534236469i32 -> 9646324351.
+     */
+    pub fn reversed(&self) -> Option<T> {
+        let radix: u128 = self.m_radix as u128;
+        let mut reversed: u128 = 0;
+        for digit in self.least_first() {
+            reversed =
+                reversed.checked_mul(radix)?.checked_add(digit as u128)?;
+        }
+        return T::from_magnitude(self.m_num.is_negative(), reversed);
+    }
+
+    /**
+     * @return true when the digits read the same in both
+     * directions. A negative number is not a palindrome,
+     * "-121" reversed is "121-" (the leet code convention).
+     */
+    pub fn is_palindrome(&self) -> bool {
+        if self.m_num.is_negative() {
+            return false;
+        }
+        return self
+            .least_first()
+            .eq(self.iter(Order::MostSignificantFirst));
+    }
+
+    fn least_first(&self) -> DigitIter {
+        return self.iter(Order::LeastSignificantFirst);
+    }
+}
+
+/**
+ * The digits of a magnitude, see Digits::iter.
+ */
+pub struct DigitIter {
+    m_rest: u128,
+    m_radix: u128,
+    m_order: Order,
+    /**
+     * The weight of the next digit for MostSignificantFirst
+     * (divided by the radix after each digit).
+     */
+    m_divisor: u128,
+    /** The number of digits left. */
+    m_len: usize,
+}
+
+impl Iterator for DigitIter {
+    type Item = u32;
+
+    fn next(&mut self) -> Option<u32> {
+        if self.m_len == 0 {
+            return None;
+        }
+        self.m_len -= 1;
+        match self.m_order {
+            Order::LeastSignificantFirst => {
+                let digit: u128 = self.m_rest % self.m_radix;
+                self.m_rest /= self.m_radix;
+                return Some(digit as u32);
+            }
+            Order::MostSignificantFirst => {
+                let digit: u128 = self.m_rest / self.m_divisor;
+                self.m_rest %= self.m_divisor;
+                self.m_divisor /= self.m_radix;
+                return Some(digit as u32);
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        return (self.m_len, Some(self.m_len));
+    }
+}
+
+impl ExactSizeIterator for DigitIter {}
+
+//_____________________________________________________________________________
+/**
+ * Compares Digits with a reference based on the std
+ * formatting of the absolute value ({:b}, {:o}, {} and
+ * {:x}), see core::fuzz. The input is a number and the
+ * index of the radix in RADIXES.
+ */
+pub struct DigitsFuzz;
+
+impl DigitsFuzz {
+    const RADIXES: [u32; 4] = [2, 8, 10, 16];
+
+    fn radix(idx: u8) -> u32 {
+        return DigitsFuzz::RADIXES[idx as usize % DigitsFuzz::RADIXES.len()];
+    }
+}
+
+/** (len, max, min, sum, reversed, is_palindrome). */
+type DigitsSummary = (usize, u32, u32, u32, Option<i64>, bool);
+
+impl crate::core::fuzz::Differential for DigitsFuzz {
+    type Input = (i64, u8);
+    type Output = DigitsSummary;
+
+    fn name(&self) -> &'static str {
+        return "digits";
+    }
+
+    fn reference((num, radix_idx): (i64, u8)) -> DigitsSummary {
+        let radix: u32 = DigitsFuzz::radix(radix_idx);
+        let abs: u64 = num.unsigned_abs();
+        let text: String = match radix {
+            2 => format!("{abs:b}"),
+            8 => format!("{abs:o}"),
+            10 => format!("{abs}"),
+            _ => format!("{abs:x}"),
+        };
+        let digits: Vec<u32> = text
+            .chars()
+            .map(|ch| ch.to_digit(radix).unwrap_or(u32::MAX))
+            .collect();
+        let reversed_text: String = text.chars().rev().collect();
+        let sign: &str = if num < 0 { "-" } else { "" };
+        let reversed: Option<i64> =
+            i64::from_str_radix(&format!("{sign}{reversed_text}"), radix).ok();
+        return (
+            digits.len(),
+            digits.iter().copied().max().unwrap_or(0),
+            digits.iter().copied().min().unwrap_or(0),
+            digits.iter().sum(),
+            reversed,
+            num >= 0 && text == reversed_text,
+        );
+    }
+
+    fn candidate((num, radix_idx): (i64, u8)) -> DigitsSummary {
+        let digits: Digits<i64> =
+            match Digits::with_radix(num, DigitsFuzz::radix(radix_idx)) {
+                Ok(digits) => digits,
+                Err(rc) => panic!("{rc}"),
+            };
+        return (
+            digits.len(),
+            digits.max(),
+            digits.min(),
+            digits.sum(),
+            digits.reversed(),
+            digits.is_palindrome(),
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /** @return the digits in the written order. */
+    fn digits<T: Integer>(digits: &Digits<T>) -> Vec<u32> {
+        return digits.iter(Order::MostSignificantFirst).collect();
+    }
+
+    #[test]
+    fn zero_and_negatives() {
+        let zero: Digits<i32> = Digits::new(0);
+        assert_eq!((zero.len(), digits(&zero)), (1, vec![0]));
+        assert_eq!(zero.reversed(), Some(0));
+        let negative: Digits<i32> = Digits::new(-907);
+        assert_eq!(digits(&negative), [9, 0, 7]);
+        assert_eq!(
+            negative
+                .iter(Order::LeastSignificantFirst)
+                .collect::<Vec<u32>>(),
+            [7, 0, 9]
+        );
+        assert_eq!((negative.max(), negative.min()), (9, 0));
+        assert_eq!(negative.reversed(), Some(-709));
+        assert!(!Digits::new(-121).is_palindrome());
+        assert!(Digits::new(121).is_palindrome());
+    }
+
+    #[test]
+    fn min_values() {
+        let min: Digits<i64> = Digits::new(i64::MIN);
+        assert_eq!(min.len(), 19);
+        assert_eq!(digits(&min)[..4], [9, 2, 2, 3]);
+        assert_eq!(min.sum(), 89);
+        assert_eq!(min.reversed(), Some(-8085774586302733229));
+        assert_eq!(Digits::new(i128::MIN).len(), 39);
+        let i8_min: Digits<i8> = Digits::with_radix(i8::MIN, 2).unwrap();
+        assert_eq!(digits(&i8_min), [1, 0, 0, 0, 0, 0, 0, 0]);
+        assert_eq!(i8_min.reversed(), Some(-1));
+    }
+
+    #[test]
+    fn radix_bounds() {
+        let binary: Digits<u128> = Digits::with_radix(u128::MAX, 2).unwrap();
+        assert_eq!((binary.len(), binary.sum()), (128, 128));
+        assert_eq!(binary.reversed(), Some(u128::MAX));
+        let base36: Digits<u8> = Digits::with_radix(35, 36).unwrap();
+        assert_eq!((base36.len(), base36.max()), (1, 35));
+        assert_eq!(digits(&Digits::with_radix(36u8, 36).unwrap()), [1, 0]);
+        assert_eq!(Digits::with_radix(u128::MAX, 36).unwrap().len(), 25);
+    }
+
+    #[test]
+    fn radix_out_of_range() {
+        for radix in [0, 1, 37, u32::MAX] {
+            match Digits::with_radix(10, radix) {
+                Err(RC::CInvalidArgument { .. }) => {}
+                Err(rc) => panic!("radix {radix}: unexpected {rc}"),
+                Ok(_) => panic!("radix {radix}: expected an error"),
+            }
+        }
+    }
+
+    #[test]
+    fn reversal_overflow() {
+        assert_eq!(Digits::new(1534236469i32).reversed(), None);
+        assert_eq!(Digits::new(-1563847412i32).reversed(), None);
+        assert_eq!(Digits::new(1463847412i32).reversed(), Some(2147483641));
+        assert_eq!(Digits::new(250u8).reversed(), Some(52));
+        assert_eq!(Digits::new(199u8).reversed(), None);
+        assert_eq!(Digits::new(120).reversed(), Some(21));
+    }
+
+    #[test]
+    fn matches_the_std_formatting() {
+        crate::core::fuzz::assert_agree(&DigitsFuzz, 2000);
+    }
+}
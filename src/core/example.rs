@@ -0,0 +1,92 @@
+/*
+ * The Example trait is the contract between an example (or
+ * a leet code solution) and the runner binary.
+ *
+ * Each example module implements it for a unit struct, so
+ * the runner can list and run the examples by name without
+ * editing main.rs every time.
+ */
+
+/**
+ * The category of an example, it matches the directory the
+ * example lives in (src/examples or src/leet_code).
+ */
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Category {
+    Examples,
+    LeetCode,
+}
+
+impl Category {
+    /**
+     * @return all the categories, in the order they are
+     * listed by the runner.
+     */
+    pub fn all() -> [Category; 2] {
+        return [Category::Examples, Category::LeetCode];
+    }
+
+    /**
+     * @return the category name as used on the command
+     * line (the module name).
+     */
+    pub fn as_str(&self) -> &'static str {
+        match *self {
+            Category::Examples => "examples",
+            Category::LeetCode => "leet_code",
+        }
+    }
+
+    /**
+     * @param name a category name as returned by as_str().
+     * @return the matching category, None if there is no
+     * such category.
+     */
+    pub fn from_name(name: &str) -> Option<Category> {
+        return Category::all()
+            .into_iter()
+            .find(|category| category.as_str() == name);
+    }
+}
+
+pub trait Example {
+    /**
+     * The example name, used by "run <name>". By
+     * convention it's the module (file) name.
+     */
+    fn name(&self) -> &'static str;
+
+    fn category(&self) -> Category;
+
+    /**
+     * A single line describing what the example shows.
+     */
+    fn description(&self) -> &'static str;
+
+    /**
+     * Runs the example.
+     * @return an error when the example cannot complete,
+     * the runner reports it and exits with its exit code.
+     */
+    fn run(&self) -> Result<(), crate::core::return_code::RC>;
+
+    /**
+     * @return false when the example's output changes from
+     * run to run (threads, memory addresses), such an
+     * example is not compared with a golden file.
+     */
+    fn is_deterministic(&self) -> bool {
+        return true;
+    }
+
+    /**
+     * @return the scenarios which are expected to panic, the
+     * runner runs them after the example (when it
+     * succeeded) and checks their panic messages.
+     */
+    fn panic_scenarios(
+        &self,
+    ) -> Vec<crate::core::panic_scenario::PanicScenario> {
+        return Vec::new();
+    }
+}
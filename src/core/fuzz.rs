@@ -0,0 +1,643 @@
+use crate::core::return_code::RC;
+use crate::core::runner::Runner;
+
+/*
+ * Randomized differential testing.
+ *
+ * A target has a reference solution (simple and obviously
+ * correct, e.g. based on strings) and a candidate solution
+ * (the optimized one). Random inputs are generated from a
+ * seed, both solutions are run on each input and their
+ * outputs are compared. A failing input is shrunk to a
+ * minimal counterexample, e.g. get_max_digit(-907) shrinks
+ * to get_max_digit(-1).
+ *
+ * Each case has its own seed (printed with the failure), so
+ * a case can be replayed alone with Fuzz::replay().
+ */
+
+/**
+ * A small seeded pseudo random generator (splitmix64). It's
+ * not cryptographic, but it's fast, has no dependencies and
+ * the same seed always generates the same numbers.
+ */
+pub struct Rng {
+    m_state: u64,
+}
+
+impl Rng {
+    pub fn new(seed: u64) -> Rng {
+        return Rng { m_state: seed };
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.m_state = self.m_state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut res: u64 = self.m_state;
+        res = (res ^ (res >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        res = (res ^ (res >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        return res ^ (res >> 31);
+    }
+
+    /**
+     * @return a number in [0, bound), bound must not be 0.
+     * Note: the modulo bias is negligible for the bounds
+     * used here.
+     */
+    pub fn below(&mut self, bound: u64) -> u64 {
+        return self.next_u64() % bound;
+    }
+
+    /**
+     * @return a number in [low, high].
+     */
+    pub fn range_i128(&mut self, low: i128, high: i128) -> i128 {
+        let span: u128 = (high - low) as u128 + 1;
+        let random: u128 =
+            ((self.next_u64() as u128) << 64) | self.next_u64() as u128;
+        /* span is 0 when the range is the whole u128. */
+        if span == 0 {
+            return random as i128;
+        }
+        return low + (random % span) as i128;
+    }
+
+    pub fn bool(&mut self) -> bool {
+        return self.next_u64() & 1 == 1;
+    }
+}
+
+//_____________________________________________________________________________
+/**
+ * A type whose values can be generated and shrunk.
+ */
+pub trait Arbitrary: Clone + std::fmt::Debug + Send + 'static {
+    /**
+     * @param size bounds the values (e.g. about -size..size)
+     * and the vector lengths. It's drawn for each case from
+     * the case seed, small sizes more often than large ones
+     * (see Fuzz::check()).
+     */
+    fn generate(rng: &mut Rng, size: usize) -> Self;
+
+    /**
+     * @return simpler values than self, the simplest first.
+     * An empty vector when self cannot be simplified.
+     */
+    fn shrink(&self) -> Vec<Self>;
+}
+
+macro_rules! impl_arbitrary_for_int {
+    ($($int:ty),*) => {
+        $(
+            impl Arbitrary for $int {
+                /**
+                 * Half of the values are small (about
+                 * -size..size), a quarter are edge values
+                 * (MIN, MAX, 0, -1, 1) and a quarter are
+                 * from the whole range.
+                 */
+                fn generate(rng: &mut Rng, size: usize) -> $int {
+                    let edges: [i128; 5] = [
+                        <$int>::MIN as i128,
+                        <$int>::MAX as i128,
+                        0,
+                        -1,
+                        1,
+                    ];
+                    let val: i128 = match rng.below(4) {
+                        0 | 1 => {
+                            let size: i128 = size as i128 + 1;
+                            rng.range_i128(-size, size)
+                        }
+                        2 => edges[rng.below(edges.len() as u64) as usize],
+                        _ => rng.range_i128(
+                            <$int>::MIN as i128,
+                            <$int>::MAX as i128,
+                        ),
+                    };
+                    /* Clamped, e.g. -1 for an unsigned type. */
+                    let val: i128 = val.clamp(
+                        <$int>::MIN as i128,
+                        <$int>::MAX as i128,
+                    );
+                    return val as $int;
+                }
+
+                /**
+                 * Towards 0: 0, half of the value, the value
+                 * minus one, and the positive value.
+                 */
+                fn shrink(&self) -> Vec<$int> {
+                    let val: i128 = *self as i128;
+                    if val == 0 {
+                        return Vec::new();
+                    }
+                    let mut candidates: Vec<i128> =
+                        vec![0, val / 2, val - val.signum()];
+                    if val < 0 {
+                        candidates.push(-val);
+                    }
+                    let mut res: Vec<$int> = Vec::new();
+                    for candidate in candidates {
+                        if let Ok(candidate) = <$int>::try_from(candidate) {
+                            if candidate != *self && !res.contains(&candidate)
+                            {
+                                res.push(candidate);
+                            }
+                        }
+                    }
+                    return res;
+                }
+            }
+        )*
+    };
+}
+
+impl_arbitrary_for_int!(i8, i16, i32, i64, u8, u16, u32, u64, usize);
+
+impl Arbitrary for bool {
+    fn generate(rng: &mut Rng, _size: usize) -> bool {
+        return rng.bool();
+    }
+
+    fn shrink(&self) -> Vec<bool> {
+        if *self {
+            return vec![false];
+        }
+        return Vec::new();
+    }
+}
+
+impl<T: Arbitrary> Arbitrary for Vec<T> {
+    fn generate(rng: &mut Rng, size: usize) -> Vec<T> {
+        let len: usize = rng.below(size as u64 + 1) as usize;
+        return (0..len).map(|_| T::generate(rng, size)).collect();
+    }
+
+    /**
+     * Removing the first or second half, removing a single
+     * element, then shrinking a single element.
+     */
+    fn shrink(&self) -> Vec<Vec<T>> {
+        let mut res: Vec<Vec<T>> = Vec::new();
+        let len: usize = self.len();
+        if len > 1 {
+            res.push(self[len / 2..].to_vec());
+            res.push(self[..len / 2].to_vec());
+        }
+        for idx in 0..len {
+            let mut smaller: Vec<T> = self.clone();
+            smaller.remove(idx);
+            res.push(smaller);
+        }
+        for idx in 0..len {
+            for elem in self[idx].shrink() {
+                let mut simpler: Vec<T> = self.clone();
+                simpler[idx] = elem;
+                res.push(simpler);
+            }
+        }
+        return res;
+    }
+}
+
+impl<T: Arbitrary> Arbitrary for Option<T> {
+    /**
+     * A quarter of the values are None.
+     */
+    fn generate(rng: &mut Rng, size: usize) -> Option<T> {
+        if rng.below(4) == 0 {
+            return None;
+        }
+        return Some(T::generate(rng, size));
+    }
+
+    /**
+     * None first, then the shrunk values.
+     */
+    fn shrink(&self) -> Vec<Option<T>> {
+        match self {
+            None => return Vec::new(),
+            Some(val) => {
+                let mut res: Vec<Option<T>> = vec![None];
+                res.extend(val.shrink().into_iter().map(Some));
+                return res;
+            }
+        }
+    }
+}
+
+macro_rules! impl_arbitrary_for_tuple {
+    ($($name:ident: $idx:tt),*) => {
+        impl<$($name: Arbitrary),*> Arbitrary for ($($name,)*) {
+            fn generate(rng: &mut Rng, size: usize) -> ($($name,)*) {
+                return ($($name::generate(rng, size),)*);
+            }
+
+            /**
+             * Shrinks a single element at a time.
+             */
+            fn shrink(&self) -> Vec<($($name,)*)> {
+                let mut res: Vec<($($name,)*)> = Vec::new();
+                $(
+                    for elem in self.$idx.shrink() {
+                        let mut simpler: Self = self.clone();
+                        simpler.$idx = elem;
+                        res.push(simpler);
+                    }
+                )*
+                return res;
+            }
+        }
+    };
+}
+
+impl_arbitrary_for_tuple!(A: 0, B: 1);
+impl_arbitrary_for_tuple!(A: 0, B: 1, C: 2);
+
+//_____________________________________________________________________________
+/**
+ * A reference and a candidate solution of the same problem.
+ */
+pub trait Differential {
+    type Input: Arbitrary;
+    type Output: PartialEq + std::fmt::Debug;
+
+    /**
+     * The target name, by convention it's the name of the
+     * candidate function.
+     */
+    fn name(&self) -> &'static str;
+
+    fn reference(input: Self::Input) -> Self::Output;
+
+    fn candidate(input: Self::Input) -> Self::Output;
+}
+
+/**
+ * A Differential with its types erased, so targets with
+ * different types can be registered in the same list (see
+ * leet_code::fuzz_targets()).
+ */
+pub trait FuzzTarget {
+    fn name(&self) -> &'static str;
+
+    /**
+     * @return RC::CMismatch with the shrunk counterexample
+     * when the solutions disagree.
+     */
+    fn fuzz(&self, fuzz: &Fuzz) -> Result<FuzzReport, RC>;
+
+    /**
+     * Runs the single case generated by case_seed.
+     */
+    fn replay(&self, fuzz: &Fuzz, case_seed: u64) -> Result<FuzzReport, RC>;
+}
+
+impl<D: Differential> FuzzTarget for D {
+    fn name(&self) -> &'static str {
+        return Differential::name(self);
+    }
+
+    fn fuzz(&self, fuzz: &Fuzz) -> Result<FuzzReport, RC> {
+        return fuzz.run::<D>(Differential::name(self));
+    }
+
+    fn replay(&self, fuzz: &Fuzz, case_seed: u64) -> Result<FuzzReport, RC> {
+        return fuzz.replay::<D>(Differential::name(self), case_seed);
+    }
+}
+
+pub struct FuzzReport {
+    pub name: &'static str,
+    pub cases: usize,
+}
+
+/**
+ * The outcome of running a solution, a panic is an outcome
+ * as well (so a candidate panicking where the reference
+ * does not is a failure).
+ */
+#[derive(PartialEq, Debug)]
+enum Outcome<O> {
+    Returned(O),
+    Panicked(String),
+}
+
+pub struct Fuzz {
+    m_seed: u64,
+    m_cases: usize,
+    m_max_size: usize,
+}
+
+impl Fuzz {
+    pub const DEFAULT_CASES: usize = 1000;
+    /** The largest size of a case (e.g. a vector's length). */
+    pub const DEFAULT_MAX_SIZE: usize = 100;
+    /** Bounds the shrinking of a pathological input. */
+    const MAX_SHRINK_STEPS: usize = 1000;
+
+    pub fn new(seed: u64, cases: usize) -> Fuzz {
+        return Fuzz {
+            m_seed: seed,
+            m_cases: cases,
+            m_max_size: Fuzz::DEFAULT_MAX_SIZE,
+        };
+    }
+
+    /**
+     * @return a seed based on the current time, for runs
+     * without an explicit seed.
+     */
+    pub fn time_seed() -> u64 {
+        let now: std::time::Duration = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap_or_default();
+        return Rng::new(now.as_nanos() as u64).next_u64();
+    }
+
+    pub fn seed(&self) -> u64 {
+        return self.m_seed;
+    }
+
+    /**
+     * Runs the cases, the seed of each case is drawn from
+     * the run's seed.
+     */
+    pub fn run<D: Differential>(
+        &self,
+        name: &'static str,
+    ) -> Result<FuzzReport, RC> {
+        let mut seeds: Rng = Rng::new(self.m_seed);
+        for _ in 0..self.m_cases {
+            self.check::<D>(seeds.next_u64()).map_err(|rc| {
+                rc.context(&format!("run seed {}", self.m_seed))
+            })?;
+        }
+        return Ok(FuzzReport {
+            name,
+            cases: self.m_cases,
+        });
+    }
+
+    pub fn replay<D: Differential>(
+        &self,
+        name: &'static str,
+        case_seed: u64,
+    ) -> Result<FuzzReport, RC> {
+        self.check::<D>(case_seed)?;
+        return Ok(FuzzReport { name, cases: 1 });
+    }
+
+    /**
+     * Generates the input of a case and compares the
+     * solutions, a failing input is shrunk.
+     *
+     * The input only depends on the case seed, so a case can
+     * be replayed from it. The size is drawn first (in
+     * 1..=max size), small sizes are more common than large
+     * ones: the bound of the size is drawn, then the size
+     * below it.
+     */
+    fn check<D: Differential>(&self, case_seed: u64) -> Result<(), RC> {
+        let mut rng: Rng = Rng::new(case_seed);
+        let max_size: u64 = rng.below(self.m_max_size as u64) + 1;
+        let size: usize = rng.below(max_size) as usize + 1;
+        let input: D::Input = D::Input::generate(&mut rng, size);
+        if Fuzz::agree::<D>(&input) {
+            return Ok(());
+        }
+        let (shrunk, steps): (D::Input, usize) =
+            Fuzz::shrink::<D>(input.clone());
+        let expected: Outcome<D::Output> =
+            Fuzz::outcome(D::reference, shrunk.clone());
+        let actual: Outcome<D::Output> =
+            Fuzz::outcome(D::candidate, shrunk.clone());
+        return Err(RC::mismatch(
+            &format!("{expected:?}"),
+            &format!("{actual:?}"),
+        )
+        .context(&format!(
+            "input {shrunk:?} (shrunk in {steps} steps from {input:?})"
+        ))
+        .context(&format!(
+            "case seed {case_seed} (replay it with --replay {case_seed})"
+        )));
+    }
+
+    fn agree<D: Differential>(input: &D::Input) -> bool {
+        return Fuzz::outcome(D::reference, input.clone())
+            == Fuzz::outcome(D::candidate, input.clone());
+    }
+
+    /**
+     * Greedy shrinking: the first simpler input which still
+     * fails replaces the input, till no simpler input fails.
+     * @return the shrunk input and the number of steps.
+     */
+    fn shrink<D: Differential>(mut input: D::Input) -> (D::Input, usize) {
+        let mut steps: usize = 0;
+        'shrinking: while steps < Fuzz::MAX_SHRINK_STEPS {
+            for simpler in input.shrink() {
+                if !Fuzz::agree::<D>(&simpler) {
+                    input = simpler;
+                    steps += 1;
+                    continue 'shrinking;
+                }
+            }
+            break;
+        }
+        return (input, steps);
+    }
+
+    fn outcome<I, O>(solution: fn(I) -> O, input: I) -> Outcome<O> {
+        let mut output: Option<O> = None;
+        let res: Result<(), RC> = Runner::catch_panic(|| {
+            output = Some(solution(input));
+            return Ok(());
+        });
+        match (res, output) {
+            (Ok(()), Some(output)) => return Outcome::Returned(output),
+            (Err(RC::CPanic { message, .. }), _) => {
+                return Outcome::Panicked(message)
+            }
+            (res, _) => {
+                return Outcome::Panicked(format!("unexpected result {res:?}"));
+            }
+        }
+    }
+}
+
+/**
+ * The unit test of a fuzz target: a short run with a fixed
+ * seed, a failure panics with the shrunk counterexample and
+ * its case seed.
+ */
+#[cfg(test)]
+pub fn assert_agree<D: Differential>(target: &D, cases: usize) {
+    if let Err(rc) = target.fuzz(&Fuzz::new(1, cases)) {
+        panic!("{}", rc.render());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /** The candidate is wrong from 10 on. */
+    struct OffByOne;
+
+    impl Differential for OffByOne {
+        type Input = i32;
+        type Output = i32;
+
+        fn name(&self) -> &'static str {
+            return "off_by_one";
+        }
+
+        fn reference(input: i32) -> i32 {
+            return input;
+        }
+
+        fn candidate(input: i32) -> i32 {
+            if input >= 10 {
+                return input + 1;
+            }
+            return input;
+        }
+    }
+
+    /** The candidate panics on vectors holding a 7. */
+    struct PanicOnSeven;
+
+    impl Differential for PanicOnSeven {
+        type Input = Vec<u8>;
+        type Output = usize;
+
+        fn name(&self) -> &'static str {
+            return "panic_on_seven";
+        }
+
+        fn reference(input: Vec<u8>) -> usize {
+            return input.len();
+        }
+
+        fn candidate(input: Vec<u8>) -> usize {
+            assert!(!input.contains(&7), "found a seven");
+            return input.len();
+        }
+    }
+
+    /** Both solutions count the true flags. */
+    struct Count;
+
+    impl Differential for Count {
+        type Input = Vec<(i8, bool)>;
+        type Output = usize;
+
+        fn name(&self) -> &'static str {
+            return "count";
+        }
+
+        fn reference(input: Vec<(i8, bool)>) -> usize {
+            return input.iter().filter(|&&(_, flag)| flag).count();
+        }
+
+        fn candidate(input: Vec<(i8, bool)>) -> usize {
+            return input
+                .iter()
+                .fold(0, |count, &(_, flag)| count + flag as usize);
+        }
+    }
+
+    /** @return the rendered error of the run. */
+    fn failure<D: Differential>(seed: u64) -> String {
+        match Fuzz::new(seed, 1000).run::<D>("target") {
+            Err(rc) => return rc.render(),
+            Ok(_) => panic!("expected a failure"),
+        }
+    }
+
+    #[test]
+    fn rng_is_seeded() {
+        let mut rng1: Rng = Rng::new(7);
+        let mut rng2: Rng = Rng::new(7);
+        for _ in 0..100 {
+            assert_eq!(rng1.next_u64(), rng2.next_u64());
+            assert!(rng1.below(10) < 10);
+            rng2.below(10);
+            let val: i128 = rng1.range_i128(-3, 3);
+            assert!((-3..=3).contains(&val));
+            rng2.range_i128(-3, 3);
+        }
+    }
+
+    #[test]
+    fn vector_lengths_are_bounded_by_the_size() {
+        let mut sizes: Vec<usize> = Vec::new();
+        let mut seeds: Rng = Rng::new(1);
+        for _ in 0..1000 {
+            let input: Vec<u8> =
+                Vec::<u8>::generate(&mut Rng::new(seeds.next_u64()), 5);
+            sizes.push(input.len());
+        }
+        assert!(sizes.iter().all(|&len| len <= 5));
+        assert!(sizes.contains(&0) && sizes.contains(&5));
+    }
+
+    #[test]
+    fn shrinks_to_the_smallest_failing_input() {
+        let rendered: String = failure::<OffByOne>(3);
+        assert!(rendered.contains("input 10 "), "{rendered}");
+        assert!(rendered.contains("Returned(10)"), "{rendered}");
+        assert!(rendered.contains("Returned(11)"), "{rendered}");
+    }
+
+    #[test]
+    fn replays_a_failing_case() {
+        let rendered: String = failure::<OffByOne>(5);
+        let case_seed: u64 = rendered
+            .split("--replay ")
+            .nth(1)
+            .and_then(|rest| rest.split(')').next())
+            .and_then(|seed| seed.parse().ok())
+            .unwrap();
+        let res: Result<FuzzReport, RC> =
+            Fuzz::new(5, 1).replay::<OffByOne>("off_by_one", case_seed);
+        assert!(res.is_err());
+    }
+
+    #[test]
+    fn agreeing_solutions_pass() {
+        let report: FuzzReport =
+            Fuzz::new(1, 500).run::<Count>("count").unwrap();
+        assert_eq!(report.cases, 500);
+    }
+
+    /**
+     * Parallel runs (e.g. the tests) catch their own panics,
+     * each failure is shrunk to [7].
+     */
+    #[test]
+    #[should_panic(expected = "replay it with --replay")]
+    fn assert_agree_panics_with_the_counterexample() {
+        assert_agree(&OffByOne, 1000);
+    }
+
+    #[test]
+    fn panics_are_caught_in_parallel() {
+        let runs: Vec<std::thread::JoinHandle<String>> = (0..8)
+            .map(|seed| {
+                std::thread::spawn(move || failure::<PanicOnSeven>(seed))
+            })
+            .collect();
+        for run in runs {
+            let rendered: String = run.join().unwrap();
+            assert!(rendered.contains("input [7] "), "{rendered}");
+            assert!(
+                rendered.contains("Panicked(\"found a seven\")"),
+                "{rendered}"
+            );
+        }
+    }
+}
@@ -0,0 +1,111 @@
+use crate::core::diff::{DiffLine, LineDiff};
+use crate::core::example::Example;
+use crate::core::output::Output;
+use crate::core::return_code::{ResultExt, RC};
+use crate::core::runner::Runner;
+use std::path::{Path, PathBuf};
+
+/**
+ * Golden (snapshot) files.
+ *
+ * The output of each example is stored in
+ * <golden dir>/<category>/<name>.txt. "verify" runs the
+ * example, captures its output and compares it with the
+ * stored file. "bless" rewrites the file with the current
+ * output, it's used after an intended output change.
+ */
+pub struct Golden {
+    m_dir: PathBuf,
+}
+
+/**
+ * The result of verifying a single example.
+ */
+pub enum GoldenStatus {
+    Match,
+    /** The rendered diff, expected (golden) vs actual. */
+    Mismatch(String),
+    /** There is no golden file, run bless to create it. */
+    Missing,
+    /** The example's output is not deterministic. */
+    Skipped,
+}
+
+impl Golden {
+    /** Relative to the directory the binary is run from. */
+    pub const DEFAULT_DIR: &'static str = "data/golden";
+
+    /** Unchanged lines shown around each change of a diff. */
+    const DIFF_CONTEXT: usize = 2;
+
+    pub fn new(dir: &Path) -> Golden {
+        return Golden {
+            m_dir: dir.to_path_buf(),
+        };
+    }
+
+    /**
+     * @return the golden file path of an example.
+     */
+    pub fn path(&self, example: &dyn Example) -> PathBuf {
+        return self
+            .m_dir
+            .join(example.category().as_str())
+            .join(format!("{}.txt", example.name()));
+    }
+
+    /**
+     * Runs the example and compares its output with its
+     * golden file.
+     * @return an error when the example fails or the golden
+     * file cannot be read.
+     */
+    pub fn verify(&self, example: &dyn Example) -> Result<GoldenStatus, RC> {
+        if !example.is_deterministic() {
+            return Ok(GoldenStatus::Skipped);
+        }
+        let path: PathBuf = self.path(example);
+        if !path.exists() {
+            return Ok(GoldenStatus::Missing);
+        }
+        let expected: String = std::fs::read_to_string(&path)
+            .context(&format!("reading golden file {}", path.display()))?;
+        let actual: String = Golden::run_captured(example)?;
+        let diff: Vec<DiffLine> = LineDiff::diff(&expected, &actual);
+        if LineDiff::is_same(&diff) {
+            return Ok(GoldenStatus::Match);
+        }
+        return Ok(GoldenStatus::Mismatch(LineDiff::render(
+            &diff,
+            Golden::DIFF_CONTEXT,
+        )));
+    }
+
+    /**
+     * Runs the example and writes its output to its golden
+     * file (the directories are created if needed).
+     * @return false when the example is skipped since its
+     * output is not deterministic.
+     */
+    pub fn bless(&self, example: &dyn Example) -> Result<bool, RC> {
+        if !example.is_deterministic() {
+            return Ok(false);
+        }
+        let path: PathBuf = self.path(example);
+        let output: String = Golden::run_captured(example)?;
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)
+                .context(&format!("creating {}", parent.display()))?;
+        }
+        std::fs::write(&path, output)
+            .context(&format!("writing golden file {}", path.display()))?;
+        return Ok(true);
+    }
+
+    fn run_captured(example: &dyn Example) -> Result<String, RC> {
+        let (res, output): (Result<(), RC>, String) =
+            Output::capture(|| Runner::catch_panic(|| example.run()));
+        res?;
+        return Ok(output);
+    }
+}
@@ -0,0 +1,160 @@
+/**
+ * A minimal JSON value, enough for writing machine readable
+ * output (https://www.json.org) without external crates.
+ *
+ * The Display implementation writes the value as compact
+ * JSON, on a single line, so a value per line is a valid
+ * JSON-lines stream (https://jsonlines.org).
+ */
+#[derive(Clone, PartialEq, Debug)]
+pub enum JsonValue {
+    Null,
+    Bool(bool),
+    Int(i64),
+    UInt(u64),
+    Float(f64),
+    Str(String),
+    Array(Vec<JsonValue>),
+    /** The fields keep their insertion order. */
+    Object(Vec<(String, JsonValue)>),
+}
+
+impl JsonValue {
+    /**
+     * @return an empty object, fields are added with
+     * with_field().
+     */
+    pub fn object() -> JsonValue {
+        return JsonValue::Object(Vec::new());
+    }
+
+    /**
+     * Adds a field to an object (builder style):
+     * JsonValue::object().with_field("name", JsonValue::from("a"))
+     * @note panics when self is not an object.
+     */
+    pub fn with_field(mut self, key: &str, value: JsonValue) -> JsonValue {
+        match self {
+            JsonValue::Object(ref mut fields) => {
+                fields.push((String::from(key), value));
+            }
+            _ => panic!("with_field() called on a non object JSON value"),
+        }
+        return self;
+    }
+
+    /**
+     * Writes a JSON string literal, escaping the characters
+     * JSON does not allow inside a string.
+     */
+    fn write_str(f: &mut std::fmt::Formatter, text: &str) -> std::fmt::Result {
+        write!(f, "\"")?;
+        for ch in text.chars() {
+            match ch {
+                '"' => write!(f, "\\\"")?,
+                '\\' => write!(f, "\\\\")?,
+                '\n' => write!(f, "\\n")?,
+                '\r' => write!(f, "\\r")?,
+                '\t' => write!(f, "\\t")?,
+                ch if (ch as u32) < 0x20 => write!(f, "\\u{:04x}", ch as u32)?,
+                ch => write!(f, "{ch}")?,
+            }
+        }
+        return write!(f, "\"");
+    }
+}
+
+impl std::fmt::Display for JsonValue {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match *self {
+            JsonValue::Null => return write!(f, "null"),
+            JsonValue::Bool(val) => return write!(f, "{val}"),
+            JsonValue::Int(val) => return write!(f, "{val}"),
+            JsonValue::UInt(val) => return write!(f, "{val}"),
+            /* JSON has no NaN and infinity. */
+            JsonValue::Float(val) if !val.is_finite() => {
+                return write!(f, "null")
+            }
+            JsonValue::Float(val) => return write!(f, "{val}"),
+            JsonValue::Str(ref text) => return JsonValue::write_str(f, text),
+            JsonValue::Array(ref items) => {
+                write!(f, "[")?;
+                for (idx, item) in items.iter().enumerate() {
+                    if idx != 0 {
+                        write!(f, ",")?;
+                    }
+                    write!(f, "{item}")?;
+                }
+                return write!(f, "]");
+            }
+            JsonValue::Object(ref fields) => {
+                write!(f, "{{")?;
+                for (idx, (key, value)) in fields.iter().enumerate() {
+                    if idx != 0 {
+                        write!(f, ",")?;
+                    }
+                    JsonValue::write_str(f, key)?;
+                    write!(f, ":{value}")?;
+                }
+                return write!(f, "}}");
+            }
+        }
+    }
+}
+
+impl From<&str> for JsonValue {
+    fn from(text: &str) -> JsonValue {
+        return JsonValue::Str(String::from(text));
+    }
+}
+
+impl From<String> for JsonValue {
+    fn from(text: String) -> JsonValue {
+        return JsonValue::Str(text);
+    }
+}
+
+impl From<bool> for JsonValue {
+    fn from(val: bool) -> JsonValue {
+        return JsonValue::Bool(val);
+    }
+}
+
+impl From<i64> for JsonValue {
+    fn from(val: i64) -> JsonValue {
+        return JsonValue::Int(val);
+    }
+}
+
+impl From<u64> for JsonValue {
+    fn from(val: u64) -> JsonValue {
+        return JsonValue::UInt(val);
+    }
+}
+
+impl From<usize> for JsonValue {
+    fn from(val: usize) -> JsonValue {
+        return JsonValue::UInt(val as u64);
+    }
+}
+
+impl From<f64> for JsonValue {
+    fn from(val: f64) -> JsonValue {
+        return JsonValue::Float(val);
+    }
+}
+
+impl<T: Into<JsonValue>> From<Option<T>> for JsonValue {
+    fn from(val: Option<T>) -> JsonValue {
+        match val {
+            Some(val) => return val.into(),
+            None => return JsonValue::Null,
+        }
+    }
+}
+
+impl<T: Into<JsonValue>> From<Vec<T>> for JsonValue {
+    fn from(items: Vec<T>) -> JsonValue {
+        return JsonValue::Array(items.into_iter().map(|item| item.into()).collect());
+    }
+}
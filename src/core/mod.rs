@@ -1,2 +1,20 @@
+pub mod alloc_stats;
+pub mod assertions;
+pub mod big_uint;
+pub mod callable;
+pub mod cli;
+pub mod complexity;
+pub mod diff;
+pub mod digits;
+pub mod example;
+pub mod fuzz;
+pub mod golden;
+pub mod json;
+pub mod notes;
+pub mod output;
+pub mod panic_scenario;
+pub mod registry;
+pub mod repl;
+pub mod reporter;
 pub mod return_code;
-pub mod utils;
+pub mod runner;
@@ -0,0 +1,456 @@
+use crate::core::reporter::Reporter;
+use crate::core::return_code::RC;
+use std::collections::{BTreeMap, HashMap};
+
+/**
+ * A searchable knowledge base of the doc comments.
+ *
+ * The long doc comments of the examples are the notes
+ * taken while learning rust. The sources are embedded in
+ * the binary (include_str!), each doc comment block is
+ * extracted with the file and the item it documents, and
+ * indexed by topic (the file name) and keyword.
+ *
+ * Note: a new source file has to be added to SOURCES to be
+ * part of the knowledge base.
+ */
+const SOURCES: &[(&str, &str)] = &[
+    ("src/main.rs", include_str!("../main.rs")),
+    ("src/examples/mod.rs", include_str!("../examples/mod.rs")),
+    (
+        "src/examples/bubble_sort.rs",
+        include_str!("../examples/bubble_sort.rs"),
+    ),
+    (
+        "src/examples/concurrency.rs",
+        include_str!("../examples/concurrency.rs"),
+    ),
+    (
+        "src/examples/core_cell.rs",
+        include_str!("../examples/core_cell.rs"),
+    ),
+    (
+        "src/examples/deref.rs",
+        include_str!("../examples/deref.rs"),
+    ),
+    (
+        "src/examples/first_steps.rs",
+        include_str!("../examples/first_steps.rs"),
+    ),
+    (
+        "src/examples/first_word_len.rs",
+        include_str!("../examples/first_word_len.rs"),
+    ),
+    (
+        "src/examples/get_nth_word.rs",
+        include_str!("../examples/get_nth_word.rs"),
+    ),
+    (
+        "src/examples/llist.rs",
+        include_str!("../examples/llist.rs"),
+    ),
+    (
+        "src/examples/llist/doubly.rs",
+        include_str!("../examples/llist/doubly.rs"),
+    ),
+    (
+        "src/examples/llist/queue.rs",
+        include_str!("../examples/llist/queue.rs"),
+    ),
+    (
+        "src/examples/matches.rs",
+        include_str!("../examples/matches.rs"),
+    ),
+    (
+        "src/examples/my_option.rs",
+        include_str!("../examples/my_option.rs"),
+    ),
+    (
+        "src/examples/ownership.rs",
+        include_str!("../examples/ownership.rs"),
+    ),
+    (
+        "src/examples/pointers.rs",
+        include_str!("../examples/pointers.rs"),
+    ),
+    (
+        "src/examples/slices.rs",
+        include_str!("../examples/slices.rs"),
+    ),
+    (
+        "src/examples/trait_impl.rs",
+        include_str!("../examples/trait_impl.rs"),
+    ),
+    (
+        "src/examples/turbo_fish.rs",
+        include_str!("../examples/turbo_fish.rs"),
+    ),
+    (
+        "src/examples/unsafe_func.rs",
+        include_str!("../examples/unsafe_func.rs"),
+    ),
+    (
+        "src/examples/vector.rs",
+        include_str!("../examples/vector.rs"),
+    ),
+    ("src/leet_code/mod.rs", include_str!("../leet_code/mod.rs")),
+    (
+        "src/leet_code/add_two_nums.rs",
+        include_str!("../leet_code/add_two_nums.rs"),
+    ),
+    (
+        "src/leet_code/calc_num_len.rs",
+        include_str!("../leet_code/calc_num_len.rs"),
+    ),
+    (
+        "src/leet_code/get_max_digit.rs",
+        include_str!("../leet_code/get_max_digit.rs"),
+    ),
+    (
+        "src/leet_code/get_nth_word.rs",
+        include_str!("../leet_code/get_nth_word.rs"),
+    ),
+    (
+        "src/leet_code/judge.rs",
+        include_str!("../leet_code/judge.rs"),
+    ),
+    (
+        "src/leet_code/literal.rs",
+        include_str!("../leet_code/literal.rs"),
+    ),
+    (
+        "src/leet_code/progress.rs",
+        include_str!("../leet_code/progress.rs"),
+    ),
+    (
+        "src/leet_code/scaffold.rs",
+        include_str!("../leet_code/scaffold.rs"),
+    ),
+    (
+        "src/leet_code/two_sum.rs",
+        include_str!("../leet_code/two_sum.rs"),
+    ),
+];
+
+/**
+ * A single doc comment block.
+ */
+pub struct Note {
+    pub file: &'static str,
+    /** The (1 based) line the block starts at. */
+    pub line: usize,
+    /**
+     * The first line of the documented item, e.g.
+     * "impl<T> std::ops::Deref for MyBox<T>". None when the
+     * block is followed by another comment (a file level
+     * note).
+     */
+    pub item: Option<String>,
+    /** The text, without the comment markers. */
+    pub text: String,
+}
+
+impl Note {
+    /**
+     * @return the topic of the note, the file name without
+     * the extension, e.g. "deref" for src/examples/deref.rs,
+     * or the directory name for a mod.rs, e.g. "leet_code"
+     * for src/leet_code/mod.rs.
+     */
+    pub fn topic(&self) -> &'static str {
+        let path: &'static str =
+            self.file.strip_suffix(".rs").unwrap_or(self.file);
+        let mut parts = path.rsplit('/');
+        let stem: &'static str = parts.next().unwrap_or(path);
+        if stem == "mod" {
+            return parts.next().unwrap_or(stem);
+        }
+        return stem;
+    }
+}
+
+pub struct Notes {
+    m_notes: Vec<Note>,
+    /** Lower case keyword => indexes of the notes. */
+    m_keywords: HashMap<String, Vec<usize>>,
+    /** Topic => indexes of the notes, sorted by topic. */
+    m_topics: BTreeMap<&'static str, Vec<usize>>,
+}
+
+impl Notes {
+    /**
+     * Single letters ("a", "n") are not indexed, two letters
+     * are ("fn", "rc", "io").
+     */
+    const MIN_KEYWORD_LEN: usize = 2;
+    /** Lines of a note shown in the search results. */
+    const SNIPPET_LINES: usize = 3;
+
+    /**
+     * Extracts and indexes the notes of all the sources.
+     */
+    pub fn new() -> Notes {
+        let mut notes: Vec<Note> = Vec::new();
+        for &(file, source) in SOURCES {
+            notes.extend(Notes::extract(file, source));
+        }
+        let mut keywords: HashMap<String, Vec<usize>> = HashMap::new();
+        let mut topics: BTreeMap<&'static str, Vec<usize>> = BTreeMap::new();
+        for (idx, note) in notes.iter().enumerate() {
+            topics.entry(note.topic()).or_default().push(idx);
+            let item: &str = note.item.as_deref().unwrap_or("");
+            for word in Notes::words(&note.text).chain(Notes::words(item)) {
+                let indexes: &mut Vec<usize> =
+                    keywords.entry(word).or_default();
+                /* A note is indexed once per keyword. */
+                if indexes.last() != Some(&idx) {
+                    indexes.push(idx);
+                }
+            }
+        }
+        return Notes {
+            m_notes: notes,
+            m_keywords: keywords,
+            m_topics: topics,
+        };
+    }
+
+    /**
+     * Extracts the doc comment blocks (the ones starting
+     * with slash-star-star) of a source file.
+     * @param file the file path, kept in the notes.
+     * @param source the file content.
+     */
+    pub fn extract(file: &'static str, source: &str) -> Vec<Note> {
+        let lines: Vec<&str> = source.lines().collect();
+        let mut notes: Vec<Note> = Vec::new();
+        let mut idx: usize = 0;
+        while idx < lines.len() {
+            let trimmed: &str = lines[idx].trim_start();
+            if !trimmed.starts_with("/**") || trimmed.starts_with("/**/") {
+                idx += 1;
+                continue;
+            }
+            let start: usize = idx;
+            let mut text_lines: Vec<String> = Vec::new();
+            /* The text after the opening marker. */
+            let mut rest: &str = &trimmed[3..];
+            loop {
+                let (content, is_last): (&str, bool) = match rest.find("*/") {
+                    Some(end) => (&rest[..end], true),
+                    None => (rest, false),
+                };
+                text_lines.push(Notes::clean_line(content));
+                if is_last {
+                    break;
+                }
+                idx += 1;
+                if idx == lines.len() {
+                    break;
+                }
+                rest = lines[idx].trim_start();
+            }
+            idx += 1;
+            let text: String = Notes::trim_blank_lines(&text_lines);
+            if !text.is_empty() {
+                notes.push(Note {
+                    file,
+                    line: start + 1,
+                    item: Notes::documented_item(
+                        &lines[idx.min(lines.len())..],
+                    ),
+                    text,
+                });
+            }
+        }
+        return notes;
+    }
+
+    /**
+     * @param query one or more words, all of them must
+     * appear in a matching note (case insensitive).
+     * @return the matching notes, the ones mentioning the
+     * words most come first.
+     */
+    pub fn search(&self, query: &str) -> Vec<&Note> {
+        let words: Vec<String> = Notes::words(query).collect();
+        if words.is_empty() {
+            return Vec::new();
+        }
+        let mut matches: Option<Vec<usize>> = None;
+        for word in words.iter() {
+            let indexes: Vec<usize> =
+                self.m_keywords.get(word).cloned().unwrap_or_default();
+            matches = Some(match matches {
+                None => indexes,
+                Some(prev) => prev
+                    .into_iter()
+                    .filter(|idx| indexes.contains(idx))
+                    .collect(),
+            });
+        }
+        let mut res: Vec<&Note> = matches
+            .unwrap_or_default()
+            .into_iter()
+            .map(|idx| &self.m_notes[idx])
+            .collect();
+        /* Stable sort, so equal notes keep the source order. */
+        res.sort_by_key(|note| {
+            let text: String = note.text.to_lowercase();
+            let hits: usize = words
+                .iter()
+                .map(|word| text.matches(word.as_str()).count())
+                .sum();
+            return std::cmp::Reverse(hits);
+        });
+        return res;
+    }
+
+    /**
+     * @param topic a topic (file name without extension).
+     * @return the notes of the topic, in source order.
+     */
+    pub fn topic(&self, topic: &str) -> Result<Vec<&Note>, RC> {
+        match self.m_topics.get(topic) {
+            Some(indexes) => {
+                return Ok(indexes
+                    .iter()
+                    .map(|&idx| &self.m_notes[idx])
+                    .collect());
+            }
+            None => {
+                return Err(RC::not_found(&format!("notes topic '{topic}'")))
+            }
+        }
+    }
+
+    /**
+     * @return the topics and their number of notes.
+     */
+    pub fn topics(&self) -> Vec<(&'static str, usize)> {
+        return self
+            .m_topics
+            .iter()
+            .map(|(&topic, indexes)| (topic, indexes.len()))
+            .collect();
+    }
+
+    /**
+     * Prints the search results, the lines which mention
+     * the query words are shown for each note.
+     */
+    pub fn print_search(&self, query: &str) {
+        let reporter: Reporter = Reporter::new();
+        let notes: Vec<&Note> = self.search(query);
+        reporter.line(&format!("{} notes mention '{query}'", notes.len()));
+        let words: Vec<String> = Notes::words(query).collect();
+        for note in notes {
+            reporter.subsection(&Notes::title(note));
+            let snippet: Vec<&str> = note
+                .text
+                .lines()
+                .filter(|line| {
+                    let line: String = line.to_lowercase();
+                    return words
+                        .iter()
+                        .any(|word| line.contains(word.as_str()));
+                })
+                .take(Notes::SNIPPET_LINES)
+                .collect();
+            for line in snippet {
+                reporter.nested().line(line.trim());
+            }
+        }
+    }
+
+    /**
+     * Prints the full notes of a topic.
+     */
+    pub fn print_topic(&self, topic: &str) -> Result<(), RC> {
+        let reporter: Reporter = Reporter::new();
+        let notes: Vec<&Note> = self.topic(topic)?;
+        reporter.section(&format!("{topic} ({} notes)", notes.len()));
+        for note in notes {
+            reporter.subsection(&Notes::title(note));
+            for line in note.text.lines() {
+                reporter.nested().line(line);
+            }
+        }
+        return Ok(());
+    }
+
+    /**
+     * @return "file:line item", e.g.
+     * "src/examples/deref.rs:43 impl<T> std::ops::Deref for MyBox<T>"
+     */
+    fn title(note: &Note) -> String {
+        match note.item {
+            Some(ref item) => {
+                return format!("{}:{} {}", note.file, note.line, item)
+            }
+            None => return format!("{}:{}", note.file, note.line),
+        }
+    }
+
+    /**
+     * Removes the leading "*" of a doc comment line:
+     * "     * text" => "text", indentation after the "* " is
+     * kept for nested lists.
+     */
+    fn clean_line(line: &str) -> String {
+        let trimmed: &str = line.trim_start();
+        let without_star: &str = match trimmed.strip_prefix('*') {
+            Some(rest) => rest.strip_prefix(' ').unwrap_or(rest),
+            None => trimmed,
+        };
+        return String::from(without_star.trim_end());
+    }
+
+    fn trim_blank_lines(lines: &[String]) -> String {
+        let first: Option<usize> =
+            lines.iter().position(|line| !line.is_empty());
+        let last: Option<usize> =
+            lines.iter().rposition(|line| !line.is_empty());
+        match (first, last) {
+            (Some(first), Some(last)) => return lines[first..=last].join("\n"),
+            _ => return String::new(),
+        }
+    }
+
+    /**
+     * @param lines the lines after a doc comment block.
+     * @return the first line of the item the block
+     * documents, without the opening brace.
+     */
+    fn documented_item(lines: &[&str]) -> Option<String> {
+        for line in lines {
+            let trimmed: &str = line.trim();
+            if trimmed.is_empty() || trimmed.starts_with("#[") {
+                continue;
+            }
+            if trimmed.starts_with("//") || trimmed.starts_with("/*") {
+                return None;
+            }
+            let item: &str = trimmed.trim_end_matches('{').trim_end();
+            return Some(String::from(item));
+        }
+        return None;
+    }
+
+    /**
+     * @return the lower case words of a text, a word is made
+     * of alphanumeric characters and '_'.
+     */
+    fn words(text: &str) -> impl Iterator<Item = String> + '_ {
+        return text
+            .split(|ch: char| !(ch.is_alphanumeric() || ch == '_'))
+            .filter(|word| word.chars().count() >= Notes::MIN_KEYWORD_LEN)
+            .map(|word| word.to_lowercase());
+    }
+}
+
+impl Default for Notes {
+    fn default() -> Notes {
+        return Notes::new();
+    }
+}
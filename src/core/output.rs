@@ -0,0 +1,164 @@
+use std::io::Write;
+use std::sync::{Arc, Mutex};
+
+/**
+ * The output sink of the examples.
+ *
+ * The examples print with the pg_println! macro instead of
+ * println!. By default the text goes to stdout, but the
+ * sink can be replaced (injected), for example by a buffer
+ * which captures the output so it can be compared with a
+ * golden file.
+ *
+ * The sink is global (and not per thread), so the output of
+ * threads spawned by an example is captured as well.
+ *
+ * None means stdout.
+ */
+static SINK: Mutex<Option<Box<dyn Write + Send>>> = Mutex::new(None);
+
+pub struct Output;
+
+impl Output {
+    /**
+     * Writes a formatted line to the current sink. Called
+     * by the pg_println! macro.
+     */
+    pub fn println(args: std::fmt::Arguments) {
+        let mut sink = Output::lock();
+        let res: std::io::Result<()> = match sink.as_mut() {
+            Some(writer) => writer
+                .write_fmt(args)
+                .and_then(|_| writer.write_all(b"\n")),
+            None => {
+                let mut stdout = std::io::stdout().lock();
+                stdout.write_fmt(args).and_then(|_| stdout.write_all(b"\n"))
+            }
+        };
+        /* Like println!, failing to print is a bug. */
+        if let Err(err) = res {
+            panic!("failed printing to the output sink: {err}");
+        }
+    }
+
+    /**
+     * @return true when the output goes to stdout (it's not
+     * captured or redirected to another sink).
+     */
+    pub fn is_stdout() -> bool {
+        return Output::lock().is_none();
+    }
+
+    /**
+     * Replaces the sink.
+     * @param sink the new sink, None for stdout.
+     * @return the previous sink.
+     */
+    pub fn set_sink(
+        sink: Option<Box<dyn Write + Send>>,
+    ) -> Option<Box<dyn Write + Send>> {
+        return std::mem::replace(&mut *Output::lock(), sink);
+    }
+
+    /**
+     * Runs a function and captures everything it prints
+     * with pg_println!.
+     * @return the function's return value and the captured
+     * text.
+     * @note the previous sink is restored also when the
+     * function panics.
+     */
+    pub fn capture<R, F: FnOnce() -> R>(func: F) -> (R, String) {
+        let buffer: CaptureBuffer = CaptureBuffer::new();
+        let _restore: RestoreSink = RestoreSink {
+            m_previous: Output::set_sink(Some(Box::new(buffer.clone()))),
+        };
+        let res: R = func();
+        return (res, buffer.take());
+    }
+
+    /**
+     * A poisoned lock only means an example panicked while
+     * printing, the sink itself is still usable.
+     */
+    fn lock() -> std::sync::MutexGuard<'static, Option<Box<dyn Write + Send>>>
+    {
+        return SINK.lock().unwrap_or_else(|err| err.into_inner());
+    }
+}
+
+/**
+ * A shared, in memory sink. The clone handed to the output
+ * and the one kept by capture() point to the same bytes.
+ */
+#[derive(Clone)]
+pub struct CaptureBuffer {
+    m_bytes: Arc<Mutex<Vec<u8>>>,
+}
+
+impl CaptureBuffer {
+    pub fn new() -> CaptureBuffer {
+        return CaptureBuffer {
+            m_bytes: Arc::new(Mutex::new(Vec::new())),
+        };
+    }
+
+    /**
+     * @return the captured text, the buffer is emptied.
+     */
+    pub fn take(&self) -> String {
+        let bytes: Vec<u8> = std::mem::take(
+            &mut *self.m_bytes.lock().unwrap_or_else(|err| err.into_inner()),
+        );
+        return String::from_utf8_lossy(&bytes).into_owned();
+    }
+}
+
+impl Default for CaptureBuffer {
+    fn default() -> CaptureBuffer {
+        return CaptureBuffer::new();
+    }
+}
+
+impl Write for CaptureBuffer {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.m_bytes
+            .lock()
+            .unwrap_or_else(|err| err.into_inner())
+            .extend_from_slice(buf);
+        return Ok(buf.len());
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        return Ok(());
+    }
+}
+
+/**
+ * Puts the previous sink back when dropped (RAII style), so
+ * a panicking example does not leave the output captured.
+ */
+struct RestoreSink {
+    m_previous: Option<Box<dyn Write + Send>>,
+}
+
+impl Drop for RestoreSink {
+    fn drop(&mut self) {
+        Output::set_sink(self.m_previous.take());
+    }
+}
+
+/**
+ * println! replacement which writes to the output sink.
+ * Same syntax as println!:
+ * pg_println!("num is: {num} its len is: {}", len);
+ */
+#[macro_export]
+macro_rules! pg_println {
+    () => {
+        $crate::core::output::Output::println(format_args!(""))
+    };
+    ($($arg:tt)*) => {
+        $crate::core::output::Output::println(format_args!($($arg)*))
+    };
+}
@@ -0,0 +1,133 @@
+use crate::core::return_code::RC;
+use crate::core::runner::Runner;
+
+/**
+ * A scenario which is expected to panic.
+ *
+ * Some of the most instructive cases are the ones which make
+ * the program panic, e.g. a second borrow_mut() of a RefCell.
+ * Instead of leaving them commented out, an example declares
+ * them (Example::panic_scenarios()) and the runner runs each
+ * of them under catch_unwind, checks the panic message and
+ * reports it with its location. A panicking scenario does
+ * not abort the rest of the run.
+ */
+pub struct PanicScenario {
+    /** A short name, e.g. "double_borrow_mut". */
+    pub name: &'static str,
+    /**
+     * A fragment of the expected panic message, e.g.
+     * "already borrowed". A fragment (and not the whole
+     * message) is used since the std messages change a
+     * bit between rust versions.
+     */
+    pub expected: &'static str,
+    pub scenario: fn(),
+}
+
+/**
+ * What happened when a scenario was run.
+ */
+pub enum ScenarioStatus {
+    /** It panicked with the expected message. */
+    Panicked {
+        message: String,
+        location: Option<String>,
+    },
+    /** It panicked, but with another message. */
+    WrongMessage {
+        message: String,
+        location: Option<String>,
+    },
+    DidNotPanic,
+}
+
+pub struct ScenarioReport {
+    pub name: &'static str,
+    pub expected: &'static str,
+    pub status: ScenarioStatus,
+}
+
+impl PanicScenario {
+    pub fn run(&self) -> ScenarioReport {
+        let scenario: fn() = self.scenario;
+        let status: ScenarioStatus = match Runner::catch_panic(|| {
+            scenario();
+            return Ok(());
+        }) {
+            Err(RC::CPanic { message, location }) => {
+                if message.contains(self.expected) {
+                    ScenarioStatus::Panicked { message, location }
+                } else {
+                    ScenarioStatus::WrongMessage { message, location }
+                }
+            }
+            /* A scenario is a fn() so it cannot return an error. */
+            Ok(()) | Err(_) => ScenarioStatus::DidNotPanic,
+        };
+        return ScenarioReport {
+            name: self.name,
+            expected: self.expected,
+            status,
+        };
+    }
+}
+
+impl ScenarioReport {
+    pub fn is_ok(&self) -> bool {
+        return matches!(self.status, ScenarioStatus::Panicked { .. });
+    }
+
+    /**
+     * @return "panicked", "wrong message" or "did not panic".
+     */
+    pub fn status_str(&self) -> &'static str {
+        match self.status {
+            ScenarioStatus::Panicked { .. } => return "panicked",
+            ScenarioStatus::WrongMessage { .. } => return "wrong message",
+            ScenarioStatus::DidNotPanic => return "did not panic",
+        }
+    }
+
+    /**
+     * @return the panic message and its location, e.g.
+     * "RefCell already borrowed at src/examples/core_cell.rs:380:14",
+     * None when the scenario did not panic.
+     */
+    pub fn panic_description(&self) -> Option<String> {
+        let (message, location): (&String, &Option<String>) = match self.status
+        {
+            ScenarioStatus::Panicked {
+                ref message,
+                ref location,
+            }
+            | ScenarioStatus::WrongMessage {
+                ref message,
+                ref location,
+            } => (message, location),
+            ScenarioStatus::DidNotPanic => return None,
+        };
+        match location {
+            Some(location) => return Some(format!("{message} at {location}")),
+            None => return Some(message.clone()),
+        }
+    }
+
+    /**
+     * @return Ok when the scenario panicked as expected,
+     * otherwise RC::CMismatch, expected vs actual panic.
+     */
+    pub fn to_result(&self) -> Result<(), RC> {
+        if self.is_ok() {
+            return Ok(());
+        }
+        let actual: String = self
+            .panic_description()
+            .unwrap_or_else(|| String::from("no panic"));
+        return Err(RC::mismatch(
+            &format!("a panic like '{}'", self.expected),
+            &actual,
+        )
+        .context(&format!("panic scenario '{}'", self.name)));
+    }
+}
@@ -0,0 +1,79 @@
+use crate::core::example::{Category, Example};
+
+/**
+ * The registry keeps all the examples of the crate.
+ *
+ * The examples are collected from the examples() function
+ * of each category module (src/examples/mod.rs and
+ * src/leet_code/mod.rs), so adding an example means adding
+ * a single line there.
+ */
+pub struct Registry {
+    m_examples: Vec<Box<dyn Example>>,
+}
+
+impl Registry {
+    pub fn new() -> Registry {
+        let mut examples: Vec<Box<dyn Example>> = Vec::new();
+        examples.extend(crate::examples::examples());
+        examples.extend(crate::leet_code::examples());
+        return Registry {
+            m_examples: examples,
+        };
+    }
+
+    pub fn iter(&self) -> std::slice::Iter<'_, Box<dyn Example>> {
+        return self.m_examples.iter();
+    }
+
+    /**
+     * @param category the category to filter by.
+     * @return the examples of the given category, in
+     * registration order.
+     */
+    pub fn by_category(&self, category: Category) -> Vec<&dyn Example> {
+        let mut res: Vec<&dyn Example> = Vec::new();
+        for example in self.m_examples.iter() {
+            if example.category() == category {
+                res.push(example.as_ref());
+            }
+        }
+        return res;
+    }
+
+    /**
+     * Finds examples by name.
+     * @param name either a plain example name ("two_sum")
+     * or a name qualified by its category
+     * ("leet_code/get_nth_word").
+     * @return all the matching examples. More than a single
+     * example is returned when a plain name exists in
+     * several categories.
+     */
+    pub fn find(&self, name: &str) -> Vec<&dyn Example> {
+        let (category, plain_name): (Option<&str>, &str) =
+            match name.split_once('/') {
+                Some((category, plain_name)) => {
+                    (Some(category), plain_name)
+                }
+                None => (None, name),
+            };
+        let mut res: Vec<&dyn Example> = Vec::new();
+        for example in self.m_examples.iter() {
+            let category_matches: bool = match category {
+                Some(category) => example.category().as_str() == category,
+                None => true,
+            };
+            if category_matches && example.name() == plain_name {
+                res.push(example.as_ref());
+            }
+        }
+        return res;
+    }
+}
+
+impl Default for Registry {
+    fn default() -> Registry {
+        return Registry::new();
+    }
+}
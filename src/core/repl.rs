@@ -0,0 +1,257 @@
+use crate::core::callable::Callable;
+use crate::core::example::Example;
+use crate::core::registry::Registry;
+use crate::core::reporter::{Reporter, Style};
+use crate::core::return_code::{ResultExt, RC};
+use crate::core::runner::{RunReport, Runner};
+use std::io::{BufRead, Write};
+
+const HELP: &str = "\
+commands:
+  list                 list the examples
+  run <name>...        run examples by name
+  calls                list the callable leet code functions
+  <function> <args>    call a function, e.g. two_sum [2,7,11,15] 9
+                       or two_sum nums = [2,7,11,15], target = 9
+  help                 show this help
+  quit                 leave the REPL (or end of input)";
+
+/**
+ * An interactive read-eval-print loop.
+ *
+ * A line is either a command (list, run, ...) or a call
+ * of a leet code function with its arguments, so an
+ * experiment does not need editing main.rs and compiling.
+ * An error is printed and the REPL continues with the next
+ * line.
+ */
+pub struct Repl {
+    m_registry: Registry,
+    m_callables: Vec<Box<dyn Callable>>,
+    m_reporter: Reporter,
+}
+
+impl Repl {
+    const PROMPT: &'static str = "rust_pg> ";
+
+    pub fn new() -> Repl {
+        return Repl {
+            m_registry: Registry::new(),
+            m_callables: crate::leet_code::callables(),
+            m_reporter: Reporter::new(),
+        };
+    }
+
+    /**
+     * Reads lines from input till "quit" or the end of the
+     * input, the results are written to output.
+     * @return RC::CIo when reading or writing fails.
+     */
+    pub fn run<R: BufRead, W: Write>(
+        &self,
+        input: R,
+        mut output: W,
+    ) -> Result<(), RC> {
+        write!(output, "{}", Repl::PROMPT).context("writing the prompt")?;
+        output.flush().context("writing the prompt")?;
+        for line in input.lines() {
+            let line: String = line.context("reading a REPL line")?;
+            if line.trim() == "quit" {
+                return Ok(());
+            }
+            if let Err(rc) = self.eval(&line, &mut output) {
+                write!(output, "{}", rc.render())
+                    .context("writing an error")?;
+            }
+            write!(output, "{}", Repl::PROMPT).context("writing the prompt")?;
+            output.flush().context("writing the prompt")?;
+        }
+        writeln!(output).context("writing the output")?;
+        return Ok(());
+    }
+
+    /**
+     * Evaluates a line, the first word is the command (or
+     * the function) and the rest of the line its arguments.
+     */
+    fn eval<W: Write>(&self, line: &str, output: &mut W) -> Result<(), RC> {
+        let line: &str = line.trim();
+        let (command, args): (&str, &str) =
+            match line.split_once(char::is_whitespace) {
+                Some((command, args)) => (command, args.trim()),
+                None => (line, ""),
+            };
+        match command {
+            "" => {}
+            "help" => writeln!(output, "{HELP}")?,
+            "list" => {
+                for example in self.m_registry.iter() {
+                    writeln!(
+                        output,
+                        "{}/{}",
+                        example.category().as_str(),
+                        example.name()
+                    )?;
+                }
+            }
+            "calls" => {
+                for callable in self.m_callables.iter() {
+                    write!(
+                        output,
+                        "{}({})",
+                        callable.name(),
+                        callable.signature()
+                    )?;
+                    if !callable.aliases().is_empty() {
+                        write!(
+                            output,
+                            ", also {}",
+                            callable.aliases().join(", ")
+                        )?;
+                    }
+                    writeln!(output)?;
+                }
+            }
+            "run" => {
+                for name in args.split_whitespace() {
+                    self.run_example(name, output)?;
+                }
+            }
+            name => {
+                let callable: &dyn Callable = self.find_callable(name)?;
+                /*
+                 * A panic (e.g. an overflow on a bad input) is
+                 * reported like an error, the REPL goes on.
+                 */
+                let mut res: String = String::new();
+                Runner::catch_panic(|| {
+                    res = callable.call(args)?;
+                    return Ok(());
+                })
+                .map_err(|rc| {
+                    return rc.context(&format!(
+                        "calling {}({})",
+                        callable.name(),
+                        callable.signature()
+                    ));
+                })?;
+                writeln!(
+                    output,
+                    "{}",
+                    self.m_reporter.paint(&res, Style::Success)
+                )?;
+            }
+        }
+        return Ok(());
+    }
+
+    /**
+     * The output of the example is captured, so it's written
+     * to the REPL output like the results of the calls.
+     */
+    fn run_example<W: Write>(
+        &self,
+        name: &str,
+        output: &mut W,
+    ) -> Result<(), RC> {
+        let found: Vec<&dyn Example> = self.m_registry.find(name);
+        let example: &dyn Example = match found.len() {
+            0 => return Err(RC::not_found(&format!("example '{name}'"))),
+            1 => found[0],
+            _ => {
+                return Err(RC::invalid_argument(
+                    name,
+                    "ambiguous, qualify it with its category",
+                ))
+            }
+        };
+        let report: RunReport = Runner::new(true).run(example);
+        write!(output, "{}", report.output)?;
+        return report.result;
+    }
+
+    fn find_callable(&self, name: &str) -> Result<&dyn Callable, RC> {
+        for callable in self.m_callables.iter() {
+            if callable.name() == name || callable.aliases().contains(&name) {
+                return Ok(callable.as_ref());
+            }
+        }
+        return Err(RC::not_found(&format!(
+            "command or function '{name}' (type help)"
+        )));
+    }
+}
+
+impl Default for Repl {
+    fn default() -> Repl {
+        return Repl::new();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Panicking;
+
+    impl Callable for Panicking {
+        fn name(&self) -> &'static str {
+            return "panicking";
+        }
+
+        fn signature(&self) -> &'static str {
+            return "n: i32";
+        }
+
+        fn call(&self, args: &str) -> Result<String, RC> {
+            panic!("called with {args}");
+        }
+    }
+
+    /** @return the output of the REPL reading input. */
+    fn run(repl: &Repl, input: &str) -> String {
+        let mut output: Vec<u8> = Vec::new();
+        repl.run(input.as_bytes(), &mut output).unwrap();
+        return String::from_utf8(output).unwrap();
+    }
+
+    #[test]
+    fn call() {
+        let output: String = run(&Repl::new(), "two_sum [2,7,11,15] 9\n");
+        assert!(output.contains("[0,1]"), "{output}");
+        let output: String =
+            run(&Repl::new(), "add_two_nums [2,4,3] [5,6,4]\n");
+        assert!(output.contains("[7,0,8]"), "{output}");
+    }
+
+    #[test]
+    fn unknown_function() {
+        let output: String = run(&Repl::new(), "nope 1\ntwo_sum [3,3] 6\n");
+        assert!(output.contains("error: "), "{output}");
+        assert!(output.contains("'nope'"), "{output}");
+        /* The REPL goes on after an error. */
+        assert!(output.contains("[0,1]"), "{output}");
+    }
+
+    #[test]
+    fn panicking_call() {
+        let mut repl: Repl = Repl::new();
+        repl.m_callables.push(Box::new(Panicking));
+        let output: String = run(&repl, "panicking 7\ntwo_sum [3,3] 6\n");
+        assert!(output.contains("calling panicking(n: i32)"), "{output}");
+        assert!(output.contains("called with 7"), "{output}");
+        assert!(output.contains("[0,1]"), "{output}");
+    }
+
+    #[test]
+    fn quit() {
+        let output: String = run(&Repl::new(), "quit\nhelp\n");
+        assert_eq!(output, Repl::PROMPT);
+    }
+
+    #[test]
+    fn run_writes_to_the_output() {
+        let output: String = run(&Repl::new(), "run first_steps\n");
+        assert!(output.contains("var0 = -1, var1 = 1"), "{output}");
+    }
+}
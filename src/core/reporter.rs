@@ -0,0 +1,305 @@
+use crate::pg_println;
+
+/**
+ * A structured console reporter.
+ *
+ * It prints titled sections, nested (indented) blocks,
+ * aligned key/value pairs and tables through the output
+ * sink (pg_println!), so a reporter's output is captured
+ * like any other example output.
+ *
+ * Colors (ANSI escape codes) are used only when the output
+ * goes to a terminal and the NO_COLOR environment variable
+ * is not set (https://no-color.org).
+ *
+ * The width is taken from the COLUMNS environment variable
+ * or from the terminal. When the output is captured, the
+ * width is always Reporter::DEFAULT_WIDTH and there are no
+ * colors, so captured output does not depend on the
+ * terminal it was produced in.
+ */
+#[derive(Clone)]
+pub struct Reporter {
+    m_width: usize,
+    m_color: bool,
+    /** Nesting level, each level indents by INDENT_WIDTH. */
+    m_level: usize,
+}
+
+/**
+ * The styles a text can be painted with.
+ */
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Style {
+    Title,
+    Success,
+    Failure,
+    Warning,
+    Dim,
+}
+
+impl Style {
+    fn ansi_code(&self) -> &'static str {
+        match *self {
+            Style::Title => "1;36",
+            Style::Success => "32",
+            Style::Failure => "1;31",
+            Style::Warning => "33",
+            Style::Dim => "2",
+        }
+    }
+}
+
+impl Reporter {
+    pub const DEFAULT_WIDTH: usize = 80;
+    const MIN_WIDTH: usize = 20;
+    const INDENT_WIDTH: usize = 2;
+
+    /**
+     * A reporter configured from the environment (see the
+     * struct documentation).
+     */
+    pub fn new() -> Reporter {
+        if !crate::core::output::Output::is_stdout() {
+            return Reporter::plain(Reporter::DEFAULT_WIDTH);
+        }
+        let width: usize = Reporter::env_width()
+            .or_else(terminal::width)
+            .unwrap_or(Reporter::DEFAULT_WIDTH);
+        let no_color: bool = match std::env::var_os("NO_COLOR") {
+            Some(value) => !value.is_empty(),
+            None => false,
+        };
+        let is_terminal: bool =
+            std::io::IsTerminal::is_terminal(&std::io::stdout());
+        return Reporter {
+            m_width: std::cmp::max(width, Reporter::MIN_WIDTH),
+            m_color: is_terminal && !no_color,
+            m_level: 0,
+        };
+    }
+
+    /**
+     * A reporter without colors and with a fixed width.
+     */
+    pub fn plain(width: usize) -> Reporter {
+        return Reporter {
+            m_width: std::cmp::max(width, Reporter::MIN_WIDTH),
+            m_color: false,
+            m_level: 0,
+        };
+    }
+
+    pub fn width(&self) -> usize {
+        return self.m_width;
+    }
+
+    /**
+     * @return a reporter whose output is indented one level
+     * deeper than this reporter's output.
+     */
+    pub fn nested(&self) -> Reporter {
+        let mut res: Reporter = self.clone();
+        res.m_level += 1;
+        return res;
+    }
+
+    /**
+     * @return the text wrapped with the style's ANSI codes,
+     * or the text as is when colors are disabled.
+     */
+    pub fn paint(&self, text: &str, style: Style) -> String {
+        if !self.m_color {
+            return String::from(text);
+        }
+        return format!("\x1b[{}m{}\x1b[0m", style.ansi_code(), text);
+    }
+
+    /**
+     * Prints a section header, filled up to the width:
+     * == title =====================
+     */
+    pub fn section(&self, title: &str) {
+        self.header(title, '=');
+    }
+
+    /**
+     * Prints a header of a part of a section:
+     * -- title ---------------------
+     */
+    pub fn subsection(&self, title: &str) {
+        self.header(title, '-');
+    }
+
+    /**
+     * Prints a line of underscores, as wide as the
+     * reporter (minus the indentation).
+     */
+    pub fn separator(&self) {
+        let len: usize = self.m_width.saturating_sub(self.indent().len());
+        self.line(&"_".repeat(len));
+    }
+
+    /**
+     * Prints an indented line.
+     */
+    pub fn line(&self, text: &str) {
+        pg_println!("{}{}", self.indent(), text);
+    }
+
+    /**
+     * Prints key/value pairs with the values aligned:
+     * name   : two_sum
+     * status : ok
+     */
+    pub fn key_values(&self, pairs: &[(&str, String)]) {
+        let key_width: usize = pairs
+            .iter()
+            .map(|(key, _)| key.chars().count())
+            .max()
+            .unwrap_or(0);
+        for (key, value) in pairs {
+            self.line(&format!("{key:<key_width$} : {value}"));
+        }
+    }
+
+    /**
+     * Prints a table. Each column is as wide as its widest
+     * cell, numbers are aligned to the right and any other
+     * text to the left:
+     * idx | value
+     * ----+------
+     *   0 |     2
+     * @param headers the column titles.
+     * @param rows the cells, a row may be shorter than the
+     * headers (the missing cells are empty).
+     */
+    pub fn table(&self, headers: &[&str], rows: &[Vec<String>]) {
+        let mut widths: Vec<usize> =
+            headers.iter().map(|header| header.chars().count()).collect();
+        for row in rows {
+            for (col, cell) in row.iter().enumerate().take(widths.len()) {
+                widths[col] = std::cmp::max(widths[col], cell.chars().count());
+            }
+        }
+
+        let header_cells: Vec<String> = headers
+            .iter()
+            .enumerate()
+            .map(|(col, header)| format!("{:<1$}", header, widths[col]))
+            .collect();
+        self.line(&self.paint(&header_cells.join(" | "), Style::Title));
+        let rule: Vec<String> =
+            widths.iter().map(|&width| "-".repeat(width)).collect();
+        self.line(&rule.join("-+-"));
+
+        for row in rows {
+            let mut cells: Vec<String> = Vec::with_capacity(widths.len());
+            for (col, &width) in widths.iter().enumerate() {
+                let cell: &str = row.get(col).map_or("", |cell| cell.as_str());
+                if Reporter::is_number(cell) {
+                    cells.push(format!("{cell:>width$}"));
+                } else {
+                    cells.push(format!("{cell:<width$}"));
+                }
+            }
+            /* No trailing spaces after the last column. */
+            self.line(cells.join(" | ").trim_end());
+        }
+    }
+
+    fn header(&self, title: &str, fill_char: char) {
+        let prefix: String = format!("{fill_char}{fill_char} {title} ");
+        let used: usize = self.indent().len() + prefix.chars().count();
+        let fill: String = fill_char
+            .to_string()
+            .repeat(self.m_width.saturating_sub(used));
+        let header: String = format!("{prefix}{fill}");
+        self.line(&self.paint(&header, Style::Title));
+    }
+
+    fn indent(&self) -> String {
+        return " ".repeat(self.m_level * Reporter::INDENT_WIDTH);
+    }
+
+    fn is_number(cell: &str) -> bool {
+        return !cell.is_empty() && cell.parse::<f64>().is_ok();
+    }
+
+    fn env_width() -> Option<usize> {
+        return std::env::var("COLUMNS").ok()?.trim().parse::<usize>().ok();
+    }
+}
+
+impl Default for Reporter {
+    fn default() -> Reporter {
+        return Reporter::new();
+    }
+}
+
+/**
+ * Getting the terminal size needs the ioctl system call.
+ * There is no std API for it, so the libc function is
+ * declared and called directly (see
+ * src/examples/unsafe_func.rs for unsafe functions).
+ */
+#[cfg(any(target_os = "linux", target_os = "macos"))]
+mod terminal {
+    /**
+     * struct winsize from <sys/ioctl.h>. The fields are
+     * written by ioctl, only ws_col is read.
+     */
+    #[repr(C)]
+    #[allow(dead_code)]
+    struct WinSize {
+        ws_row: u16,
+        ws_col: u16,
+        ws_xpixel: u16,
+        ws_ypixel: u16,
+    }
+
+    #[cfg(target_os = "linux")]
+    const TIOCGWINSZ: std::ffi::c_ulong = 0x5413;
+    #[cfg(target_os = "macos")]
+    const TIOCGWINSZ: std::ffi::c_ulong = 0x40087468;
+    const STDOUT_FILENO: std::ffi::c_int = 1;
+
+    extern "C" {
+        fn ioctl(
+            fd: std::ffi::c_int,
+            request: std::ffi::c_ulong,
+            ...
+        ) -> std::ffi::c_int;
+    }
+
+    /**
+     * @return the number of columns of the terminal stdout
+     * is connected to, None when stdout is not a terminal.
+     */
+    pub fn width() -> Option<usize> {
+        let mut win_size: WinSize = WinSize {
+            ws_row: 0,
+            ws_col: 0,
+            ws_xpixel: 0,
+            ws_ypixel: 0,
+        };
+        /*
+         * Safe since win_size lives till the call returns
+         * and has the layout ioctl expects for TIOCGWINSZ.
+         */
+        let rc: std::ffi::c_int = unsafe {
+            ioctl(STDOUT_FILENO, TIOCGWINSZ, &mut win_size as *mut WinSize)
+        };
+        if rc != 0 || win_size.ws_col == 0 {
+            return None;
+        }
+        return Some(win_size.ws_col as usize);
+    }
+}
+
+#[cfg(not(any(target_os = "linux", target_os = "macos")))]
+mod terminal {
+    pub fn width() -> Option<usize> {
+        return None;
+    }
+}
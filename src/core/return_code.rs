@@ -1,5 +1,10 @@
 /**
  * Return code enum.
+ *
+ * RC is the error type shared by the whole crate. Each
+ * variant carries the data needed to understand what went
+ * wrong, so printing the error (with {}) is enough for
+ * diagnosis.
  */
 pub enum RC {
     /*
@@ -10,8 +15,219 @@ pub enum RC {
      * if there are less than n words in the string, return
      * an invalid argument.
      */
-    CInvalidArgument,
+    CInvalidArgument {
+        /** The name of the invalid argument. */
+        argument: String,
+        /** Why the argument is invalid. */
+        reason: String,
+    },
+    /*
+     * Return when a text input cannot be parsed.
+     */
+    CParse {
+        /** The text which was parsed. */
+        input: String,
+        /** The (byte) position the parsing failed at. */
+        position: usize,
+        message: String,
+    },
+    /*
+     * Return when a value differs from the expected one.
+     */
+    CMismatch { expected: String, actual: String },
+    /*
+     * Return when a searched item does not exist.
+     */
+    CNotFound { what: String },
+    /*
+     * Return when the input of a problem has no solution.
+     */
+    CNoSolution,
+    /*
+     * Return when a function panicked and the panic was
+     * caught (see core::runner).
+     */
+    CPanic {
+        message: String,
+        /** file:line:column of the panic, if known. */
+        location: Option<String>,
+    },
+    /*
+     * An I/O error, kept as is so its kind is not lost.
+     */
+    CIo(std::io::Error),
+    /*
+     * Wraps an underlying error (the cause) with a
+     * description of what was done when it happened.
+     * The cause is returned by Error::source(), so the
+     * whole chain can be printed.
+     */
+    CContext {
+        context: String,
+        source: Box<dyn std::error::Error + Send + Sync + 'static>,
+    },
 }
+
+impl RC {
+    /**
+     * A shortcut for building RC::CInvalidArgument.
+     */
+    pub fn invalid_argument(argument: &str, reason: &str) -> RC {
+        return RC::CInvalidArgument {
+            argument: String::from(argument),
+            reason: String::from(reason),
+        };
+    }
+
+    /**
+     * A shortcut for building RC::CParse.
+     */
+    pub fn parse(input: &str, position: usize, message: &str) -> RC {
+        return RC::CParse {
+            input: String::from(input),
+            position,
+            message: String::from(message),
+        };
+    }
+
+    /**
+     * A shortcut for building RC::CMismatch.
+     */
+    pub fn mismatch(expected: &str, actual: &str) -> RC {
+        return RC::CMismatch {
+            expected: String::from(expected),
+            actual: String::from(actual),
+        };
+    }
+
+    /**
+     * A shortcut for building RC::CNotFound.
+     */
+    pub fn not_found(what: &str) -> RC {
+        return RC::CNotFound {
+            what: String::from(what),
+        };
+    }
+
+    /**
+     * Wraps the return code with a context.
+     * @param context what was done when the error happened,
+     * for example "reading golden file a.txt".
+     */
+    pub fn context(self, context: &str) -> RC {
+        return RC::CContext {
+            context: String::from(context),
+            source: Box::new(self),
+        };
+    }
+}
+
+/**
+ * Process exit codes.
+ *
+ * Each variant maps to a stable exit code, so a shell
+ * script running the binary can tell why it failed:
+ *
+ * | code | meaning                                   |
+ * |------|-------------------------------------------|
+ * |    0 | success                                   |
+ * |    1 | an error which is not an RC               |
+ * |    2 | RC::CInvalidArgument (bad usage/argument) |
+ * |    3 | RC::CParse                                |
+ * |    4 | RC::CMismatch                             |
+ * |    5 | RC::CNotFound                             |
+ * |    6 | RC::CNoSolution                           |
+ * |    7 | RC::CIo                                   |
+ * |  101 | RC::CPanic (rust's panic exit code)       |
+ *
+ * RC::CContext does not have its own code, it uses the
+ * code of the error it wraps: an io::Error uses 7 and a
+ * ParseIntError (or ParseFloatError) uses 3, like RC::CIo
+ * and RC::CParse.
+ */
+impl RC {
+    pub const EXIT_OTHER: u8 = 1;
+    pub const EXIT_INVALID_ARGUMENT: u8 = 2;
+    pub const EXIT_PARSE: u8 = 3;
+    pub const EXIT_MISMATCH: u8 = 4;
+    pub const EXIT_NOT_FOUND: u8 = 5;
+    pub const EXIT_NO_SOLUTION: u8 = 6;
+    pub const EXIT_IO: u8 = 7;
+    pub const EXIT_PANIC: u8 = 101;
+
+    /**
+     * @return the process exit code of the return code,
+     * see the table above.
+     */
+    pub fn exit_code(&self) -> u8 {
+        match *self {
+            RC::CInvalidArgument { .. } => RC::EXIT_INVALID_ARGUMENT,
+            RC::CParse { .. } => RC::EXIT_PARSE,
+            RC::CMismatch { .. } => RC::EXIT_MISMATCH,
+            RC::CNotFound { .. } => RC::EXIT_NOT_FOUND,
+            RC::CNoSolution => RC::EXIT_NO_SOLUTION,
+            RC::CPanic { .. } => RC::EXIT_PANIC,
+            RC::CIo(_) => RC::EXIT_IO,
+            RC::CContext { ref source, .. } => {
+                /*
+                 * The wrapped error is an RC, or a std error
+                 * which has an RC counterpart (ResultExt::context
+                 * and From<ParseIntError> wrap them as is).
+                 */
+                if let Some(rc) = source.downcast_ref::<RC>() {
+                    rc.exit_code()
+                } else if source.is::<std::io::Error>() {
+                    RC::EXIT_IO
+                } else if source.is::<std::num::ParseIntError>()
+                    || source.is::<std::num::ParseFloatError>()
+                {
+                    RC::EXIT_PARSE
+                } else {
+                    RC::EXIT_OTHER
+                }
+            }
+        }
+    }
+
+    /**
+     * Renders the error and its chain of causes, a line per
+     * error, for example:
+     * error: running example 'two_sum'
+     *   caused by: no solution exists
+     */
+    pub fn render(&self) -> String {
+        let mut res: String = format!("error: {self}\n");
+        let mut cause: Option<&(dyn std::error::Error + 'static)> =
+            std::error::Error::source(self);
+        while let Some(err) = cause {
+            res.push_str(&format!("  caused by: {err}\n"));
+            cause = err.source();
+        }
+        return res;
+    }
+}
+
+/**
+ * Allows calling context() on any Result whose error is a
+ * std error, for example:
+ * std::fs::read_to_string(path).context("reading notes")?;
+ */
+pub trait ResultExt<T> {
+    fn context(self, context: &str) -> Result<T, RC>;
+}
+
+impl<T, E> ResultExt<T> for Result<T, E>
+where
+    E: std::error::Error + Send + Sync + 'static,
+{
+    fn context(self, context: &str) -> Result<T, RC> {
+        return self.map_err(|err| RC::CContext {
+            context: String::from(context),
+            source: Box::new(err),
+        });
+    }
+}
+
 /**
  * Note: when using
  * #[derive(Debug)]
@@ -19,11 +235,133 @@ pub enum RC {
  *     CInvalidArgument,
  * }
  * The compiler actually adds the next code:
+ *
+ * (For the variants with fields, derive(Debug) uses the
+ * debug_struct/debug_tuple helpers of the formatter, like
+ * the implementation below).
  */
 impl core::fmt::Debug for RC {
     fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
         match *self {
-            RC::CInvalidArgument => write!(f, "InvalidArgument"),
+            RC::CInvalidArgument {
+                ref argument,
+                ref reason,
+            } => f
+                .debug_struct("InvalidArgument")
+                .field("argument", argument)
+                .field("reason", reason)
+                .finish(),
+            RC::CParse {
+                ref input,
+                position,
+                ref message,
+            } => f
+                .debug_struct("Parse")
+                .field("input", input)
+                .field("position", &position)
+                .field("message", message)
+                .finish(),
+            RC::CMismatch {
+                ref expected,
+                ref actual,
+            } => f
+                .debug_struct("Mismatch")
+                .field("expected", expected)
+                .field("actual", actual)
+                .finish(),
+            RC::CNotFound { ref what } => {
+                f.debug_struct("NotFound").field("what", what).finish()
+            }
+            RC::CNoSolution => write!(f, "NoSolution"),
+            RC::CPanic {
+                ref message,
+                ref location,
+            } => f
+                .debug_struct("Panic")
+                .field("message", message)
+                .field("location", location)
+                .finish(),
+            RC::CIo(ref err) => f.debug_tuple("Io").field(err).finish(),
+            RC::CContext {
+                ref context,
+                ref source,
+            } => f
+                .debug_struct("Context")
+                .field("context", context)
+                .field("source", source)
+                .finish(),
+        }
+    }
+}
+
+/**
+ * Display is the user facing message. Unlike Debug, it
+ * cannot be derived, the message has to be written by hand.
+ */
+impl core::fmt::Display for RC {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        match *self {
+            RC::CInvalidArgument {
+                ref argument,
+                ref reason,
+            } => write!(f, "invalid argument '{argument}': {reason}"),
+            RC::CParse {
+                ref input,
+                position,
+                ref message,
+            } => write!(
+                f,
+                "cannot parse '{input}' at position {position}: {message}"
+            ),
+            RC::CMismatch {
+                ref expected,
+                ref actual,
+            } => write!(f, "expected {expected}, actual {actual}"),
+            RC::CNotFound { ref what } => write!(f, "{what} not found"),
+            RC::CNoSolution => write!(f, "no solution exists"),
+            RC::CPanic {
+                ref message,
+                location: Some(ref location),
+            } => write!(f, "panicked at {location}: {message}"),
+            RC::CPanic {
+                ref message,
+                location: None,
+            } => write!(f, "panicked: {message}"),
+            RC::CIo(ref err) => write!(f, "i/o error: {err}"),
+            RC::CContext { ref context, .. } => write!(f, "{context}"),
+        }
+    }
+}
+
+/**
+ * Implementing std::error::Error (Debug and Display are
+ * its super traits) makes RC usable wherever a std error
+ * is expected, e.g. Box<dyn std::error::Error>.
+ *
+ * source() returns the underlying cause, so a caller can
+ * walk the chain of errors. RC::CIo already prints the io
+ * error in its message, so it does not return it again.
+ */
+impl std::error::Error for RC {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match *self {
+            RC::CContext { ref source, .. } => return Some(source.as_ref()),
+            _ => return None,
         }
     }
 }
+
+impl From<std::io::Error> for RC {
+    fn from(err: std::io::Error) -> RC {
+        return RC::CIo(err);
+    }
+}
+
+impl From<std::num::ParseIntError> for RC {
+    fn from(err: std::num::ParseIntError) -> RC {
+        return RC::CContext {
+            context: String::from("cannot parse an integer"),
+            source: Box::new(err),
+        };
+    }
+}
@@ -0,0 +1,304 @@
+use crate::core::alloc_stats::AllocStats;
+use crate::core::assertions::Assertions;
+use crate::core::example::{Category, Example};
+use crate::core::output::Output;
+use crate::core::panic_scenario::{PanicScenario, ScenarioReport};
+use crate::core::return_code::RC;
+use std::cell::{Cell, RefCell};
+use std::sync::OnceLock;
+use std::time::{Duration, Instant};
+
+/**
+ * Runs examples and reports what happened: the output, the
+ * number of assertions hit, the duration and whether the
+ * example succeeded, returned an error or panicked.
+ *
+ * A panicking example does not abort the process, the
+ * panic is caught (std::panic::catch_unwind) and reported.
+ */
+pub struct Runner {
+    /** Capture the output instead of printing it. */
+    m_capture: bool,
+}
+
+/**
+ * What happened when an example was run.
+ */
+pub struct RunReport {
+    pub name: &'static str,
+    pub category: Category,
+    /** The captured output, empty when not captured. */
+    pub output: String,
+    pub assertions: usize,
+    pub duration: Duration,
+    /** None unless allocation counting is enabled. */
+    pub alloc: Option<AllocStats>,
+    /** The panic scenarios, empty when the example failed. */
+    pub scenarios: Vec<ScenarioReport>,
+    /**
+     * Ok when the example succeeded. Otherwise the error it
+     * returned, RC::CPanic when it panicked, or the error of
+     * the first panic scenario which did not panic as
+     * expected.
+     */
+    pub result: Result<(), RC>,
+}
+
+impl RunReport {
+    /**
+     * @return "ok", "error" or "panic".
+     */
+    pub fn status(&self) -> &'static str {
+        match self.result {
+            Ok(()) => return "ok",
+            Err(RC::CPanic { .. }) => return "panic",
+            Err(_) => return "error",
+        }
+    }
+}
+
+/**
+ * The panic hook is process-wide, so it's installed once
+ * (see Runner::install_hook()) and never swapped: a thread
+ * restoring the hook it replaced would race with the other
+ * threads catching panics (the judge cases, the fuzz cases,
+ * the parallel tests).
+ */
+static HOOK_INSTALLED: OnceLock<()> = OnceLock::new();
+
+thread_local! {
+    /** How many catch_panic() calls run on this thread. */
+    static CATCHING: Cell<usize> = const { Cell::new(0) };
+    /** The location of the last panic caught on this thread. */
+    static PANIC_LOCATION: RefCell<Option<String>> =
+        const { RefCell::new(None) };
+}
+
+/**
+ * Leaves the catching state of the thread when dropped, so
+ * it's left even if the function does not return normally.
+ */
+struct CatchingGuard;
+
+impl CatchingGuard {
+    fn enter() -> CatchingGuard {
+        CATCHING.with(|catching| catching.set(catching.get() + 1));
+        return CatchingGuard;
+    }
+}
+
+impl Drop for CatchingGuard {
+    fn drop(&mut self) {
+        CATCHING.with(|catching| catching.set(catching.get() - 1));
+    }
+}
+
+impl Runner {
+    pub fn new(capture: bool) -> Runner {
+        return Runner { m_capture: capture };
+    }
+
+    pub fn run(&self, example: &dyn Example) -> RunReport {
+        let assertions_before: usize = Assertions::count();
+        let start: Instant = Instant::now();
+        /*
+         * The allocations are counted inside the capture, so
+         * setting up the capture buffer is not counted.
+         */
+        let run_counted = || {
+            return AllocStats::measure(|| {
+                let result: Result<(), RC> =
+                    Runner::catch_panic(|| example.run());
+                if result.is_err() {
+                    return (result, Vec::new());
+                }
+                let scenarios: Vec<ScenarioReport> = example
+                    .panic_scenarios()
+                    .iter()
+                    .map(PanicScenario::run)
+                    .collect();
+                let result: Result<(), RC> = scenarios
+                    .iter()
+                    .map(ScenarioReport::to_result)
+                    .find(|res| res.is_err())
+                    .unwrap_or(Ok(()));
+                return (result, scenarios);
+            });
+        };
+        let (((result, scenarios), alloc), output) = if self.m_capture {
+            Output::capture(run_counted)
+        } else {
+            (run_counted(), String::new())
+        };
+        return RunReport {
+            name: example.name(),
+            category: example.category(),
+            output,
+            assertions: Assertions::count() - assertions_before,
+            duration: start.elapsed(),
+            alloc,
+            scenarios,
+            result,
+        };
+    }
+
+    /**
+     * Runs a function and catches a panic (if any).
+     *
+     * A panic of the function is not printed by the default
+     * panic hook (the one printing "thread 'main' panicked
+     * at..."), its location is only recorded, so the caller
+     * decides how to report it. Any thread can call it, the
+     * calls do not interfere.
+     *
+     * @return the function's result, or RC::CPanic with the
+     * panic message and location when it panicked.
+     */
+    pub fn catch_panic<F>(func: F) -> Result<(), RC>
+    where
+        F: FnOnce() -> Result<(), RC>,
+    {
+        Runner::install_hook();
+        PANIC_LOCATION.with(|location| location.borrow_mut().take());
+        let guard: CatchingGuard = CatchingGuard::enter();
+        /*
+         * AssertUnwindSafe: the closure's captured state is
+         * not used after a panic, so a broken invariant in
+         * it cannot be observed.
+         */
+        let res = std::panic::catch_unwind(std::panic::AssertUnwindSafe(func));
+        drop(guard);
+
+        match res {
+            Ok(res) => return res,
+            Err(payload) => {
+                let location: Option<String> = PANIC_LOCATION
+                    .with(|location| location.borrow_mut().take());
+                return Err(RC::CPanic {
+                    message: Runner::panic_message(payload.as_ref()),
+                    location,
+                });
+            }
+        }
+    }
+
+    /**
+     * Installs (once) a panic hook which records the panic
+     * location when the panicking thread is in catch_panic(),
+     * and calls the previous hook otherwise.
+     */
+    fn install_hook() {
+        HOOK_INSTALLED.get_or_init(|| {
+            let previous_hook = std::panic::take_hook();
+            std::panic::set_hook(Box::new(move |info| {
+                if CATCHING.with(|catching| catching.get()) == 0 {
+                    previous_hook(info);
+                    return;
+                }
+                let location: Option<String> =
+                    info.location().map(|location| location.to_string());
+                PANIC_LOCATION.with(|slot| *slot.borrow_mut() = location);
+            }));
+        });
+    }
+
+    /**
+     * The panic payload is the value passed to panic!, it's
+     * a &str for a literal message and a String for a
+     * formatted one.
+     */
+    fn panic_message(payload: &(dyn std::any::Any + Send)) -> String {
+        if let Some(message) = payload.downcast_ref::<&str>() {
+            return String::from(*message);
+        }
+        if let Some(message) = payload.downcast_ref::<String>() {
+            return message.clone();
+        }
+        return String::from("<non string panic payload>");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /** @return the location of the RC::CPanic of res. */
+    fn panic_location(res: Result<(), RC>) -> String {
+        match res {
+            Err(RC::CPanic {
+                location: Some(location),
+                ..
+            }) => return location,
+            Err(rc) => panic!("expected a panic with a location, got {rc}"),
+            Ok(()) => panic!("expected a panic"),
+        }
+    }
+
+    #[test]
+    fn returns_the_function_result() {
+        assert!(Runner::catch_panic(|| Ok(())).is_ok());
+        let res: Result<(), RC> =
+            Runner::catch_panic(|| Err(RC::not_found("x")));
+        assert!(matches!(res, Err(RC::CNotFound { .. })));
+    }
+
+    #[test]
+    fn reports_the_panic_message_and_location() {
+        let line: u32 = line!() + 1;
+        let res: Result<(), RC> = Runner::catch_panic(|| panic!("boom {}", 7));
+        match res {
+            Err(RC::CPanic { message, location }) => {
+                assert_eq!(message, "boom 7");
+                let location: String = location.unwrap_or_default();
+                assert!(location.starts_with(&format!("{}:{line}:", file!())));
+            }
+            _ => panic!("expected a panic"),
+        }
+    }
+
+    #[test]
+    fn nested_calls() {
+        let res: Result<(), RC> = Runner::catch_panic(|| {
+            let inner: Result<(), RC> =
+                Runner::catch_panic(|| panic!("inner"));
+            assert!(panic_location(inner).starts_with(file!()));
+            panic!("outer");
+        });
+        assert!(panic_location(res).starts_with(file!()));
+    }
+
+    /**
+     * A thread still catching (e.g. a timed out judge case)
+     * does not affect the panics caught by the others.
+     */
+    #[test]
+    fn threads_do_not_interfere() {
+        let (started, wait_started) = std::sync::mpsc::channel();
+        let (finish, wait_finish) = std::sync::mpsc::channel::<()>();
+        let slow = std::thread::spawn(move || {
+            return Runner::catch_panic(|| {
+                started.send(()).unwrap();
+                wait_finish.recv().unwrap();
+                panic!("slow");
+            });
+        });
+        wait_started.recv().unwrap();
+        let workers: Vec<std::thread::JoinHandle<String>> = (0..8)
+            .map(|idx| {
+                return std::thread::spawn(move || {
+                    let res: Result<(), RC> = Runner::catch_panic(|| {
+                        panic!("worker {idx}");
+                    });
+                    return panic_location(res);
+                });
+            })
+            .collect();
+        for worker in workers {
+            assert!(worker.join().unwrap().starts_with(file!()));
+        }
+        finish.send(()).unwrap();
+        let res: Result<(), RC> = slow.join().unwrap();
+        assert!(panic_location(res).starts_with(file!()));
+        assert!(Runner::catch_panic(|| panic!("after")).is_err());
+    }
+}
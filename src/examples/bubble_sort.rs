@@ -1,3 +1,6 @@
+use crate::pg_assert;
+use crate::pg_println;
+
 pub struct BubbleSort;
 
 //TODO why it does not recognize the other method without
@@ -20,12 +23,10 @@ impl BubbleSort {
         ];
 
         for arr in arr_of_arr {
-            println!("Unsorted array is: {:?}", arr);
+            pg_println!("Unsorted array is: {:?}", arr);
             BubbleSort::u64_arr_bubble_sort(arr);
-            println!("Sorted array is: {:?}", arr);
+            pg_println!("Sorted array is: {:?}", arr);
         }
-
-        crate::core::utils::Utils::print_line_separator();
     }
 
     /**
@@ -55,9 +56,54 @@ impl BubbleSort {
         elem_idx: usize,
     ) {
         /* Validate memory access */
-        assert!(elem_idx < arr_slice.len() - 1);
+        pg_assert!(elem_idx < arr_slice.len() - 1);
         let temp: u64 = arr_slice[elem_idx];
         arr_slice[elem_idx] = arr_slice[elem_idx + 1];
         arr_slice[elem_idx + 1] = temp;
     }
 }
+
+//_____________________________________________________________________________
+impl crate::core::example::Example for BubbleSort {
+    fn name(&self) -> &'static str {
+        return "bubble_sort";
+    }
+
+    fn category(&self) -> crate::core::example::Category {
+        return crate::core::example::Category::Examples;
+    }
+
+    fn description(&self) -> &'static str {
+        return "Bubble sort over mutable array slices.";
+    }
+
+    fn run(&self) -> Result<(), crate::core::return_code::RC> {
+        BubbleSort::example();
+        return Ok(());
+    }
+}
+
+/**
+ * A bubble step per element, each step goes over the whole
+ * slice: O(n^2) whatever the input.
+ */
+impl crate::core::complexity::Scalable for BubbleSort {
+    type Input = Vec<u64>;
+
+    fn name(&self) -> &'static str {
+        return "bubble_sort";
+    }
+
+    fn claimed(&self) -> crate::core::complexity::Growth {
+        return crate::core::complexity::Growth::Quadratic;
+    }
+
+    fn input(&self, size: usize, rng: &mut crate::core::fuzz::Rng) -> Vec<u64> {
+        return (0..size).map(|_| rng.next_u64()).collect();
+    }
+
+    fn run(&self, mut arr: Vec<u64>) {
+        BubbleSort::u64_arr_bubble_sort(&mut arr);
+        std::hint::black_box(arr);
+    }
+}
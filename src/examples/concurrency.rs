@@ -1,9 +1,10 @@
+use crate::pg_println;
+
 pub struct ConcExample;
 
 impl ConcExample {
     pub fn run_example() {
         ConcExample::create_thread();
-        crate::core::utils::Utils::print_line_separator();
     }
 
     fn create_thread() {
@@ -18,7 +19,7 @@ impl ConcExample {
         let sleep_time_micro: u64 = 2;
         let iteration: u64 = 7;
         for i in 0..iteration {
-            println!(
+            pg_println!(
                 "New thread: number is: {i}, let's sleep \
                  for a {sleep_time_micro} micros"
             );
@@ -32,7 +33,7 @@ impl ConcExample {
         let sleep_time_micro: u64 = 2000;
         let iteration: u64 = 5;
         for i in 0..iteration {
-            println!(
+            pg_println!(
                 "Old thread: number is: {i}, let's sleep \
                 for a {sleep_time_micro} micros"
             );
@@ -42,4 +43,29 @@ impl ConcExample {
             ))
         }
     }
-}
\ No newline at end of file
+}
+
+//_____________________________________________________________________________
+impl crate::core::example::Example for ConcExample {
+    fn name(&self) -> &'static str {
+        return "concurrency";
+    }
+
+    fn category(&self) -> crate::core::example::Category {
+        return crate::core::example::Category::Examples;
+    }
+
+    fn description(&self) -> &'static str {
+        return "Spawning a thread and interleaving its output.";
+    }
+
+    fn run(&self) -> Result<(), crate::core::return_code::RC> {
+        ConcExample::run_example();
+        return Ok(());
+    }
+
+    /** The threads interleave differently in each run. */
+    fn is_deterministic(&self) -> bool {
+        return false;
+    }
+}
@@ -1,3 +1,6 @@
+use crate::pg_assert_eq;
+use crate::pg_println;
+
 /**
  * core::cell
  * https://doc.rust-lang.org/core/cell/index.html
@@ -235,7 +238,6 @@ impl CoreCellExample {
     pub fn run_example() {
         CoreCellExample::cell();
         CoreCellExample::ref_cell();
-        crate::core::utils::Utils::print_line_separator();
     }
 
     fn cell() {
@@ -297,87 +299,133 @@ impl CoreCellExample {
          * to to the Vec<String> using the send() method).
          */
         limit_tracker.set_value(1);
-        assert_eq!(
+        pg_assert_eq!(
             mock_messenger.m_sent_messages_ref_cell.borrow().len(),
             0
         );
         limit_tracker.set_value(8);
-        assert_eq!(
+        pg_assert_eq!(
             mock_messenger.m_sent_messages_ref_cell.borrow().len(),
             1
         );
-        println!(
+        pg_println!(
             "{}",
             mock_messenger.m_sent_messages_ref_cell.borrow()[0]
         );
         limit_tracker.set_value(9);
-        assert_eq!(
+        pg_assert_eq!(
             mock_messenger.m_sent_messages_ref_cell.borrow().len(),
             2
         );
-        println!(
+        pg_println!(
             "{}",
             mock_messenger.m_sent_messages_ref_cell.borrow()[1]
         );
         limit_tracker.set_value(10);
-        assert_eq!(
+        pg_assert_eq!(
             mock_messenger.m_sent_messages_ref_cell.borrow().len(),
             3
         );
-        println!(
+        pg_println!(
             "{}",
             mock_messenger.m_sent_messages_ref_cell.borrow()[2]
         );
 
         /*
-         * Now let's make the system panic by having two
-         * mutable borrows through the RefCell.
-         * Note - two mutable references in the same scope
-         * do not lead to borrow-checker error.
+         * Making the system panic by having two mutable
+         * borrows through the RefCell, or an immutable
+         * borrow while there is a mutable one, is shown by
+         * the panic scenarios below (see panic_scenarios()),
+         * the runner runs them and checks they panic.
          */
-        let mut _r_messages1 =
-            mock_messenger.m_sent_messages_ref_cell.borrow_mut();
+
         /*
-         * Uncomment and run to make the program panic.
+         * TODO - need to understand what's going on inside
+         * the ref-cell.
          */
-        // let mut _r_messages2 =
-        //     mock_messenger.m_sent_messages_ref_cell.borrow_mut();
+    }
+
+    /**
+     * Makes the system panic by having two mutable borrows
+     * through the RefCell.
+     * Note - two mutable references in the same scope do
+     * not lead to borrow-checker error.
+     */
+    fn double_borrow_mut() {
+        let mock_messenger: MockMessenger = MockMessenger::new();
+        let mut r_messages1 =
+            mock_messenger.m_sent_messages_ref_cell.borrow_mut();
         /*
-         * The program panics in the previous line, as
-         * the RefCell mechanism two calls to borrow_mut()
-         * in the same scope.
-         * The next lines just tries to emphasis what one
+         * The program panics in the next line, as the
+         * RefCell mechanism two calls to borrow_mut() in the
+         * same scope.
+         * The lines after it just try to emphasis what one
          * might like to do.
          */
-        // _r_messages1.push(String::from("not cool 1"));
-        // _r_messages2.push(String::from("not cool 2"));
+        let mut r_messages2 =
+            mock_messenger.m_sent_messages_ref_cell.borrow_mut();
+        r_messages1.push(String::from("not cool 1"));
+        r_messages2.push(String::from("not cool 2"));
+    }
 
-        /*
-         * NOTE: RefCell can be thought of a single-thread
-         * rw-lock for some data as it causes the program
-         * to panic when:
-         * 1. The variable is mutated by more than a single
-         *    writer.
-         * 2. The variable is mutated while it's also read.
-         */
+    /**
+     * NOTE: RefCell can be thought of a single-thread
+     * rw-lock for some data as it causes the program to
+     * panic when:
+     * 1. The variable is mutated by more than a single
+     *    writer.
+     * 2. The variable is mutated while it's also read.
+     */
+    fn borrow_while_mutably_borrowed() {
         let protected_data: core::cell::RefCell<u64> =
             core::cell::RefCell::<u64>::new(2);
-        let mut _r_mt_u64_val: core::cell::RefMut<'_, u64> =
+        let mut r_mt_u64_val: core::cell::RefMut<'_, u64> =
             protected_data.borrow_mut();
         /*
          * Next line causes a panic since the RefCell
          * detects a immutable borrow while the reference
          * is already mutably borrowed.
          */
-        // let r_imt_u64_val: core::cell::Ref<'_, u64> =
-        //     protected_data.borrow();
-        // println!("protected value is: {}", *r_imt_u64_val);
-        // *_r_mt_u64_val += 1;
-        // println!("protected value is: {}", protected_data.borrow());
+        let r_imt_u64_val: core::cell::Ref<'_, u64> =
+            protected_data.borrow();
+        pg_println!("protected value is: {}", *r_imt_u64_val);
+        *r_mt_u64_val += 1;
+    }
+}
 
-        /*
-         * TODO - need to understand what's going on inside
-         * the ref-cell.
-         */
+//_____________________________________________________________________________
+impl crate::core::example::Example for CoreCellExample {
+    fn name(&self) -> &'static str {
+        return "core_cell";
+    }
+
+    fn category(&self) -> crate::core::example::Category {
+        return crate::core::example::Category::Examples;
+    }
+
+    fn description(&self) -> &'static str {
+        return "Cell, RefCell and the interior mutability pattern.";
+    }
+
+    fn run(&self) -> Result<(), crate::core::return_code::RC> {
+        CoreCellExample::run_example();
+        return Ok(());
+    }
+
+    fn panic_scenarios(
+        &self,
+    ) -> Vec<crate::core::panic_scenario::PanicScenario> {
+        return vec![
+            crate::core::panic_scenario::PanicScenario {
+                name: "double_borrow_mut",
+                expected: "already borrowed",
+                scenario: CoreCellExample::double_borrow_mut,
+            },
+            crate::core::panic_scenario::PanicScenario {
+                name: "borrow_while_mutably_borrowed",
+                expected: "already mutably borrowed",
+                scenario: CoreCellExample::borrow_while_mutably_borrowed,
+            },
+        ];
     }
 }
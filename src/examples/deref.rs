@@ -1,3 +1,6 @@
+use crate::pg_assert;
+use crate::pg_println;
+
 pub struct DerefExample;
 
 impl DerefExample {
@@ -10,17 +13,16 @@ impl DerefExample {
         DerefExample::value_from_pointer();
         DerefExample::deref_my_box();
         DerefExample::deref_coercion();
-        crate::core::utils::Utils::print_line_separator();
     }
 
     fn value_from_reference() {
         let x: u64 = 2;
         let y: &u64 = &x;
         let z = *y;
-        assert!(x == 2);
-        assert!(z == x);
-        assert!(*y == x);
-        println!("x = {}, z = {}", x, z);
+        pg_assert!(x == 2);
+        pg_assert!(z == x);
+        pg_assert!(*y == x);
+        pg_println!("x = {}, z = {}", x, z);
         /*
          * Note: the value of x is not moves to z but
          * copied. Thus, it's possible to use both x and z
@@ -31,8 +33,8 @@ impl DerefExample {
     fn value_from_pointer() {
         let x: u64 = 2;
         let p_x: Box<u64> = Box::new(x);
-        assert!(x == *p_x);
-        println!("x = {}, *p_x = {}", x, p_x);
+        pg_assert!(x == *p_x);
+        pg_println!("x = {}, *p_x = {}", x, p_x);
     }
 }
 
@@ -84,13 +86,13 @@ impl DerefExample {
     fn deref_my_box() {
         let p_val: MyBox<u64> = MyBox::new(2);
         let val = *p_val;
-        assert!(val == 2);
+        pg_assert!(val == 2);
         /*
          * Can still use the p_val, as deref returns a
          * reference to the value inside the box.
          */
         let same_val = *p_val;
-        assert!(val == same_val);
+        pg_assert!(val == same_val);
     }
 
     fn deref_coercion() {
@@ -100,7 +102,7 @@ impl DerefExample {
          */
 
         fn print(a_string: &str) {
-            println!("{}", a_string);
+            pg_println!("{}", a_string);
         }
 
         let my_str_box: MyBox<String> = MyBox::new(String::from("Hello"));
@@ -136,3 +138,23 @@ impl DerefExample {
 /*
  * https://web.mit.edu/rust-lang_v1.25/arch/amd64_ubuntu1404/share/doc/rust/html/book/second-edition/ch15-02-deref.html
  */
+
+//_____________________________________________________________________________
+impl crate::core::example::Example for DerefExample {
+    fn name(&self) -> &'static str {
+        return "deref";
+    }
+
+    fn category(&self) -> crate::core::example::Category {
+        return crate::core::example::Category::Examples;
+    }
+
+    fn description(&self) -> &'static str {
+        return "The Deref trait and deref coercion.";
+    }
+
+    fn run(&self) -> Result<(), crate::core::return_code::RC> {
+        DerefExample::run_example();
+        return Ok(());
+    }
+}
@@ -1,3 +1,6 @@
+use crate::core::reporter::Reporter;
+use crate::pg_println;
+
 pub struct FirstSteps;
 
 impl FirstSteps {
@@ -15,12 +18,12 @@ impl FirstSteps {
     }
 
     fn pg_basic_str() {
-        println!("pg_basic_str()");
+        Reporter::new().subsection("pg_basic_str()");
         {
             //Heap allocated, will be dropped when the param is out
             //of scope.
             let immut_str_val: String = String::from("3.1415926535");
-            println!("Almost pi: {immut_str_val}");
+            pg_println!("Almost pi: {immut_str_val}");
         } //RAII style.
           //String::drop() method is called (implicitly). It's
           //the String module/library/package has to implement
@@ -30,42 +33,39 @@ impl FirstSteps {
             //Mutable string which can be modified.
             let mut mut_str_var: String = String::from("3.1415926535");
             mut_str_var.push_str("8979323");
-            println!("Almost pi: {mut_str_var}");
+            pg_println!("Almost pi: {mut_str_var}");
         } //RAII style.
-        crate::core::utils::Utils::print_line_separator();
     }
 
     fn pg_for() {
-        println!("pg_for()");
+        Reporter::new().subsection("pg_for()");
         let array: [u64; 8] = [0, 1, 2, 3, 4, 5, 6, 7];
         let mut idx: u64 = 0;
         for elem in array {
-            println!("elem in index {idx} is {elem}");
+            pg_println!("elem in index {idx} is {elem}");
             idx += 1;
         }
 
         for idx in 0..7 {
-            println!("elem in index {idx} is {}", array[idx]);
+            pg_println!("elem in index {idx} is {}", array[idx]);
         }
-        crate::core::utils::Utils::print_line_separator();
     }
 
     fn pg_while() {
-        println!("pg_while()");
+        Reporter::new().subsection("pg_while()");
         let mut var: u64 = 2;
         while var < 11 {
-            println!("var value is: {var}");
+            pg_println!("var value is: {var}");
             var += 1;
         }
-        println!("var value is: {var}");
-        crate::core::utils::Utils::print_line_separator();
+        pg_println!("var value is: {var}");
     }
 
     fn pg_loop() {
-        println!("pg_loop()");
+        Reporter::new().subsection("pg_loop()");
         let mut var: u64 = 2;
         loop {
-            println!("in loop: var value is: {var}");
+            pg_println!("in loop: var value is: {var}");
             var += 1;
             if var == 5 {
                 continue;
@@ -75,48 +75,43 @@ impl FirstSteps {
                 break;
             }
         }
-        println!("var last value is: {var}");
-        crate::core::utils::Utils::print_line_separator();
+        pg_println!("var last value is: {var}");
     }
 
     fn pg_ifelse(condition: bool, val_if_true: u64, val_if_false: u64) {
-        println!("pg_ifelse()");
+        Reporter::new().subsection("pg_ifelse()");
         //note, any block's value is the last expression in the block.
         let res: u64 = if condition { val_if_true } else { val_if_false };
-        println!("res is: {}", res);
-        crate::core::utils::Utils::print_line_separator();
+        pg_println!("res is: {}", res);
     }
 
     //function playground.
     fn pg_func(param: u64) -> u64 {
-        println!("pg_func()");
-        println!("param is: {}", param);
+        Reporter::new().subsection("pg_func()");
+        pg_println!("param is: {}", param);
         let mut res: u64 = 5;
         res += param;
-        println!("retrun value is {}", res);
-        crate::core::utils::Utils::print_line_separator();
+        pg_println!("retrun value is {}", res);
         return res; //I know one could use res. But for now, I don't like it.
                     //What about casting?
     }
 
     //mutable playground
     fn pg_mutable() {
-        println!("pg_mutable()");
+        Reporter::new().subsection("pg_mutable()");
         let var0: u64 = 2; //imutable by default.
         let mut var1: u64 = 3; //mutable.
                                //var0 += 1; //compilation error, since var0 is immutable.
         var1 += 1; //life is good.
-        println!("var0 = {}, var1 = {}", var0, var1);
-        crate::core::utils::Utils::print_line_separator();
+        pg_println!("var0 = {}, var1 = {}", var0, var1);
     }
 
     //data type playground:
     fn pg_datatype() {
-        println!("pg_datatype()");
+        Reporter::new().subsection("pg_datatype()");
         let var0: i32 = -1;
         let var1: u32 = 1;
-        println!("var0 = {}, var1 = {} ", var0, var1);
-        crate::core::utils::Utils::print_line_separator();
+        pg_println!("var0 = {}, var1 = {} ", var0, var1);
     }
 
     fn structs() {
@@ -127,6 +122,26 @@ impl FirstSteps {
         }
         let my_struct = MyStruct { m_x: 2, m_y: 3 };
         let x: u64 = my_struct.m_x + my_struct.m_y as u64;
-        println!("MY STRUCT IS {:?} and its some is: {x}", &my_struct);
+        pg_println!("MY STRUCT IS {:?} and its some is: {x}", &my_struct);
+    }
+}
+
+//_____________________________________________________________________________
+impl crate::core::example::Example for FirstSteps {
+    fn name(&self) -> &'static str {
+        return "first_steps";
+    }
+
+    fn category(&self) -> crate::core::example::Category {
+        return crate::core::example::Category::Examples;
+    }
+
+    fn description(&self) -> &'static str {
+        return "Data types, mutability, functions and control flow basics.";
+    }
+
+    fn run(&self) -> Result<(), crate::core::return_code::RC> {
+        FirstSteps::run_example();
+        return Ok(());
     }
 }
@@ -1,16 +1,18 @@
+use crate::pg_assert_eq;
+use crate::pg_println;
+
 pub fn pg_first_word_len_example() {
-    println!("FIRST WORD LEN EXAMPLE");
+    pg_println!("FIRST WORD LEN EXAMPLE");
     let str1 = String::from("3.14159265358979323");
     let str2 = String::from("pi is not that tasty");
-    println!(
+    pg_println!(
         "\"{str1}\" number of chars till space is: {}",
         first_word_len(&str1)
     );
-    println!(
+    pg_println!(
         "\"{str2}\" number of chars till space is: {}",
         first_word_len(&str2)
     );
-    crate::core::utils::Utils::print_line_separator();
 }
 /**
  * @param r_string a reference to a string or a string
@@ -22,8 +24,8 @@ pub fn pg_first_word_len_example() {
  */
 fn first_word_len(r_string: &str) -> usize {
     let len: usize = first_word_len_ver1(r_string);
-    assert_eq!(len, first_word_len_ver2(r_string));
-    assert_eq!(len, first_word_len_ver3(r_string));
+    pg_assert_eq!(len, first_word_len_ver2(r_string));
+    pg_assert_eq!(len, first_word_len_ver3(r_string));
     return len;
 }
 
@@ -40,7 +42,7 @@ fn first_word_len_ver1(r_string: &str) -> usize {
         }
         char_idx += 1;
     }
-    assert_eq!(char_idx, r_string.len());
+    pg_assert_eq!(char_idx, r_string.len());
     return char_idx;
 }
 
@@ -71,3 +73,25 @@ fn first_word_len_ver3(r_string: &str) -> usize {
     }
     return r_string.len();
 }
+
+//_____________________________________________________________________________
+pub struct PgFirstWordLen;
+
+impl crate::core::example::Example for PgFirstWordLen {
+    fn name(&self) -> &'static str {
+        return "first_word_len";
+    }
+
+    fn category(&self) -> crate::core::example::Category {
+        return crate::core::example::Category::Examples;
+    }
+
+    fn description(&self) -> &'static str {
+        return "Three ways to find the length of the first word.";
+    }
+
+    fn run(&self) -> Result<(), crate::core::return_code::RC> {
+        pg_first_word_len_example();
+        return Ok(());
+    }
+}
@@ -0,0 +1,507 @@
+use crate::pg_assert_eq;
+use crate::pg_println;
+
+pub mod doubly;
+pub mod queue;
+
+/**
+ * A singly linked list, the continuation of the Box lessons
+ * (pointers.rs) and the Option lessons (my_option.rs).
+ *
+ * Each node owns the next one through a Box, so the list is
+ * a chain of heap allocations:
+ *
+ * m_head -> [1 | next] -> [2 | next] -> [3 | None]
+ *
+ * The pointer to the next node is an Option<Box<Node<T>>>,
+ * None marks the end of the list. Option<Box<T>> has the
+ * size of a pointer: a Box is never null, so the compiler
+ * uses the null value to represent None (the "null pointer
+ * optimization").
+ *
+ * Most of the operations are done with Option::take(),
+ * which moves the value out of an Option and leaves None
+ * in its place. Moving out of a borrowed field is not
+ * allowed (the field would be left uninitialized), take()
+ * always leaves a valid value behind.
+ */
+pub struct LinkedList<T> {
+    m_head: Link<T>,
+    m_len: usize,
+}
+
+type Link<T> = Option<Box<Node<T>>>;
+
+struct Node<T> {
+    m_elem: T,
+    m_next: Link<T>,
+}
+
+impl<T> LinkedList<T> {
+    pub fn new() -> LinkedList<T> {
+        return LinkedList {
+            m_head: None,
+            m_len: 0,
+        };
+    }
+
+    /**
+     * The new node takes the current head as its next node,
+     * then becomes the head.
+     */
+    pub fn push_front(&mut self, elem: T) {
+        let node: Box<Node<T>> = Box::new(Node {
+            m_elem: elem,
+            m_next: self.m_head.take(),
+        });
+        self.m_head = Some(node);
+        self.m_len += 1;
+    }
+
+    pub fn pop_front(&mut self) -> Option<T> {
+        return self.m_head.take().map(|node| {
+            /*
+             * node is a Box<Node<T>> owned by the closure, so
+             * its fields can be moved out of it.
+             */
+            self.m_head = node.m_next;
+            self.m_len -= 1;
+            return node.m_elem;
+        });
+    }
+
+    /**
+     * as_ref() turns the &Option<Box<Node<T>>> into an
+     * Option<&Box<Node<T>>>, so the node is borrowed and not
+     * moved out of the list.
+     */
+    pub fn peek_front(&self) -> Option<&T> {
+        return self.m_head.as_ref().map(|node| &node.m_elem);
+    }
+
+    pub fn peek_front_mut(&mut self) -> Option<&mut T> {
+        return self.m_head.as_mut().map(|node| &mut node.m_elem);
+    }
+
+    pub fn len(&self) -> usize {
+        return self.m_len;
+    }
+
+    pub fn is_empty(&self) -> bool {
+        return self.m_head.is_none();
+    }
+
+    /**
+     * Reverses the list in place, no node is allocated or
+     * freed: each node is moved from the front of the rest
+     * of the list to the front of the reversed part.
+     *
+     * rest:     1 -> 2 -> 3     reversed: None
+     * rest:     2 -> 3          reversed: 1
+     * rest:     3               reversed: 2 -> 1
+     * rest:     None            reversed: 3 -> 2 -> 1
+     */
+    pub fn reverse(&mut self) {
+        let mut reversed: Link<T> = None;
+        let mut rest: Link<T> = self.m_head.take();
+        while let Some(mut node) = rest {
+            rest = node.m_next.take();
+            node.m_next = reversed;
+            reversed = Some(node);
+        }
+        self.m_head = reversed;
+    }
+
+    pub fn iter(&self) -> Iter<'_, T> {
+        return Iter {
+            m_next: self.m_head.as_deref(),
+        };
+    }
+
+    pub fn iter_mut(&mut self) -> IterMut<'_, T> {
+        return IterMut {
+            m_next: self.m_head.as_deref_mut(),
+        };
+    }
+}
+
+impl<T> Default for LinkedList<T> {
+    fn default() -> LinkedList<T> {
+        return LinkedList::new();
+    }
+}
+
+/**
+ * The default drop is recursive: dropping the head Box
+ * drops its node, which drops the next Box, and so on. Each
+ * node adds a stack frame, so dropping a list of a million
+ * nodes overflows the stack.
+ *
+ * Here the nodes are unlinked one by one: the next link is
+ * taken out of the node before the node is dropped, so
+ * dropping the node does not recurse.
+ */
+impl<T> Drop for LinkedList<T> {
+    fn drop(&mut self) {
+        let mut link: Link<T> = self.m_head.take();
+        while let Some(mut node) = link {
+            link = node.m_next.take();
+            /* node goes out of scope here, its next is None. */
+        }
+    }
+}
+
+/**
+ * Iterates by reference, from the front.
+ */
+pub struct Iter<'a, T> {
+    m_next: Option<&'a Node<T>>,
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<&'a T> {
+        return self.m_next.map(|node| {
+            self.m_next = node.m_next.as_deref();
+            return &node.m_elem;
+        });
+    }
+}
+
+/**
+ * Iterates by mutable reference, from the front.
+ *
+ * Unlike Iter, the Option<&mut Node<T>> is not Copy (two
+ * mutable references to the same node cannot exist), so
+ * the current node is taken out of m_next before giving
+ * out a reference to its element.
+ */
+pub struct IterMut<'a, T> {
+    m_next: Option<&'a mut Node<T>>,
+}
+
+impl<'a, T> Iterator for IterMut<'a, T> {
+    type Item = &'a mut T;
+
+    fn next(&mut self) -> Option<&'a mut T> {
+        return self.m_next.take().map(|node| {
+            self.m_next = node.m_next.as_deref_mut();
+            return &mut node.m_elem;
+        });
+    }
+}
+
+/**
+ * Iterates by value, the list is consumed.
+ */
+pub struct IntoIter<T>(LinkedList<T>);
+
+impl<T> Iterator for IntoIter<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        return self.0.pop_front();
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        return (self.0.len(), Some(self.0.len()));
+    }
+}
+
+impl<T> IntoIterator for LinkedList<T> {
+    type Item = T;
+    type IntoIter = IntoIter<T>;
+
+    fn into_iter(self) -> IntoIter<T> {
+        return IntoIter(self);
+    }
+}
+
+impl<'a, T> IntoIterator for &'a LinkedList<T> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Iter<'a, T> {
+        return self.iter();
+    }
+}
+
+impl<'a, T> IntoIterator for &'a mut LinkedList<T> {
+    type Item = &'a mut T;
+    type IntoIter = IterMut<'a, T>;
+
+    fn into_iter(self) -> IterMut<'a, T> {
+        return self.iter_mut();
+    }
+}
+
+/**
+ * Appends the elements at the back, in the iteration order
+ * (like Vec::extend). The end of the list is found once,
+ * then each new node is linked after the previous one.
+ */
+impl<T> Extend<T> for LinkedList<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        let mut tail: &mut Link<T> = &mut self.m_head;
+        while let Some(node) = tail {
+            tail = &mut node.m_next;
+        }
+        for elem in iter {
+            let node: &mut Box<Node<T>> = tail.insert(Box::new(Node {
+                m_elem: elem,
+                m_next: None,
+            }));
+            tail = &mut node.m_next;
+            self.m_len += 1;
+        }
+    }
+}
+
+/**
+ * The list keeps the iteration order: [1, 2, 3] collects
+ * to 1 -> 2 -> 3.
+ */
+impl<T> FromIterator<T> for LinkedList<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> LinkedList<T> {
+        let mut list: LinkedList<T> = LinkedList::new();
+        list.extend(iter);
+        return list;
+    }
+}
+
+/**
+ * Implemented with the iterators and not derived: the
+ * derived implementations recurse into the next node, like
+ * the default drop.
+ */
+impl<T: std::fmt::Debug> std::fmt::Debug for LinkedList<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        return f.debug_list().entries(self.iter()).finish();
+    }
+}
+
+impl<T: PartialEq> PartialEq for LinkedList<T> {
+    fn eq(&self, other: &LinkedList<T>) -> bool {
+        return self.len() == other.len() && self.iter().eq(other.iter());
+    }
+}
+
+//_____________________________________________________________________________
+pub struct LinkedListExample;
+
+impl LinkedListExample {
+    pub fn run_example() {
+        LinkedListExample::push_pop_peek();
+        LinkedListExample::reverse();
+        LinkedListExample::iterators();
+        LinkedListExample::collect_extend();
+        LinkedListExample::long_list_drop();
+    }
+
+    fn push_pop_peek() {
+        let mut list: LinkedList<u64> = LinkedList::new();
+        list.push_front(3);
+        list.push_front(2);
+        list.push_front(1);
+        pg_println!("after pushing 3, 2, 1 to the front: {:?}", list);
+        pg_assert_eq!(list.pop_front(), Some(1));
+        pg_assert_eq!(list.peek_front(), Some(&2));
+    }
+
+    fn reverse() {
+        let mut list: LinkedList<char> = "abcd".chars().collect();
+        list.reverse();
+        pg_println!("\"abcd\" reversed: {:?}", list);
+    }
+
+    fn iterators() {
+        let mut list: LinkedList<String> =
+            ["x", "y", "z"].iter().map(|s| s.to_string()).collect();
+        for elem in list.iter_mut() {
+            elem.push('!');
+        }
+        let owned: Vec<String> = list.into_iter().collect();
+        pg_println!("owned elements: {:?}", owned);
+    }
+
+    fn collect_extend() {
+        let mut list: LinkedList<i32> = (1..=3).collect();
+        list.extend(vec![4, 5]);
+        pg_println!("(1..=3) collected and extended with [4, 5]: {:?}", list);
+    }
+
+    /**
+     * With the default (recursive) drop, this overflows the
+     * stack.
+     */
+    fn long_list_drop() {
+        let len: usize = 1_000_000;
+        let list: LinkedList<usize> = (0..len).collect();
+        drop(list);
+        pg_println!("a list of {len} nodes was dropped");
+    }
+}
+
+impl crate::core::example::Example for LinkedListExample {
+    fn name(&self) -> &'static str {
+        return "llist";
+    }
+
+    fn category(&self) -> crate::core::example::Category {
+        return crate::core::example::Category::Examples;
+    }
+
+    fn description(&self) -> &'static str {
+        return "A generic singly linked list of boxed nodes.";
+    }
+
+    fn run(&self) -> Result<(), crate::core::return_code::RC> {
+        LinkedListExample::run_example();
+        return Ok(());
+    }
+}
+
+/**
+ * Compares LinkedList with a VecDeque (used as a stack at
+ * its front) on random sequences of operations, see
+ * core::fuzz. Each operation is an (op, value) pair, op
+ * selects push_front, pop_front, peek_front, reverse or
+ * an increment of all the elements with iter_mut, the
+ * output is what each operation observed and the final
+ * elements.
+ */
+pub struct LinkedListFuzz;
+
+impl crate::core::fuzz::Differential for LinkedListFuzz {
+    type Input = Vec<(u8, i32)>;
+    type Output = (Vec<Option<i32>>, Vec<i32>);
+
+    fn name(&self) -> &'static str {
+        return "llist";
+    }
+
+    fn reference(ops: Vec<(u8, i32)>) -> (Vec<Option<i32>>, Vec<i32>) {
+        let mut deque: std::collections::VecDeque<i32> =
+            std::collections::VecDeque::new();
+        let mut observed: Vec<Option<i32>> = Vec::new();
+        for (op, value) in ops {
+            match op % 5 {
+                0 => deque.push_front(value),
+                1 => observed.push(deque.pop_front()),
+                2 => observed.push(deque.front().copied()),
+                3 => deque.make_contiguous().reverse(),
+                _ => deque
+                    .iter_mut()
+                    .for_each(|elem| *elem = elem.wrapping_add(1)),
+            }
+            observed.push(Some(deque.len() as i32));
+        }
+        return (observed, deque.into_iter().collect());
+    }
+
+    fn candidate(ops: Vec<(u8, i32)>) -> (Vec<Option<i32>>, Vec<i32>) {
+        let mut list: LinkedList<i32> = LinkedList::new();
+        let mut observed: Vec<Option<i32>> = Vec::new();
+        for (op, value) in ops {
+            match op % 5 {
+                0 => list.push_front(value),
+                1 => observed.push(list.pop_front()),
+                2 => observed.push(list.peek_front().copied()),
+                3 => list.reverse(),
+                _ => list
+                    .iter_mut()
+                    .for_each(|elem| *elem = elem.wrapping_add(1)),
+            }
+            observed.push(Some(list.len() as i32));
+        }
+        return (observed, list.into_iter().collect());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn push_pop_peek() {
+        let mut list: LinkedList<u64> = LinkedList::new();
+        assert!(list.is_empty());
+        assert_eq!(list.pop_front(), None);
+        assert_eq!(list.peek_front(), None);
+        list.push_front(3);
+        list.push_front(2);
+        list.push_front(1);
+        assert_eq!(list.len(), 3);
+        assert_eq!(list.peek_front(), Some(&1));
+        if let Some(front) = list.peek_front_mut() {
+            *front = 10;
+        }
+        assert_eq!(list.pop_front(), Some(10));
+        assert_eq!(list.pop_front(), Some(2));
+        assert_eq!(list.len(), 1);
+        assert_eq!(list.pop_front(), Some(3));
+        assert_eq!(list.pop_front(), None);
+        assert!(list.is_empty());
+    }
+
+    #[test]
+    fn reverse() {
+        let mut list: LinkedList<char> = "abcd".chars().collect();
+        list.reverse();
+        assert_eq!(list.iter().collect::<String>(), "dcba");
+        assert_eq!(list.len(), 4);
+
+        let mut empty: LinkedList<char> = LinkedList::new();
+        empty.reverse();
+        assert!(empty.is_empty());
+        let mut single: LinkedList<char> = LinkedList::new();
+        single.push_front('a');
+        single.reverse();
+        assert_eq!(single.peek_front(), Some(&'a'));
+    }
+
+    #[test]
+    fn iterators() {
+        let mut list: LinkedList<String> =
+            ["x", "y", "z"].iter().map(|s| s.to_string()).collect();
+        for elem in list.iter_mut() {
+            elem.push('!');
+        }
+        let borrowed: Vec<&String> = list.iter().collect();
+        assert_eq!(borrowed, ["x!", "y!", "z!"]);
+        let lens: usize = (&list).into_iter().map(|s| s.len()).sum();
+        assert_eq!(lens, 6);
+        let into_iter: IntoIter<String> = list.into_iter();
+        assert_eq!(into_iter.size_hint(), (3, Some(3)));
+        assert_eq!(into_iter.collect::<Vec<String>>(), ["x!", "y!", "z!"]);
+    }
+
+    #[test]
+    fn collect_extend() {
+        let mut list: LinkedList<i32> = (1..=3).collect();
+        list.extend(vec![4, 5]);
+        assert_eq!(list.len(), 5);
+        assert_eq!(list, (1..=5).collect::<LinkedList<i32>>());
+        assert_eq!(format!("{list:?}"), "[1, 2, 3, 4, 5]");
+
+        let mut empty: LinkedList<i32> = LinkedList::new();
+        empty.extend(std::iter::empty());
+        assert!(empty.is_empty());
+        empty.extend([7]);
+        assert_eq!(empty.peek_front(), Some(&7));
+    }
+
+    /** The default (recursive) drop overflows the stack. */
+    #[test]
+    fn long_list_drop() {
+        let list: LinkedList<usize> = (0..1_000_000).collect();
+        assert_eq!(list.len(), 1_000_000);
+        drop(list);
+    }
+
+    #[test]
+    fn matches_a_vec_deque() {
+        crate::core::fuzz::assert_agree(&LinkedListFuzz, 200);
+    }
+}
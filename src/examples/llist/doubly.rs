@@ -0,0 +1,825 @@
+use crate::pg_assert;
+use crate::pg_assert_eq;
+use crate::pg_println;
+use std::cell::{Ref, RefCell};
+use std::rc::{Rc, Weak};
+
+/**
+ * A doubly linked list with shared ownership: a node is
+ * pointed to by its previous node (or the head) and by its
+ * next node (or the tail), so a Box (a single owner) cannot
+ * be used.
+ *
+ * - Rc<T> gives several owners to the same value, the value
+ *   is dropped when the last Rc is dropped.
+ * - RefCell<T> allows mutating a node through any of its
+ *   Rcs (the interior mutability pattern of core_cell.rs),
+ *   the borrow rules are checked at run time.
+ * - Weak<T> is a non owning Rc. If the back links were Rcs,
+ *   two neighbours would own each other: a reference cycle,
+ *   their counts never reach 0 and the nodes leak. With a
+ *   Weak back link each node has exactly one owner (the
+ *   previous node's m_next, or m_head).
+ *
+ * m_head =Rc=> [1] =Rc=> [2] =Rc=> [3]
+ *               ^- Weak -'^- Weak -'  ^- Weak - m_tail
+ *
+ * Weak::upgrade() returns an Rc while the node is alive and
+ * None once it was dropped.
+ */
+pub struct DoublyLinkedList<T> {
+    m_head: Link<T>,
+    m_tail: WeakLink<T>,
+    m_len: usize,
+}
+
+type Link<T> = Option<Rc<RefCell<Node<T>>>>;
+type WeakLink<T> = Option<Weak<RefCell<Node<T>>>>;
+
+struct Node<T> {
+    m_elem: T,
+    m_next: Link<T>,
+    m_prev: WeakLink<T>,
+}
+
+impl<T> Node<T> {
+    fn new(elem: T) -> Rc<RefCell<Node<T>>> {
+        return Rc::new(RefCell::new(Node {
+            m_elem: elem,
+            m_next: None,
+            m_prev: None,
+        }));
+    }
+
+    /**
+     * Moves the element out of an unlinked node.
+     * Rc::try_unwrap succeeds only for the last Rc of the
+     * node (the Weaks do not count), so a panic here means
+     * the node is still owned somewhere: it would leak.
+     */
+    fn into_elem(node: Rc<RefCell<Node<T>>>) -> T {
+        match Rc::try_unwrap(node) {
+            Ok(cell) => return cell.into_inner().m_elem,
+            Err(_) => panic!("a removed node is still owned"),
+        }
+    }
+}
+
+fn upgrade<T>(link: &WeakLink<T>) -> Link<T> {
+    return link.as_ref().and_then(Weak::upgrade);
+}
+
+impl<T> DoublyLinkedList<T> {
+    pub fn new() -> DoublyLinkedList<T> {
+        return DoublyLinkedList {
+            m_head: None,
+            m_tail: None,
+            m_len: 0,
+        };
+    }
+
+    pub fn len(&self) -> usize {
+        return self.m_len;
+    }
+
+    pub fn is_empty(&self) -> bool {
+        return self.m_head.is_none();
+    }
+
+    pub fn push_front(&mut self, elem: T) {
+        let node: Rc<RefCell<Node<T>>> = Node::new(elem);
+        match self.m_head.take() {
+            Some(old_head) => {
+                old_head.borrow_mut().m_prev = Some(Rc::downgrade(&node));
+                node.borrow_mut().m_next = Some(old_head);
+            }
+            None => self.m_tail = Some(Rc::downgrade(&node)),
+        }
+        self.m_head = Some(node);
+        self.m_len += 1;
+    }
+
+    pub fn push_back(&mut self, elem: T) {
+        let node: Rc<RefCell<Node<T>>> = Node::new(elem);
+        match upgrade(&self.m_tail) {
+            Some(old_tail) => {
+                node.borrow_mut().m_prev = Some(Rc::downgrade(&old_tail));
+                self.m_tail = Some(Rc::downgrade(&node));
+                old_tail.borrow_mut().m_next = Some(node);
+            }
+            None => {
+                self.m_tail = Some(Rc::downgrade(&node));
+                self.m_head = Some(node);
+            }
+        }
+        self.m_len += 1;
+    }
+
+    pub fn pop_front(&mut self) -> Option<T> {
+        let head: Rc<RefCell<Node<T>>> = self.m_head.take()?;
+        match head.borrow_mut().m_next.take() {
+            Some(next) => {
+                next.borrow_mut().m_prev = None;
+                self.m_head = Some(next);
+            }
+            None => self.m_tail = None,
+        }
+        self.m_len -= 1;
+        return Some(Node::into_elem(head));
+    }
+
+    pub fn pop_back(&mut self) -> Option<T> {
+        /* The tail is owned by its previous node (or m_head). */
+        let tail: Rc<RefCell<Node<T>>> = upgrade(&self.m_tail)?;
+        match upgrade(&tail.borrow_mut().m_prev.take()) {
+            Some(prev) => {
+                prev.borrow_mut().m_next = None;
+                self.m_tail = Some(Rc::downgrade(&prev));
+            }
+            None => {
+                self.m_head = None;
+                self.m_tail = None;
+            }
+        }
+        self.m_len -= 1;
+        return Some(Node::into_elem(tail));
+    }
+
+    /**
+     * The Ref keeps the node's RefCell borrowed, a
+     * borrow_mut() of the node panics till it's dropped.
+     */
+    pub fn peek_front(&self) -> Option<Ref<'_, T>> {
+        return self
+            .m_head
+            .as_ref()
+            .map(|node| Ref::map(node.borrow(), |node| &node.m_elem));
+    }
+
+    /**
+     * Unlike peek_front, a Ref cannot be returned: the tail
+     * is reached through a Weak, the Rc upgraded from it is
+     * a local of this function and a Ref borrowing it cannot
+     * outlive it. So the element is cloned.
+     */
+    pub fn peek_back(&self) -> Option<T>
+    where
+        T: Clone,
+    {
+        return upgrade(&self.m_tail).map(|node| node.borrow().m_elem.clone());
+    }
+
+    /**
+     * @return the elements from the front, following the Rc
+     * (forward) links.
+     */
+    pub fn to_vec(&self) -> Vec<T>
+    where
+        T: Clone,
+    {
+        let mut res: Vec<T> = Vec::with_capacity(self.m_len);
+        let mut link: Link<T> = self.m_head.clone();
+        while let Some(node) = link {
+            res.push(node.borrow().m_elem.clone());
+            link = node.borrow().m_next.clone();
+        }
+        return res;
+    }
+
+    /**
+     * @return the elements from the back, following the
+     * Weak (back) links.
+     */
+    pub fn to_vec_rev(&self) -> Vec<T>
+    where
+        T: Clone,
+    {
+        let mut res: Vec<T> = Vec::with_capacity(self.m_len);
+        let mut link: Link<T> = upgrade(&self.m_tail);
+        while let Some(node) = link {
+            res.push(node.borrow().m_elem.clone());
+            link = upgrade(&node.borrow().m_prev);
+        }
+        return res;
+    }
+
+    /**
+     * @return (strong, weak) counts of each node, from the
+     * front. Each node has a single owner (strong == 1), and
+     * a Weak from its next node and/or from m_tail.
+     */
+    pub fn counts(&self) -> Vec<(usize, usize)> {
+        let mut res: Vec<(usize, usize)> = Vec::with_capacity(self.m_len);
+        let mut link: Link<T> = self.m_head.clone();
+        while let Some(node) = link {
+            /* - 1, the Rc of this loop is not an owner. */
+            res.push((Rc::strong_count(&node) - 1, Rc::weak_count(&node)));
+            link = node.borrow().m_next.clone();
+        }
+        return res;
+    }
+
+    /**
+     * A cursor on the first element (or on the "ghost"
+     * position when the list is empty).
+     */
+    pub fn cursor_front_mut(&mut self) -> CursorMut<'_, T> {
+        let current: Link<T> = self.m_head.clone();
+        return CursorMut {
+            m_list: self,
+            m_current: current,
+        };
+    }
+
+    pub fn cursor_back_mut(&mut self) -> CursorMut<'_, T> {
+        let current: Link<T> = upgrade(&self.m_tail);
+        return CursorMut {
+            m_list: self,
+            m_current: current,
+        };
+    }
+}
+
+impl<T> Default for DoublyLinkedList<T> {
+    fn default() -> DoublyLinkedList<T> {
+        return DoublyLinkedList::new();
+    }
+}
+
+/**
+ * The default drop would recurse through the Rc links (see
+ * the singly linked list), the nodes are unlinked one by
+ * one instead.
+ */
+impl<T> Drop for DoublyLinkedList<T> {
+    fn drop(&mut self) {
+        let mut link: Link<T> = self.m_head.take();
+        while let Some(node) = link {
+            link = node.borrow_mut().m_next.take();
+        }
+    }
+}
+
+impl<T: std::fmt::Debug> std::fmt::Debug for DoublyLinkedList<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let mut list: std::fmt::DebugList = f.debug_list();
+        let mut link: Link<T> = self.m_head.clone();
+        while let Some(node) = link {
+            list.entry(&node.borrow().m_elem);
+            link = node.borrow().m_next.clone();
+        }
+        return list.finish();
+    }
+}
+
+/**
+ * A position in the list to insert and remove elements in
+ * the middle, in O(1).
+ *
+ * The cursor is either on a node or on the "ghost"
+ * position, between the back and the front (like
+ * std::collections::linked_list::CursorMut): moving next
+ * from the back goes to the ghost, and moving next from the
+ * ghost goes to the front.
+ *
+ * The cursor holds an Rc of its node, so while it exists the
+ * node has 2 strong references. The list is mutably
+ * borrowed by the cursor, it cannot be changed behind its
+ * back.
+ */
+pub struct CursorMut<'a, T> {
+    m_list: &'a mut DoublyLinkedList<T>,
+    m_current: Link<T>,
+}
+
+/**
+ * Nothing to do, m_current is dropped anyway. But a type
+ * with a Drop impl stays borrowed till it is dropped (not
+ * only till its last use): without it, a cursor kept in
+ * scope after its last use would still hold its node while
+ * the list is used again, and removing that node would find
+ * it still owned (see Node::into_elem).
+ */
+impl<T> Drop for CursorMut<'_, T> {
+    fn drop(&mut self) {}
+}
+
+impl<T> CursorMut<'_, T> {
+    /**
+     * @return None on the ghost position.
+     */
+    pub fn current(&self) -> Option<Ref<'_, T>> {
+        return self
+            .m_current
+            .as_ref()
+            .map(|node| Ref::map(node.borrow(), |node| &node.m_elem));
+    }
+
+    pub fn move_next(&mut self) {
+        self.m_current = match self.m_current.as_ref() {
+            Some(node) => node.borrow().m_next.clone(),
+            None => self.m_list.m_head.clone(),
+        };
+    }
+
+    pub fn move_prev(&mut self) {
+        self.m_current = match self.m_current.as_ref() {
+            Some(node) => upgrade(&node.borrow().m_prev),
+            None => upgrade(&self.m_list.m_tail),
+        };
+    }
+
+    /**
+     * Inserts after the current node, at the front on the
+     * ghost position. The cursor does not move.
+     */
+    pub fn insert_after(&mut self, elem: T) {
+        let current: &Rc<RefCell<Node<T>>> = match self.m_current.as_ref() {
+            Some(current) => current,
+            None => return self.m_list.push_front(elem),
+        };
+        let node: Rc<RefCell<Node<T>>> = Node::new(elem);
+        node.borrow_mut().m_prev = Some(Rc::downgrade(current));
+        match current.borrow_mut().m_next.take() {
+            Some(next) => {
+                next.borrow_mut().m_prev = Some(Rc::downgrade(&node));
+                node.borrow_mut().m_next = Some(next);
+            }
+            None => self.m_list.m_tail = Some(Rc::downgrade(&node)),
+        }
+        current.borrow_mut().m_next = Some(node);
+        self.m_list.m_len += 1;
+    }
+
+    /**
+     * Inserts before the current node, at the back on the
+     * ghost position. The cursor does not move.
+     */
+    pub fn insert_before(&mut self, elem: T) {
+        let current: &Rc<RefCell<Node<T>>> = match self.m_current.as_ref() {
+            Some(current) => current,
+            None => return self.m_list.push_back(elem),
+        };
+        let node: Rc<RefCell<Node<T>>> = Node::new(elem);
+        let prev: Link<T> = upgrade(&current.borrow().m_prev);
+        current.borrow_mut().m_prev = Some(Rc::downgrade(&node));
+        node.borrow_mut().m_next = Some(current.clone());
+        /* Replaces the owner Rc of current by the one of node. */
+        match prev {
+            Some(prev) => {
+                node.borrow_mut().m_prev = Some(Rc::downgrade(&prev));
+                prev.borrow_mut().m_next = Some(node);
+            }
+            None => self.m_list.m_head = Some(node),
+        }
+        self.m_list.m_len += 1;
+    }
+
+    /**
+     * Removes the current node, the cursor moves to the
+     * next one.
+     * @return None on the ghost position.
+     */
+    pub fn remove_current(&mut self) -> Option<T> {
+        let current: Rc<RefCell<Node<T>>> = self.m_current.take()?;
+        let prev: Link<T> = upgrade(&current.borrow_mut().m_prev.take());
+        let next: Link<T> = current.borrow_mut().m_next.take();
+        let prev_weak: WeakLink<T> = prev.as_ref().map(Rc::downgrade);
+        match next.as_ref() {
+            Some(next) => next.borrow_mut().m_prev = prev_weak,
+            None => self.m_list.m_tail = prev_weak,
+        }
+        /* Drops the owner Rc of current. */
+        match prev.as_ref() {
+            Some(prev) => prev.borrow_mut().m_next = next.clone(),
+            None => self.m_list.m_head = next.clone(),
+        }
+        self.m_current = next;
+        self.m_list.m_len -= 1;
+        return Some(Node::into_elem(current));
+    }
+}
+
+//_____________________________________________________________________________
+pub struct DoublyLinkedListExample;
+
+impl DoublyLinkedListExample {
+    pub fn run_example() {
+        DoublyLinkedListExample::both_ends();
+        DoublyLinkedListExample::cursor();
+        DoublyLinkedListExample::no_leaks();
+    }
+
+    fn both_ends() {
+        let mut list: DoublyLinkedList<i32> = DoublyLinkedList::new();
+        pg_assert!(list.is_empty());
+        pg_assert_eq!(list.pop_front(), None);
+        pg_assert_eq!(list.pop_back(), None);
+        pg_assert_eq!(list.peek_back(), None);
+
+        list.push_back(2);
+        list.push_back(3);
+        list.push_front(1);
+        pg_println!(
+            "after push_back(2), push_back(3), push_front(1): {list:?}"
+        );
+        pg_assert_eq!(list.len(), 3);
+        pg_assert_eq!(list.peek_front().map(|elem| *elem), Some(1));
+        pg_assert_eq!(list.peek_back(), Some(3));
+        pg_assert_eq!(list.to_vec(), [1, 2, 3]);
+        pg_assert_eq!(list.to_vec_rev(), [3, 2, 1]);
+
+        pg_assert_eq!(list.pop_back(), Some(3));
+        pg_assert_eq!(list.pop_front(), Some(1));
+        pg_assert_eq!(list.pop_back(), Some(2));
+        pg_assert_eq!(list.pop_front(), None);
+        pg_assert!(list.is_empty());
+        pg_assert_eq!(list.to_vec_rev(), []);
+    }
+
+    fn cursor() {
+        let mut list: DoublyLinkedList<char> = DoublyLinkedList::new();
+        "ace".chars().for_each(|ch| list.push_back(ch));
+
+        let mut cursor: CursorMut<char> = list.cursor_front_mut();
+        cursor.insert_after('b');
+        cursor.move_next();
+        cursor.move_next();
+        pg_assert_eq!(cursor.current().map(|ch| *ch), Some('c'));
+        cursor.insert_after('d');
+        cursor.insert_before('x');
+        cursor.move_prev();
+        pg_assert_eq!(cursor.remove_current(), Some('x'));
+        pg_assert_eq!(cursor.current().map(|ch| *ch), Some('c'));
+        drop(cursor);
+
+        /* The ghost position, between the back and the front. */
+        let mut cursor: CursorMut<char> = list.cursor_back_mut();
+        cursor.move_next();
+        pg_assert!(cursor.current().is_none());
+        pg_assert_eq!(cursor.remove_current(), None);
+        cursor.insert_after('<');
+        cursor.insert_before('>');
+        drop(cursor);
+        pg_println!("after the cursor inserts and removes: {list:?}");
+        pg_assert_eq!(list.to_vec().iter().collect::<String>(), "<abcde>");
+        pg_assert_eq!(list.to_vec_rev().iter().collect::<String>(), ">edcba<");
+        pg_assert_eq!(list.len(), 7);
+
+        let mut cursor: CursorMut<char> = list.cursor_front_mut();
+        while cursor.current().is_some() {
+            cursor.remove_current();
+        }
+        drop(cursor);
+        pg_assert!(list.is_empty());
+        pg_assert_eq!(list.peek_back(), None);
+    }
+
+    /**
+     * The strong count of each node is 1 (only its owner) and
+     * the elements are dropped as soon as they are removed.
+     * The elements are Rcs too: their own strong count tells
+     * whether the list still holds a copy.
+     */
+    fn no_leaks() {
+        let elem: Rc<String> = Rc::new("elem".to_string());
+        let mut list: DoublyLinkedList<Rc<String>> = DoublyLinkedList::new();
+        for _ in 0..4 {
+            list.push_back(elem.clone());
+        }
+        pg_assert_eq!(Rc::strong_count(&elem), 5);
+        let counts: Vec<(usize, usize)> = list.counts();
+        pg_println!("(strong, weak) counts of the nodes: {counts:?}");
+        pg_assert!(counts.iter().all(|&(strong, _)| strong == 1));
+        /* A Weak from the next node, and from m_tail for the last. */
+        pg_assert_eq!(counts, [(1, 1), (1, 1), (1, 1), (1, 1)]);
+
+        let mut cursor: CursorMut<Rc<String>> = list.cursor_front_mut();
+        cursor.move_next();
+        let removed: Weak<RefCell<Node<Rc<String>>>> = match &cursor.m_current {
+            Some(node) => Rc::downgrade(node),
+            None => Weak::new(),
+        };
+        drop(cursor.remove_current());
+        drop(cursor);
+        pg_assert!(removed.upgrade().is_none());
+        pg_assert_eq!(Rc::strong_count(&elem), 4);
+
+        drop(list.pop_front());
+        drop(list.pop_back());
+        pg_assert_eq!(Rc::strong_count(&elem), 2);
+        pg_assert_eq!(list.counts(), [(1, 1)]);
+
+        list.push_front(elem.clone());
+        drop(list);
+        pg_assert_eq!(Rc::strong_count(&elem), 1);
+        pg_assert_eq!(Rc::weak_count(&elem), 0);
+
+        let len: usize = 1_000_000;
+        let mut long: DoublyLinkedList<usize> = DoublyLinkedList::new();
+        (0..len).for_each(|i| long.push_back(i));
+        drop(long);
+        pg_println!("a list of {len} nodes was dropped");
+    }
+}
+
+impl crate::core::example::Example for DoublyLinkedListExample {
+    fn name(&self) -> &'static str {
+        return "doubly_llist";
+    }
+
+    fn category(&self) -> crate::core::example::Category {
+        return crate::core::example::Category::Examples;
+    }
+
+    fn description(&self) -> &'static str {
+        return "A doubly linked list of Rc<RefCell> nodes with Weak back links.";
+    }
+
+    fn run(&self) -> Result<(), crate::core::return_code::RC> {
+        DoublyLinkedListExample::run_example();
+        return Ok(());
+    }
+}
+
+/**
+ * Compares DoublyLinkedList with a VecDeque on random
+ * sequences of operations, see core::fuzz. Each operation
+ * is an (op, value) pair, op selects a push or a pop at
+ * either end, or a cursor moved |value| % 16 steps from the
+ * front (the ghost position is step len) which then inserts
+ * after, inserts before, removes, or moves back and reads.
+ * The output is what each operation observed, the elements
+ * in both directions and whether all the strong counts are
+ * 1.
+ */
+pub struct DoublyLinkedListFuzz;
+
+/** (observed, forward, backward, no leak). */
+type DoublyLinkedListSummary = (Vec<Option<i32>>, Vec<i32>, Vec<i32>, bool);
+
+impl DoublyLinkedListFuzz {
+    /** The cursor position, len is the ghost position. */
+    fn position(value: i32, len: usize) -> usize {
+        return (value.unsigned_abs() % 16) as usize % (len + 1);
+    }
+}
+
+impl crate::core::fuzz::Differential for DoublyLinkedListFuzz {
+    type Input = Vec<(u8, i32)>;
+    type Output = DoublyLinkedListSummary;
+
+    fn name(&self) -> &'static str {
+        return "doubly_llist";
+    }
+
+    fn reference(ops: Vec<(u8, i32)>) -> DoublyLinkedListSummary {
+        let mut deque: std::collections::VecDeque<i32> =
+            std::collections::VecDeque::new();
+        let mut observed: Vec<Option<i32>> = Vec::new();
+        for (op, value) in ops {
+            let pos: usize = DoublyLinkedListFuzz::position(value, deque.len());
+            let on_node: bool = pos < deque.len();
+            match op % 8 {
+                0 => deque.push_front(value),
+                1 => deque.push_back(value),
+                2 => observed.push(deque.pop_front()),
+                3 => observed.push(deque.pop_back()),
+                4 if on_node => deque.insert(pos + 1, value),
+                4 => deque.push_front(value),
+                5 => deque.insert(pos, value),
+                6 if on_node => observed.push(deque.remove(pos)),
+                6 => observed.push(None),
+                /* move_prev from the front goes to the ghost. */
+                _ if on_node && pos == 0 => observed.push(None),
+                _ if on_node => observed.push(deque.get(pos - 1).copied()),
+                _ => observed.push(deque.back().copied()),
+            }
+            observed.push(Some(deque.len() as i32));
+        }
+        let backward: Vec<i32> = deque.iter().rev().copied().collect();
+        return (observed, deque.into_iter().collect(), backward, true);
+    }
+
+    fn candidate(ops: Vec<(u8, i32)>) -> DoublyLinkedListSummary {
+        let mut list: DoublyLinkedList<i32> = DoublyLinkedList::new();
+        let mut observed: Vec<Option<i32>> = Vec::new();
+        for (op, value) in ops {
+            let pos: usize = DoublyLinkedListFuzz::position(value, list.len());
+            match op % 8 {
+                0 => list.push_front(value),
+                1 => list.push_back(value),
+                2 => observed.push(list.pop_front()),
+                3 => observed.push(list.pop_back()),
+                _ => {
+                    let mut cursor: CursorMut<i32> = list.cursor_front_mut();
+                    (0..pos).for_each(|_| cursor.move_next());
+                    match op % 8 {
+                        4 => cursor.insert_after(value),
+                        5 => cursor.insert_before(value),
+                        6 => observed.push(cursor.remove_current()),
+                        _ => {
+                            cursor.move_prev();
+                            observed.push(cursor.current().map(|elem| *elem));
+                        }
+                    }
+                }
+            }
+            observed.push(Some(list.len() as i32));
+        }
+        let no_leak: bool =
+            list.counts().iter().all(|&(strong, _)| strong == 1);
+        return (observed, list.to_vec(), list.to_vec_rev(), no_leak);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn from_chars(text: &str) -> DoublyLinkedList<char> {
+        let mut list: DoublyLinkedList<char> = DoublyLinkedList::new();
+        text.chars().for_each(|ch| list.push_back(ch));
+        return list;
+    }
+
+    fn text(list: &DoublyLinkedList<char>) -> String {
+        return list.to_vec().into_iter().collect();
+    }
+
+    /** The back links agree with the forward links. */
+    fn assert_linked(list: &DoublyLinkedList<char>) {
+        let mut backward: Vec<char> = list.to_vec_rev();
+        backward.reverse();
+        assert_eq!(list.to_vec(), backward);
+        assert_eq!(list.to_vec().len(), list.len());
+        assert!(list.counts().iter().all(|&(strong, _)| strong == 1));
+    }
+
+    #[test]
+    fn push_pop_both_ends() {
+        let mut list: DoublyLinkedList<i32> = DoublyLinkedList::new();
+        assert!(list.is_empty());
+        assert_eq!(list.pop_front(), None);
+        assert_eq!(list.pop_back(), None);
+        assert_eq!(list.peek_back(), None);
+        list.push_back(2);
+        list.push_back(3);
+        list.push_front(1);
+        assert_eq!(list.len(), 3);
+        assert_eq!(list.peek_front().map(|elem| *elem), Some(1));
+        assert_eq!(list.peek_back(), Some(3));
+        assert_eq!(list.to_vec(), [1, 2, 3]);
+        assert_eq!(list.to_vec_rev(), [3, 2, 1]);
+        assert_eq!(list.pop_back(), Some(3));
+        assert_eq!(list.pop_front(), Some(1));
+        assert_eq!(list.pop_back(), Some(2));
+        assert_eq!(list.pop_front(), None);
+        assert!(list.is_empty());
+        list.push_front(4);
+        assert_eq!(list.peek_back(), Some(4));
+    }
+
+    #[test]
+    fn cursor_insert() {
+        let mut list: DoublyLinkedList<char> = from_chars("ace");
+        let mut cursor: CursorMut<char> = list.cursor_front_mut();
+        cursor.insert_after('b');
+        cursor.move_next();
+        cursor.move_next();
+        assert_eq!(cursor.current().map(|ch| *ch), Some('c'));
+        cursor.insert_after('d');
+        cursor.insert_before('x');
+        drop(cursor);
+        assert_eq!(text(&list), "abxcde");
+        assert_linked(&list);
+
+        /* Before the front and after the back. */
+        let mut cursor: CursorMut<char> = list.cursor_front_mut();
+        cursor.insert_before('<');
+        drop(cursor);
+        let mut cursor: CursorMut<char> = list.cursor_back_mut();
+        cursor.insert_after('>');
+        drop(cursor);
+        assert_eq!(text(&list), "<abxcde>");
+        assert_eq!(list.peek_back(), Some('>'));
+        assert_linked(&list);
+    }
+
+    #[test]
+    fn cursor_ghost_position() {
+        let mut list: DoublyLinkedList<char> = DoublyLinkedList::new();
+        let mut cursor: CursorMut<char> = list.cursor_front_mut();
+        assert!(cursor.current().is_none());
+        assert_eq!(cursor.remove_current(), None);
+        cursor.insert_after('b');
+        cursor.insert_after('a');
+        cursor.insert_before('c');
+        cursor.move_next();
+        assert_eq!(cursor.current().map(|ch| *ch), Some('a'));
+        cursor.move_prev();
+        assert!(cursor.current().is_none());
+        cursor.move_prev();
+        assert_eq!(cursor.current().map(|ch| *ch), Some('c'));
+        drop(cursor);
+        assert_eq!(text(&list), "abc");
+        assert_linked(&list);
+    }
+
+    #[test]
+    fn cursor_remove() {
+        let mut list: DoublyLinkedList<char> = from_chars("abcde");
+        let mut cursor: CursorMut<char> = list.cursor_front_mut();
+        cursor.move_next();
+        cursor.move_next();
+        assert_eq!(cursor.remove_current(), Some('c'));
+        assert_eq!(cursor.current().map(|ch| *ch), Some('d'));
+        cursor.move_prev();
+        assert_eq!(cursor.current().map(|ch| *ch), Some('b'));
+        drop(cursor);
+        assert_eq!(text(&list), "abde");
+        assert_linked(&list);
+
+        /* The front, then the back. */
+        let mut cursor: CursorMut<char> = list.cursor_front_mut();
+        assert_eq!(cursor.remove_current(), Some('a'));
+        drop(cursor);
+        let mut cursor: CursorMut<char> = list.cursor_back_mut();
+        assert_eq!(cursor.remove_current(), Some('e'));
+        assert!(cursor.current().is_none());
+        drop(cursor);
+        assert_eq!(text(&list), "bd");
+        assert_eq!(list.peek_back(), Some('d'));
+        assert_linked(&list);
+
+        let mut cursor: CursorMut<char> = list.cursor_front_mut();
+        while cursor.current().is_some() {
+            cursor.remove_current();
+        }
+        drop(cursor);
+        assert!(list.is_empty());
+        assert_eq!(list.peek_back(), None);
+    }
+
+    /** Each node has a single owner and a single Weak. */
+    #[test]
+    fn counts() {
+        let list: DoublyLinkedList<char> = from_chars("abcd");
+        assert_eq!(list.counts(), [(1, 1), (1, 1), (1, 1), (1, 1)]);
+        let single: DoublyLinkedList<char> = from_chars("a");
+        assert_eq!(single.counts(), [(1, 1)]);
+    }
+
+    #[test]
+    fn removed_node_is_freed() {
+        let mut list: DoublyLinkedList<char> = from_chars("abc");
+        let mut cursor: CursorMut<char> = list.cursor_front_mut();
+        cursor.move_next();
+        let removed: Weak<RefCell<Node<char>>> = match &cursor.m_current {
+            Some(node) => Rc::downgrade(node),
+            None => Weak::new(),
+        };
+        assert!(removed.upgrade().is_some());
+        assert_eq!(cursor.remove_current(), Some('b'));
+        assert!(removed.upgrade().is_none());
+        assert_eq!(removed.strong_count(), 0);
+        drop(cursor);
+        assert_linked(&list);
+    }
+
+    /** The elements are Rcs, their count tells if one leaked. */
+    #[test]
+    fn elements_are_dropped() {
+        let elem: Rc<String> = Rc::new("elem".to_string());
+        let mut list: DoublyLinkedList<Rc<String>> = DoublyLinkedList::new();
+        for _ in 0..4 {
+            list.push_back(elem.clone());
+        }
+        assert_eq!(Rc::strong_count(&elem), 5);
+        drop(list.pop_front());
+        drop(list.pop_back());
+        assert_eq!(Rc::strong_count(&elem), 3);
+        let mut cursor: CursorMut<Rc<String>> = list.cursor_front_mut();
+        drop(cursor.remove_current());
+        drop(cursor);
+        assert_eq!(Rc::strong_count(&elem), 2);
+        list.push_front(elem.clone());
+        drop(list);
+        assert_eq!(Rc::strong_count(&elem), 1);
+        assert_eq!(Rc::weak_count(&elem), 0);
+    }
+
+    /** The default (recursive) drop overflows the stack. */
+    #[test]
+    fn long_list_drop() {
+        let mut list: DoublyLinkedList<usize> = DoublyLinkedList::new();
+        (0..1_000_000).for_each(|i| list.push_back(i));
+        assert_eq!(list.len(), 1_000_000);
+        drop(list);
+    }
+
+    #[test]
+    fn matches_a_vec_deque() {
+        crate::core::fuzz::assert_agree(&DoublyLinkedListFuzz, 200);
+    }
+}
@@ -0,0 +1,557 @@
+use crate::pg_assert;
+use crate::pg_assert_eq;
+use crate::pg_println;
+use std::marker::PhantomData;
+use std::ptr;
+
+/**
+ * A singly linked queue: push at the back, pop at the front,
+ * both in O(1).
+ *
+ * With Boxes (see LinkedList), the back node is owned by its
+ * previous node, so a &mut to it cannot be kept in the list
+ * next to the head Box that (indirectly) owns it. Here the
+ * links are raw pointers (pointers.rs, pg_raw_pointers):
+ *
+ * m_head -> [1 | next] -> [2 | next] -> [3 | null] <- m_tail
+ *
+ * The raw pointers are dereferenced in unsafe blocks
+ * (unsafe_func.rs), and the public API is safe: each unsafe
+ * block relies on the invariants below, which every method
+ * keeps.
+ *
+ * - Every node is allocated by Box::new and turned into a
+ *   raw pointer by Box::into_raw, the list owns it till
+ *   Box::from_raw gives it back (pop_front or drop).
+ * - m_head and m_tail are both null (empty list), or both
+ *   point to live nodes, m_tail to the last one whose
+ *   m_next is null.
+ * - All the pointers to a node come from the same
+ *   Box::into_raw, and no &mut to a node is kept in the list
+ *   (only raw pointers). Mixing a &mut Node and a raw
+ *   pointer to the same node, then using the raw pointer,
+ *   is undefined behavior even when it "works": Miri's
+ *   borrow checks report it. The tests at the end of this
+ *   file go through this API only, so they run under Miri.
+ */
+pub struct Queue<T> {
+    m_head: Link<T>,
+    m_tail: Link<T>,
+    m_len: usize,
+    /**
+     * The queue owns values of type T (through raw pointers,
+     * which do not tell it to the compiler): needed for the
+     * drop check.
+     */
+    m_marker: PhantomData<T>,
+}
+
+type Link<T> = *mut Node<T>;
+
+struct Node<T> {
+    m_elem: T,
+    m_next: Link<T>,
+}
+
+impl<T> Queue<T> {
+    pub fn new() -> Queue<T> {
+        return Queue {
+            m_head: ptr::null_mut(),
+            m_tail: ptr::null_mut(),
+            m_len: 0,
+            m_marker: PhantomData,
+        };
+    }
+
+    pub fn push_back(&mut self, elem: T) {
+        let new_tail: Link<T> = Box::into_raw(Box::new(Node {
+            m_elem: elem,
+            m_next: ptr::null_mut(),
+        }));
+        if self.m_tail.is_null() {
+            self.m_head = new_tail;
+        } else {
+            /* m_tail points to a live node (see the invariants). */
+            unsafe {
+                (*self.m_tail).m_next = new_tail;
+            }
+        }
+        self.m_tail = new_tail;
+        self.m_len += 1;
+    }
+
+    pub fn pop_front(&mut self) -> Option<T> {
+        if self.m_head.is_null() {
+            return None;
+        }
+        /*
+         * The list gives up the head: from_raw makes it a Box
+         * again, which frees the node when dropped.
+         */
+        let head: Box<Node<T>> = unsafe { Box::from_raw(self.m_head) };
+        self.m_head = head.m_next;
+        if self.m_head.is_null() {
+            self.m_tail = ptr::null_mut();
+        }
+        self.m_len -= 1;
+        return Some(head.m_elem);
+    }
+
+    /**
+     * as_ref() and as_mut() of a raw pointer return None for
+     * null, else a reference whose lifetime is chosen by the
+     * caller: here the one of &self (or &mut self), so the
+     * borrow checker keeps the list borrowed while the
+     * reference is used.
+     */
+    pub fn peek_front(&self) -> Option<&T> {
+        return unsafe { self.m_head.as_ref() }.map(|node| &node.m_elem);
+    }
+
+    pub fn peek_front_mut(&mut self) -> Option<&mut T> {
+        return unsafe { self.m_head.as_mut() }.map(|node| &mut node.m_elem);
+    }
+
+    pub fn peek_back(&self) -> Option<&T> {
+        return unsafe { self.m_tail.as_ref() }.map(|node| &node.m_elem);
+    }
+
+    pub fn peek_back_mut(&mut self) -> Option<&mut T> {
+        return unsafe { self.m_tail.as_mut() }.map(|node| &mut node.m_elem);
+    }
+
+    pub fn len(&self) -> usize {
+        return self.m_len;
+    }
+
+    pub fn is_empty(&self) -> bool {
+        return self.m_head.is_null();
+    }
+
+    pub fn iter(&self) -> Iter<'_, T> {
+        return Iter {
+            m_next: unsafe { self.m_head.as_ref() },
+        };
+    }
+
+    pub fn iter_mut(&mut self) -> IterMut<'_, T> {
+        return IterMut {
+            m_next: unsafe { self.m_head.as_mut() },
+        };
+    }
+}
+
+impl<T> Default for Queue<T> {
+    fn default() -> Queue<T> {
+        return Queue::new();
+    }
+}
+
+/**
+ * The nodes are not Boxes, nothing would free them: they
+ * are given back to Boxes one by one by pop_front.
+ */
+impl<T> Drop for Queue<T> {
+    fn drop(&mut self) {
+        while self.pop_front().is_some() {}
+    }
+}
+
+/**
+ * Iterates by reference, from the front.
+ */
+pub struct Iter<'a, T> {
+    m_next: Option<&'a Node<T>>,
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<&'a T> {
+        return self.m_next.map(|node| {
+            self.m_next = unsafe { node.m_next.as_ref() };
+            return &node.m_elem;
+        });
+    }
+}
+
+/**
+ * Iterates by mutable reference, from the front. Each node
+ * is reached once, so the &mut returned never alias.
+ */
+pub struct IterMut<'a, T> {
+    m_next: Option<&'a mut Node<T>>,
+}
+
+impl<'a, T> Iterator for IterMut<'a, T> {
+    type Item = &'a mut T;
+
+    fn next(&mut self) -> Option<&'a mut T> {
+        return self.m_next.take().map(|node| {
+            self.m_next = unsafe { node.m_next.as_mut() };
+            return &mut node.m_elem;
+        });
+    }
+}
+
+/**
+ * Iterates by value, from the front.
+ */
+pub struct IntoIter<T>(Queue<T>);
+
+impl<T> Iterator for IntoIter<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        return self.0.pop_front();
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        return (self.0.len(), Some(self.0.len()));
+    }
+}
+
+impl<T> IntoIterator for Queue<T> {
+    type Item = T;
+    type IntoIter = IntoIter<T>;
+
+    fn into_iter(self) -> IntoIter<T> {
+        return IntoIter(self);
+    }
+}
+
+impl<'a, T> IntoIterator for &'a Queue<T> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Iter<'a, T> {
+        return self.iter();
+    }
+}
+
+impl<'a, T> IntoIterator for &'a mut Queue<T> {
+    type Item = &'a mut T;
+    type IntoIter = IterMut<'a, T>;
+
+    fn into_iter(self) -> IterMut<'a, T> {
+        return self.iter_mut();
+    }
+}
+
+impl<T> Extend<T> for Queue<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for elem in iter {
+            self.push_back(elem);
+        }
+    }
+}
+
+impl<T> FromIterator<T> for Queue<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Queue<T> {
+        let mut queue: Queue<T> = Queue::new();
+        queue.extend(iter);
+        return queue;
+    }
+}
+
+impl<T: std::fmt::Debug> std::fmt::Debug for Queue<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        return f.debug_list().entries(self.iter()).finish();
+    }
+}
+
+//_____________________________________________________________________________
+pub struct QueueExample;
+
+impl QueueExample {
+    pub fn run_example() {
+        QueueExample::push_pop_peek();
+        QueueExample::iterators();
+        QueueExample::no_leaks();
+    }
+
+    fn push_pop_peek() {
+        let mut queue: Queue<i32> = Queue::new();
+        pg_assert!(queue.is_empty());
+        pg_assert_eq!(queue.pop_front(), None);
+        pg_assert_eq!(queue.peek_back(), None);
+
+        queue.push_back(1);
+        queue.push_back(2);
+        queue.push_back(3);
+        pg_println!("after pushing 1, 2, 3 to the back: {queue:?}");
+        pg_assert_eq!(queue.len(), 3);
+        pg_assert_eq!(queue.peek_front(), Some(&1));
+        pg_assert_eq!(queue.peek_back(), Some(&3));
+
+        if let Some(front) = queue.peek_front_mut() {
+            *front = 10;
+        }
+        if let Some(back) = queue.peek_back_mut() {
+            *back *= 10;
+        }
+        pg_assert_eq!(queue.pop_front(), Some(10));
+        pg_assert_eq!(queue.pop_front(), Some(2));
+
+        /* Emptied then pushed again: m_tail was reset. */
+        pg_assert_eq!(queue.pop_front(), Some(30));
+        pg_assert!(queue.is_empty());
+        queue.push_back(4);
+        pg_assert_eq!(queue.peek_front(), Some(&4));
+        pg_assert_eq!(queue.peek_back(), Some(&4));
+        pg_assert_eq!(queue.pop_front(), Some(4));
+        pg_assert_eq!(queue.pop_front(), None);
+    }
+
+    fn iterators() {
+        let mut queue: Queue<String> =
+            ["x", "y", "z"].iter().map(|s| s.to_string()).collect();
+        for elem in &mut queue {
+            elem.push('!');
+        }
+        let borrowed: Vec<&String> = queue.iter().collect();
+        pg_assert_eq!(borrowed, ["x!", "y!", "z!"]);
+
+        /* A push_back after the iterators, which borrowed it. */
+        queue.extend(["w".to_string()]);
+        let owned: Vec<String> = queue.into_iter().collect();
+        pg_println!("owned elements: {owned:?}");
+        pg_assert_eq!(owned, ["x!", "y!", "z!", "w"]);
+    }
+
+    /**
+     * The elements are Rcs: their strong count tells how
+     * many are still in the queue, each element is dropped
+     * once when popped, dropped with the queue or when a
+     * partially consumed IntoIter is dropped.
+     */
+    fn no_leaks() {
+        let elem: std::rc::Rc<()> = std::rc::Rc::new(());
+        let mut queue: Queue<std::rc::Rc<()>> =
+            (0..5).map(|_| elem.clone()).collect();
+        pg_assert_eq!(std::rc::Rc::strong_count(&elem), 6);
+        drop(queue.pop_front());
+        pg_assert_eq!(std::rc::Rc::strong_count(&elem), 5);
+        drop(queue);
+        pg_assert_eq!(std::rc::Rc::strong_count(&elem), 1);
+
+        let queue: Queue<std::rc::Rc<()>> =
+            (0..5).map(|_| elem.clone()).collect();
+        let mut iter: IntoIter<std::rc::Rc<()>> = queue.into_iter();
+        drop(iter.next());
+        pg_assert_eq!(iter.size_hint(), (4, Some(4)));
+        drop(iter);
+        pg_assert_eq!(std::rc::Rc::strong_count(&elem), 1);
+        pg_println!("all the elements were dropped once");
+    }
+}
+
+impl crate::core::example::Example for QueueExample {
+    fn name(&self) -> &'static str {
+        return "queue";
+    }
+
+    fn category(&self) -> crate::core::example::Category {
+        return crate::core::example::Category::Examples;
+    }
+
+    fn description(&self) -> &'static str {
+        return "A linked queue with a raw tail pointer, a safe API over unsafe code.";
+    }
+
+    fn run(&self) -> Result<(), crate::core::return_code::RC> {
+        QueueExample::run_example();
+        return Ok(());
+    }
+}
+
+/**
+ * Compares Queue with a VecDeque on random sequences of
+ * operations, see core::fuzz. Each operation is an (op,
+ * value) pair, op selects push_back, pop_front, an update
+ * of the front or of the back through peek_front_mut and
+ * peek_back_mut, or an increment of all the elements with
+ * iter_mut, the output is what each operation observed and
+ * the final elements.
+ */
+pub struct QueueFuzz;
+
+impl crate::core::fuzz::Differential for QueueFuzz {
+    type Input = Vec<(u8, i32)>;
+    type Output = (Vec<Option<i32>>, Vec<i32>);
+
+    fn name(&self) -> &'static str {
+        return "queue";
+    }
+
+    fn reference(ops: Vec<(u8, i32)>) -> (Vec<Option<i32>>, Vec<i32>) {
+        let mut deque: std::collections::VecDeque<i32> =
+            std::collections::VecDeque::new();
+        let mut observed: Vec<Option<i32>> = Vec::new();
+        for (op, value) in ops {
+            match op % 5 {
+                0 | 1 => deque.push_back(value),
+                2 => observed.push(deque.pop_front()),
+                3 => {
+                    if let Some(front) = deque.front_mut() {
+                        *front = value;
+                    }
+                    observed.push(deque.back().copied());
+                }
+                _ => deque
+                    .iter_mut()
+                    .for_each(|elem| *elem = elem.wrapping_add(value)),
+            }
+            if let Some(back) = deque.back_mut() {
+                *back = back.wrapping_sub(1);
+            }
+            observed.push(Some(deque.len() as i32));
+        }
+        return (observed, deque.into_iter().collect());
+    }
+
+    fn candidate(ops: Vec<(u8, i32)>) -> (Vec<Option<i32>>, Vec<i32>) {
+        let mut queue: Queue<i32> = Queue::new();
+        let mut observed: Vec<Option<i32>> = Vec::new();
+        for (op, value) in ops {
+            match op % 5 {
+                0 | 1 => queue.push_back(value),
+                2 => observed.push(queue.pop_front()),
+                3 => {
+                    if let Some(front) = queue.peek_front_mut() {
+                        *front = value;
+                    }
+                    observed.push(queue.peek_back().copied());
+                }
+                _ => queue
+                    .iter_mut()
+                    .for_each(|elem| *elem = elem.wrapping_add(value)),
+            }
+            if let Some(back) = queue.peek_back_mut() {
+                *back = back.wrapping_sub(1);
+            }
+            observed.push(Some(queue.len() as i32));
+        }
+        return (observed, queue.into_iter().collect());
+    }
+}
+
+/*
+ * The tests only go through the safe API, print no address
+ * and use small sizes, so `cargo miri test` runs them: Miri
+ * checks each unsafe access against the invariants of Queue
+ * and reports the leaked nodes.
+ */
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::rc::Rc;
+
+    #[test]
+    fn push_back_pop_front() {
+        let mut queue: Queue<i32> = Queue::new();
+        assert!(queue.is_empty());
+        assert_eq!(queue.pop_front(), None);
+        queue.push_back(1);
+        queue.push_back(2);
+        queue.push_back(3);
+        assert_eq!(queue.len(), 3);
+        assert_eq!(queue.pop_front(), Some(1));
+        queue.push_back(4);
+        assert_eq!(queue.pop_front(), Some(2));
+        assert_eq!(queue.pop_front(), Some(3));
+        assert_eq!(queue.pop_front(), Some(4));
+        assert_eq!(queue.pop_front(), None);
+        assert!(queue.is_empty());
+    }
+
+    /** Emptying resets m_tail, the next push must not use it. */
+    #[test]
+    fn push_back_after_emptied() {
+        let mut queue: Queue<i32> = Queue::new();
+        queue.push_back(1);
+        assert_eq!(queue.pop_front(), Some(1));
+        assert_eq!(queue.peek_back(), None);
+        queue.push_back(2);
+        queue.push_back(3);
+        assert_eq!(queue.peek_front(), Some(&2));
+        assert_eq!(queue.peek_back(), Some(&3));
+        assert_eq!(queue.pop_front(), Some(2));
+        assert_eq!(queue.pop_front(), Some(3));
+    }
+
+    #[test]
+    fn peek_mut() {
+        let mut queue: Queue<i32> = Queue::new();
+        assert_eq!(queue.peek_front_mut(), None);
+        assert_eq!(queue.peek_back_mut(), None);
+        queue.push_back(1);
+        /* A single node is both the front and the back. */
+        if let Some(front) = queue.peek_front_mut() {
+            *front = 10;
+        }
+        assert_eq!(queue.peek_back(), Some(&10));
+        queue.push_back(2);
+        if let Some(back) = queue.peek_back_mut() {
+            *back *= 10;
+        }
+        /* A push after a peek_back_mut goes through m_tail. */
+        queue.push_back(3);
+        assert_eq!(queue.into_iter().collect::<Vec<i32>>(), [10, 20, 3]);
+    }
+
+    #[test]
+    fn iter() {
+        let queue: Queue<i32> = (1..=3).collect();
+        assert_eq!(queue.iter().collect::<Vec<&i32>>(), [&1, &2, &3]);
+        assert_eq!((&queue).into_iter().sum::<i32>(), 6);
+        assert_eq!(Queue::<i32>::new().iter().next(), None);
+    }
+
+    #[test]
+    fn iter_mut() {
+        let mut queue: Queue<i32> = (1..=3).collect();
+        for elem in queue.iter_mut() {
+            *elem *= 2;
+        }
+        for elem in &mut queue {
+            *elem += 1;
+        }
+        queue.push_back(0);
+        assert_eq!(queue.iter().copied().collect::<Vec<i32>>(), [3, 5, 7, 0]);
+    }
+
+    #[test]
+    fn into_iter() {
+        let mut queue: Queue<String> = Queue::new();
+        queue.extend(["a".to_string(), "b".to_string()]);
+        let mut iter: IntoIter<String> = queue.into_iter();
+        assert_eq!(iter.size_hint(), (2, Some(2)));
+        assert_eq!(iter.next().as_deref(), Some("a"));
+        assert_eq!(iter.next().as_deref(), Some("b"));
+        assert_eq!(iter.next(), None);
+    }
+
+    #[test]
+    fn drop_frees_the_elements() {
+        let elem: Rc<()> = Rc::new(());
+        let mut queue: Queue<Rc<()>> = (0..4).map(|_| elem.clone()).collect();
+        assert_eq!(Rc::strong_count(&elem), 5);
+        drop(queue.pop_front());
+        assert_eq!(Rc::strong_count(&elem), 4);
+        drop(queue);
+        assert_eq!(Rc::strong_count(&elem), 1);
+
+        /* A partially consumed IntoIter drops the rest. */
+        let queue: Queue<Rc<()>> = (0..4).map(|_| elem.clone()).collect();
+        let mut iter: IntoIter<Rc<()>> = queue.into_iter();
+        drop(iter.next());
+        drop(iter);
+        assert_eq!(Rc::strong_count(&elem), 1);
+    }
+
+    #[test]
+    fn matches_a_vec_deque() {
+        crate::core::fuzz::assert_agree(&QueueFuzz, 200);
+    }
+}
@@ -1,3 +1,5 @@
+use crate::pg_println;
+
 /*
  * How match works:
  *
@@ -8,7 +10,6 @@ pub struct MatchExample;
 impl MatchExample {
     pub fn run_example() {
         MatchExample::dereference_input();
-        crate::core::utils::Utils::print_line_separator();
     }
 }
 
@@ -54,11 +55,31 @@ impl MatchExample {
              * the value represented in the Option's Some
              * variant
              */
-            Option::Some(ref r_str) => println!("The string is {}", r_str),
-            Option::None => println!("There is no string"),
+            Option::Some(ref r_str) => pg_println!("The string is {}", r_str),
+            Option::None => pg_println!("There is no string"),
         }
     }
 }
 
 //read about match an ownership.
 //https://blog.rust-lang.org/2015/04/17/Enums-match-mutation-and-moves.html
+
+//_____________________________________________________________________________
+impl crate::core::example::Example for MatchExample {
+    fn name(&self) -> &'static str {
+        return "matches";
+    }
+
+    fn category(&self) -> crate::core::example::Category {
+        return crate::core::example::Category::Examples;
+    }
+
+    fn description(&self) -> &'static str {
+        return "Matching on a reference without moving the value.";
+    }
+
+    fn run(&self) -> Result<(), crate::core::return_code::RC> {
+        MatchExample::run_example();
+        return Ok(());
+    }
+}
@@ -19,3 +19,53 @@ pub mod turbo_fish;
 pub mod unsafe_func;
 pub mod vector;
 pub mod matches;
+
+/**
+ * @return the examples of this directory, in the order
+ * they are listed and run by the runner binary.
+ */
+pub fn examples() -> Vec<Box<dyn crate::core::example::Example>> {
+    return vec![
+        Box::new(first_steps::FirstSteps),
+        Box::new(ownership::PgOwnership),
+        Box::new(slices::PgSlices),
+        Box::new(pointers::PgPointers),
+        Box::new(unsafe_func::PgUnsafeFunctions),
+        Box::new(first_word_len::PgFirstWordLen),
+        Box::new(get_nth_word::PgGetNthWord),
+        Box::new(bubble_sort::BubbleSort),
+        Box::new(trait_impl::TraitImpl),
+        Box::new(vector::PgVector),
+        Box::new(turbo_fish::PgTurboFish),
+        Box::new(concurrency::ConcExample),
+        Box::new(deref::DerefExample),
+        Box::new(my_option::MyOptionExample),
+        Box::new(matches::MatchExample),
+        Box::new(core_cell::CoreCellExample),
+        Box::new(llist::LinkedListExample),
+        Box::new(llist::doubly::DoublyLinkedListExample),
+        Box::new(llist::queue::QueueExample),
+    ];
+}
+
+/**
+ * @return the examples whose running time is measured by
+ * rust_pg complexity (see core::complexity).
+ */
+pub fn complexity_targets(
+) -> Vec<Box<dyn crate::core::complexity::ComplexityTarget>> {
+    return vec![Box::new(bubble_sort::BubbleSort)];
+}
+
+/**
+ * @return the examples which are compared with a reference
+ * implementation on random inputs (see core::fuzz).
+ */
+pub fn fuzz_targets() -> Vec<Box<dyn crate::core::fuzz::FuzzTarget>> {
+    return vec![
+        Box::new(llist::LinkedListFuzz),
+        Box::new(llist::doubly::DoublyLinkedListFuzz),
+        Box::new(llist::queue::QueueFuzz),
+        Box::new(my_option::MyOptionFuzz),
+    ];
+}
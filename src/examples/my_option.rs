@@ -1,3 +1,7 @@
+use crate::pg_assert;
+use crate::pg_assert_eq;
+use crate::pg_println;
+
 pub enum MyOption<T> {
     None,
     Some(T),
@@ -77,9 +81,442 @@ impl<T> MyOption<T> {
      * Note: the std::mem::replace does memory swap in an
      * unsafe block.
      */
-    fn take(&mut self) -> MyOption<T> {
+    pub fn take(&mut self) -> MyOption<T> {
         return std::mem::replace(self, MyOption::None);
     }
+
+    pub fn is_some(&self) -> bool {
+        return matches!(self, MyOption::Some(_));
+    }
+
+    pub fn is_none(&self) -> bool {
+        return !self.is_some();
+    }
+
+    /**
+     * &MyOption<T> -> MyOption<&T>: the value is borrowed
+     * instead of moved, so the consuming methods (map,
+     * unwrap, ...) can be called without giving up the
+     * option.
+     */
+    pub fn as_ref(&self) -> MyOption<&T> {
+        match self {
+            MyOption::Some(val) => return MyOption::Some(val),
+            MyOption::None => return MyOption::None,
+        }
+    }
+
+    pub fn as_mut(&mut self) -> MyOption<&mut T> {
+        match self {
+            MyOption::Some(val) => return MyOption::Some(val),
+            MyOption::None => return MyOption::None,
+        }
+    }
+
+    /**
+     * FnOnce: the closure is called at most once, so it may
+     * move the values it captured.
+     */
+    pub fn map<U, F: FnOnce(T) -> U>(self, f: F) -> MyOption<U> {
+        match self {
+            MyOption::Some(val) => return MyOption::Some(f(val)),
+            MyOption::None => return MyOption::None,
+        }
+    }
+
+    /**
+     * Like map, but f may fail: its MyOption is returned
+     * as is instead of being wrapped in another one
+     * (flatMap in other languages).
+     */
+    pub fn and_then<U, F: FnOnce(T) -> MyOption<U>>(self, f: F) -> MyOption<U> {
+        match self {
+            MyOption::Some(val) => return f(val),
+            MyOption::None => return MyOption::None,
+        }
+    }
+
+    /**
+     * optb is evaluated even when self is Some, or_else
+     * evaluates it lazily.
+     */
+    pub fn or(self, optb: MyOption<T>) -> MyOption<T> {
+        match self {
+            MyOption::Some(val) => return MyOption::Some(val),
+            MyOption::None => return optb,
+        }
+    }
+
+    pub fn or_else<F: FnOnce() -> MyOption<T>>(self, f: F) -> MyOption<T> {
+        match self {
+            MyOption::Some(val) => return MyOption::Some(val),
+            MyOption::None => return f(),
+        }
+    }
+
+    /**
+     * #[track_caller]: the panic reports the location of the
+     * call to unwrap, not this line.
+     */
+    #[track_caller]
+    pub fn unwrap(self) -> T {
+        match self {
+            MyOption::Some(val) => return val,
+            MyOption::None => {
+                panic!("called `MyOption::unwrap()` on a `None` value")
+            }
+        }
+    }
+
+    #[track_caller]
+    pub fn expect(self, msg: &str) -> T {
+        match self {
+            MyOption::Some(val) => return val,
+            MyOption::None => panic!("{msg}"),
+        }
+    }
+
+    pub fn unwrap_or(self, default: T) -> T {
+        match self {
+            MyOption::Some(val) => return val,
+            MyOption::None => return default,
+        }
+    }
+
+    pub fn unwrap_or_else<F: FnOnce() -> T>(self, f: F) -> T {
+        match self {
+            MyOption::Some(val) => return val,
+            MyOption::None => return f(),
+        }
+    }
+
+    pub fn unwrap_or_default(self) -> T
+    where
+        T: Default,
+    {
+        return self.unwrap_or_else(T::default);
+    }
+
+    /**
+     * The predicate borrows the value, it decides whether
+     * the value is kept without consuming it.
+     */
+    pub fn filter<P: FnOnce(&T) -> bool>(self, predicate: P) -> MyOption<T> {
+        match self {
+            MyOption::Some(val) if predicate(&val) => {
+                return MyOption::Some(val)
+            }
+            _ => return MyOption::None,
+        }
+    }
+
+    pub fn zip<U>(self, other: MyOption<U>) -> MyOption<(T, U)> {
+        match (self, other) {
+            (MyOption::Some(a), MyOption::Some(b)) => {
+                return MyOption::Some((a, b))
+            }
+            _ => return MyOption::None,
+        }
+    }
+
+    /**
+     * Turns the absence of a value into an error, so the ?
+     * operator can be used on it.
+     */
+    pub fn ok_or<E>(self, err: E) -> Result<T, E> {
+        match self {
+            MyOption::Some(val) => return Ok(val),
+            MyOption::None => return Err(err),
+        }
+    }
+
+    /**
+     * Like take, but leaves Some(value) in place of None.
+     */
+    pub fn replace(&mut self, value: T) -> MyOption<T> {
+        return std::mem::replace(self, MyOption::Some(value));
+    }
+
+    /**
+     * @return a reference to the value, f computes it when
+     * self is None (the lazy initialization pattern).
+     */
+    pub fn get_or_insert_with<F: FnOnce() -> T>(&mut self, f: F) -> &mut T {
+        if self.is_none() {
+            *self = MyOption::Some(f());
+        }
+        match self {
+            MyOption::Some(val) => return val,
+            MyOption::None => unreachable!("a value was just inserted"),
+        }
+    }
+}
+
+impl<T> From<Option<T>> for MyOption<T> {
+    fn from(option: Option<T>) -> MyOption<T> {
+        match option {
+            Some(val) => return MyOption::Some(val),
+            None => return MyOption::None,
+        }
+    }
+}
+
+/**
+ * Implementing From also gives Into: my_option.into() is an
+ * Option<T>.
+ */
+impl<T> From<MyOption<T>> for Option<T> {
+    fn from(my_option: MyOption<T>) -> Option<T> {
+        match my_option {
+            MyOption::Some(val) => return Some(val),
+            MyOption::None => return None,
+        }
+    }
+}
+
+/**
+ * The std traits are implemented by hand (see trait_impl.rs),
+ * #[derive(...)] above the enum would generate the same
+ * code: variant by variant, then value by value.
+ *
+ * Two MyOptions are equal when both are None, or both are
+ * Some with equal values.
+ */
+impl<T: PartialEq> PartialEq for MyOption<T> {
+    fn eq(&self, other: &MyOption<T>) -> bool {
+        match (self, other) {
+            (MyOption::Some(a), MyOption::Some(b)) => return a == b,
+            (MyOption::None, MyOption::None) => return true,
+            _ => return false,
+        }
+    }
+}
+
+/**
+ * Eq has no method, it promises that eq is reflexive
+ * (a == a), which PartialEq does not (f64::NAN != f64::NAN).
+ * So it's only implemented when T keeps that promise.
+ */
+impl<T: Eq> Eq for MyOption<T> {}
+
+/**
+ * None is less than any Some, like the derived order (the
+ * variants are compared in their declaration order).
+ */
+impl<T: PartialOrd> PartialOrd for MyOption<T> {
+    fn partial_cmp(&self, other: &MyOption<T>) -> Option<std::cmp::Ordering> {
+        match (self, other) {
+            (MyOption::Some(a), MyOption::Some(b)) => return a.partial_cmp(b),
+            (MyOption::None, MyOption::None) => {
+                return Some(std::cmp::Ordering::Equal)
+            }
+            (MyOption::None, MyOption::Some(_)) => {
+                return Some(std::cmp::Ordering::Less)
+            }
+            (MyOption::Some(_), MyOption::None) => {
+                return Some(std::cmp::Ordering::Greater)
+            }
+        }
+    }
+}
+
+impl<T: Ord> Ord for MyOption<T> {
+    fn cmp(&self, other: &MyOption<T>) -> std::cmp::Ordering {
+        match (self, other) {
+            (MyOption::Some(a), MyOption::Some(b)) => return a.cmp(b),
+            (MyOption::None, MyOption::None) => {
+                return std::cmp::Ordering::Equal
+            }
+            (MyOption::None, MyOption::Some(_)) => {
+                return std::cmp::Ordering::Less
+            }
+            (MyOption::Some(_), MyOption::None) => {
+                return std::cmp::Ordering::Greater
+            }
+        }
+    }
+}
+
+/**
+ * Prints like Option: None, Some(2). debug_tuple also
+ * handles the {:#?} (pretty) format.
+ */
+impl<T: std::fmt::Debug> std::fmt::Debug for MyOption<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            MyOption::Some(val) => {
+                return f.debug_tuple("Some").field(val).finish()
+            }
+            MyOption::None => return f.write_str("None"),
+        }
+    }
+}
+
+impl<T: Clone> Clone for MyOption<T> {
+    fn clone(&self) -> MyOption<T> {
+        match self {
+            MyOption::Some(val) => return MyOption::Some(val.clone()),
+            MyOption::None => return MyOption::None,
+        }
+    }
+}
+
+/**
+ * Copy has no method either: it tells the compiler that a
+ * bitwise copy is a valid clone, so a MyOption<u64> is
+ * copied instead of moved. Copy requires Clone.
+ */
+impl<T: Copy> Copy for MyOption<T> {}
+
+/**
+ * The default is None, for any T (T itself needs no
+ * Default).
+ */
+impl<T> Default for MyOption<T> {
+    fn default() -> MyOption<T> {
+        return MyOption::None;
+    }
+}
+
+/**
+ * Equal MyOptions must have equal hashes: the variant is
+ * hashed first, so None and a Some never feed the same
+ * bytes to the hasher.
+ */
+impl<T: std::hash::Hash> std::hash::Hash for MyOption<T> {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        match self {
+            MyOption::Some(val) => {
+                state.write_u8(1);
+                val.hash(state);
+            }
+            MyOption::None => state.write_u8(0),
+        }
+    }
+}
+
+/**
+ * A MyOption is a collection of zero or one element, so it
+ * can be iterated: by value, by reference (for val in
+ * &my_option) and by mutable reference.
+ */
+pub struct IntoIter<T> {
+    m_inner: MyOption<T>,
+}
+
+impl<T> Iterator for IntoIter<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        return self.m_inner.take().into();
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len: usize = if self.m_inner.is_some() { 1 } else { 0 };
+        return (len, Some(len));
+    }
+}
+
+pub struct Iter<'a, T> {
+    m_inner: MyOption<&'a T>,
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<&'a T> {
+        return self.m_inner.take().into();
+    }
+}
+
+pub struct IterMut<'a, T> {
+    m_inner: MyOption<&'a mut T>,
+}
+
+impl<'a, T> Iterator for IterMut<'a, T> {
+    type Item = &'a mut T;
+
+    fn next(&mut self) -> Option<&'a mut T> {
+        return self.m_inner.take().into();
+    }
+}
+
+impl<T> IntoIterator for MyOption<T> {
+    type Item = T;
+    type IntoIter = IntoIter<T>;
+
+    fn into_iter(self) -> IntoIter<T> {
+        return IntoIter { m_inner: self };
+    }
+}
+
+impl<'a, T> IntoIterator for &'a MyOption<T> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Iter<'a, T> {
+        return Iter {
+            m_inner: self.as_ref(),
+        };
+    }
+}
+
+impl<'a, T> IntoIterator for &'a mut MyOption<T> {
+    type Item = &'a mut T;
+    type IntoIter = IterMut<'a, T>;
+
+    fn into_iter(self) -> IterMut<'a, T> {
+        return IterMut {
+            m_inner: self.as_mut(),
+        };
+    }
+}
+
+/**
+ * Collects an iterator of MyOption<A> into a MyOption<V>,
+ * e.g. MyOption<Vec<A>>: Some of all the values, or None
+ * as soon as an element is None (the rest of the iterator
+ * is not consumed).
+ */
+impl<A, V: FromIterator<A>> FromIterator<MyOption<A>> for MyOption<V> {
+    fn from_iter<I: IntoIterator<Item = MyOption<A>>>(iter: I) -> MyOption<V> {
+        let mut found_none: bool = false;
+        let collected: V = iter
+            .into_iter()
+            .map_while(|elem| match elem {
+                MyOption::Some(val) => return Some(val),
+                MyOption::None => {
+                    found_none = true;
+                    return None;
+                }
+            })
+            .collect();
+        if found_none {
+            return MyOption::None;
+        }
+        return MyOption::Some(collected);
+    }
+}
+
+/**
+ * The ? operator of MyOption: evaluates to the value of a
+ * Some, and returns None from the enclosing function for a
+ * None. (? itself needs the Try trait, which is unstable.)
+ *
+ * fn add(a: MyOption<u8>, b: MyOption<u8>) -> MyOption<u8> {
+ *     return MyOption::Some(try_my!(a) + try_my!(b));
+ * }
+ */
+#[macro_export]
+macro_rules! try_my {
+    ($expr:expr) => {
+        match $expr {
+            $crate::examples::my_option::MyOption::Some(val) => val,
+            $crate::examples::my_option::MyOption::None => {
+                return $crate::examples::my_option::MyOption::None;
+            }
+        }
+    };
 }
 
 /**
@@ -105,24 +542,142 @@ impl MyOptionExample {
     pub fn run_example() {
         //MyOptionExample::deref_my_option();
         MyOptionExample::take_example();
-        crate::core::utils::Utils::print_line_separator();
+        MyOptionExample::combinators();
+        MyOptionExample::std_traits();
+        MyOptionExample::iterate_and_collect();
+        MyOptionExample::try_my_example();
     }
 
     fn take_example() {
         let val: u64 = 2;
         let mut my_option: MyOption<u64> = MyOption::Some(val);
         let taking_my_option: MyOption<u64> = my_option.take();
-        match my_option {
-            MyOption::Some(_x) => println!("Bug in take() impl"),
-            MyOption::None => println!("Cool, take() works!"),
+        pg_assert_eq!(my_option, MyOption::None);
+        pg_assert_eq!(taking_my_option, MyOption::Some(val));
+        pg_println!("after take(): {my_option:?}, taken: {taking_my_option:?}");
+    }
+
+    /**
+     * Parses a port out of "host:port", each step may fail
+     * and the combinators chain them without a match.
+     */
+    fn combinators() {
+        let parse_port = |addr: &str| -> MyOption<u16> {
+            return MyOption::from(addr.split_once(':'))
+                .map(|(_, port)| port)
+                .and_then(|port| MyOption::from(port.parse::<u16>().ok()))
+                .filter(|port| *port != 0);
+        };
+        pg_assert!(parse_port("localhost:8080").unwrap() == 8080);
+        pg_assert!(parse_port("localhost").is_none());
+        pg_assert!(parse_port("localhost:0").is_none());
+        pg_assert!(parse_port("localhost:http").unwrap_or(80) == 80);
+        let port: u16 = parse_port("localhost")
+            .or_else(|| parse_port("localhost:443"))
+            .expect("a fallback port");
+        pg_println!("the fallback port is {port}");
+        pg_assert!(port == 443);
+
+        let mut cache: MyOption<String> = MyOption::None;
+        cache
+            .get_or_insert_with(|| "computed".to_string())
+            .push('!');
+        cache.get_or_insert_with(|| "not computed again".to_string());
+        pg_assert!(
+            cache.as_ref().map(|val| val.as_str()).unwrap() == "computed!"
+        );
+        let old: MyOption<String> = cache.replace("replaced".to_string());
+        pg_assert!(old.unwrap_or_default() == "computed!");
+
+        let pair: Option<(u16, String)> = parse_port("a:1").zip(cache).into();
+        pg_println!("zipped and converted to an Option: {pair:?}");
+        pg_assert!(pair == Some((1, "replaced".to_string())));
+        pg_assert!(MyOption::<u8>::None.ok_or("missing") == Err("missing"));
+    }
+
+    fn std_traits() {
+        let mut sorted: Vec<MyOption<i32>> =
+            vec![MyOption::Some(3), MyOption::None, MyOption::Some(-1)];
+        sorted.sort();
+        pg_println!("sorted: {sorted:?}");
+        pg_assert_eq!(
+            sorted,
+            [MyOption::None, MyOption::Some(-1), MyOption::Some(3)]
+        );
+        pg_assert!(MyOption::Some(f64::NAN)
+            .partial_cmp(&MyOption::Some(1.0))
+            .is_none());
+
+        /* Copy: my_option is still usable after the move. */
+        let my_option: MyOption<u64> = MyOption::Some(7);
+        let copied: MyOption<u64> = my_option;
+        pg_assert_eq!(my_option, copied);
+        let cloned: MyOption<String> = MyOption::Some("text".to_string());
+        pg_assert_eq!(cloned.clone(), cloned);
+        pg_assert_eq!(MyOption::<String>::default(), MyOption::None);
+        pg_assert_eq!(format!("{:?}", cloned), format!("{:?}", Some("text")));
+
+        let set: std::collections::HashSet<MyOption<char>> = "abca"
+            .chars()
+            .map(MyOption::Some)
+            .chain([MyOption::None])
+            .collect();
+        pg_assert_eq!(set.len(), 4);
+        pg_assert!(set.contains(&MyOption::None));
+        pg_assert!(set.contains(&MyOption::Some('c')));
+    }
+
+    fn iterate_and_collect() {
+        let mut my_option: MyOption<Vec<u8>> = MyOption::Some(vec![1]);
+        for vals in &mut my_option {
+            vals.push(2);
         }
-        match taking_my_option {
-            MyOption::Some(x) => {
-                assert!(x == val);
-                println!("The taken value is {}", x);
-            }
-            MyOption::None => println!("Bug in take() impl"),
+        let lens: usize = (&my_option).into_iter().map(|vals| vals.len()).sum();
+        pg_assert_eq!(lens, 2);
+        let flattened: Vec<u8> = my_option.into_iter().flatten().collect();
+        pg_assert_eq!(flattened, [1, 2]);
+
+        /* Only the Somes of an iterator, like Option's flatten. */
+        let somes: Vec<i32> =
+            [MyOption::Some(1), MyOption::None, MyOption::Some(3)]
+                .into_iter()
+                .flatten()
+                .collect();
+        pg_assert_eq!(somes, [1, 3]);
+
+        let parsed: MyOption<Vec<u32>> = "1 2 3"
+            .split(' ')
+            .map(|word| MyOption::from(word.parse::<u32>().ok()))
+            .collect();
+        pg_println!("\"1 2 3\" collected: {parsed:?}");
+        pg_assert_eq!(parsed, MyOption::Some(vec![1, 2, 3]));
+
+        let mut consumed: usize = 0;
+        let failed: MyOption<Vec<u32>> = "1 x 3"
+            .split(' ')
+            .inspect(|_| consumed += 1)
+            .map(|word| MyOption::from(word.parse::<u32>().ok()))
+            .collect();
+        pg_assert_eq!(failed, MyOption::None);
+        /* Stopped at the first None. */
+        pg_assert_eq!(consumed, 2);
+    }
+
+    fn try_my_example() {
+        fn middle_name_len(name: &str) -> MyOption<usize> {
+            let mut words: std::str::SplitWhitespace = name.split_whitespace();
+            try_my!(MyOption::from(words.next()));
+            let middle: &str = try_my!(MyOption::from(words.next()));
+            try_my!(MyOption::from(words.next()));
+            return MyOption::Some(middle.len());
         }
+        pg_assert_eq!(
+            middle_name_len("John Ronald Tolkien"),
+            MyOption::Some(6)
+        );
+        pg_assert_eq!(middle_name_len("John Tolkien"), MyOption::None);
+        pg_assert_eq!(middle_name_len(""), MyOption::None);
+        pg_println!("try_my! returned early on a missing word");
     }
 
     // fn deref_my_option() {
@@ -134,3 +689,350 @@ impl MyOptionExample {
     //     //     assert!(deref_maybe_val2 == 2);
     // }
 }
+
+//_____________________________________________________________________________
+impl crate::core::example::Example for MyOptionExample {
+    fn name(&self) -> &'static str {
+        return "my_option";
+    }
+
+    fn category(&self) -> crate::core::example::Category {
+        return crate::core::example::Category::Examples;
+    }
+
+    fn description(&self) -> &'static str {
+        return "A hand-rolled Option, its methods and std traits.";
+    }
+
+    fn run(&self) -> Result<(), crate::core::return_code::RC> {
+        MyOptionExample::run_example();
+        return Ok(());
+    }
+}
+
+/**
+ * Checks every method of MyOption against the same method
+ * of std::option::Option, on the same inputs, see
+ * core::fuzz. The input is (a, b, x): a is the option the
+ * methods are called on, b the other option of or, or_else
+ * and zip, x the value given to the methods and closures.
+ * The results are converted to std Options to be compared,
+ * eq and cmp compare a with b.
+ */
+pub struct MyOptionFuzz;
+
+/** The result of each method, see MyOptionFuzz. */
+#[derive(PartialEq, Debug)]
+pub struct MyOptionSummary {
+    pub is_some: bool,
+    pub is_none: bool,
+    pub as_ref: Option<i32>,
+    pub as_mut: Option<i32>,
+    pub map: Option<i64>,
+    pub and_then: Option<i32>,
+    pub or: Option<i32>,
+    pub or_else: Option<i32>,
+    /** unwrap and expect are only called on Some. */
+    pub unwrap: Option<i32>,
+    pub expect: Option<i32>,
+    pub unwrap_or: i32,
+    pub unwrap_or_else: i32,
+    pub unwrap_or_default: i32,
+    pub filter: Option<i32>,
+    pub zip: Option<(i32, i32)>,
+    pub ok_or: Result<i32, i32>,
+    /** (returned, left in place) */
+    pub take: (Option<i32>, Option<i32>),
+    pub replace: (Option<i32>, Option<i32>),
+    pub get_or_insert_with: (i32, Option<i32>),
+    /** The std traits. */
+    pub eq: bool,
+    pub cmp: std::cmp::Ordering,
+    pub debug: String,
+    pub into_iter: Vec<i32>,
+}
+
+impl crate::core::fuzz::Differential for MyOptionFuzz {
+    type Input = (Option<i32>, Option<i32>, i32);
+    type Output = MyOptionSummary;
+
+    fn name(&self) -> &'static str {
+        return "my_option";
+    }
+
+    #[allow(clippy::unnecessary_lazy_evaluations)]
+    fn reference(
+        (a, b, x): (Option<i32>, Option<i32>, i32),
+    ) -> MyOptionSummary {
+        let mut as_mut: Option<i32> = a;
+        if let Some(val) = as_mut.as_mut() {
+            *val = val.wrapping_add(x);
+        }
+        let mut take: Option<i32> = a;
+        let mut replace: Option<i32> = a;
+        let mut get_or_insert_with: Option<i32> = a;
+        let inserted: i32 = *get_or_insert_with.get_or_insert_with(|| x);
+        return MyOptionSummary {
+            is_some: a.is_some(),
+            is_none: a.is_none(),
+            as_ref: a.as_ref().copied(),
+            as_mut,
+            map: a.map(|val| val as i64 * 2),
+            and_then: a.and_then(|val| val.checked_add(x)),
+            or: a.or(b),
+            or_else: a.or_else(|| b),
+            unwrap: a.is_some().then(|| a.unwrap()),
+            expect: a.is_some().then(|| a.expect("checked")),
+            unwrap_or: a.unwrap_or(x),
+            unwrap_or_else: a.unwrap_or_else(|| x.wrapping_mul(3)),
+            unwrap_or_default: a.unwrap_or_default(),
+            filter: a.filter(|val| val % 2 == 0),
+            zip: a.zip(b),
+            ok_or: a.ok_or(x),
+            take: (take.take(), take),
+            replace: (replace.replace(x), replace),
+            get_or_insert_with: (inserted, get_or_insert_with),
+            eq: a == b,
+            cmp: a.cmp(&b),
+            debug: format!("{a:?}"),
+            into_iter: a.into_iter().collect(),
+        };
+    }
+
+    fn candidate(
+        (a, b, x): (Option<i32>, Option<i32>, i32),
+    ) -> MyOptionSummary {
+        /* MyOption<i32> is Copy, like Option<i32>. */
+        let my: MyOption<i32> = MyOption::from(a);
+        let other: MyOption<i32> = MyOption::from(b);
+        let mut as_mut: MyOption<i32> = my;
+        if let MyOption::Some(val) = as_mut.as_mut() {
+            *val = val.wrapping_add(x);
+        }
+        let mut take: MyOption<i32> = my;
+        let taken: MyOption<i32> = take.take();
+        let mut replace: MyOption<i32> = my;
+        let replaced: MyOption<i32> = replace.replace(x);
+        let mut get_or_insert_with: MyOption<i32> = my;
+        let inserted: i32 = *get_or_insert_with.get_or_insert_with(|| x);
+        return MyOptionSummary {
+            is_some: my.is_some(),
+            is_none: my.is_none(),
+            as_ref: my.as_ref().map(|val| *val).into(),
+            as_mut: as_mut.into(),
+            map: my.map(|val| val as i64 * 2).into(),
+            and_then: my
+                .and_then(|val| MyOption::from(val.checked_add(x)))
+                .into(),
+            or: my.or(other).into(),
+            or_else: my.or_else(|| other).into(),
+            unwrap: my.is_some().then(|| my.unwrap()),
+            expect: my.is_some().then(|| my.expect("checked")),
+            unwrap_or: my.unwrap_or(x),
+            unwrap_or_else: my.unwrap_or_else(|| x.wrapping_mul(3)),
+            unwrap_or_default: my.unwrap_or_default(),
+            filter: my.filter(|val| val % 2 == 0).into(),
+            zip: my.zip(other).into(),
+            ok_or: my.ok_or(x),
+            take: (taken.into(), take.into()),
+            replace: (replaced.into(), replace.into()),
+            get_or_insert_with: (inserted, get_or_insert_with.into()),
+            eq: my == other,
+            cmp: my.cmp(&other),
+            debug: format!("{my:?}"),
+            into_iter: my.into_iter().collect(),
+        };
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse_port(addr: &str) -> MyOption<u16> {
+        return MyOption::from(addr.split_once(':'))
+            .map(|(_, port)| port)
+            .and_then(|port| MyOption::from(port.parse::<u16>().ok()))
+            .filter(|port| *port != 0);
+    }
+
+    #[test]
+    fn take_and_replace() {
+        let mut my_option: MyOption<u64> = MyOption::Some(2);
+        let taken: MyOption<u64> = my_option.take();
+        assert!(my_option.is_none());
+        assert_eq!(Option::from(taken), Some(2));
+        assert!(my_option.take().is_none());
+        let old: MyOption<u64> = my_option.replace(3);
+        assert!(old.is_none());
+        assert_eq!(Option::from(my_option.replace(4)), Some(3));
+        assert_eq!(Option::from(my_option), Some(4));
+    }
+
+    #[test]
+    fn chained_combinators() {
+        assert_eq!(parse_port("localhost:8080").unwrap(), 8080);
+        assert!(parse_port("localhost").is_none());
+        assert!(parse_port("localhost:0").is_none());
+        assert_eq!(parse_port("localhost:http").unwrap_or(80), 80);
+        let port: u16 = parse_port("localhost")
+            .or_else(|| parse_port("localhost:443"))
+            .expect("a fallback port");
+        assert_eq!(port, 443);
+        assert_eq!(parse_port("a:1").or(parse_port("b:2")).unwrap(), 1);
+    }
+
+    #[test]
+    fn borrowing_combinators() {
+        let mut my_option: MyOption<String> = MyOption::Some("a".to_string());
+        assert_eq!(my_option.as_ref().map(|val| val.len()).unwrap(), 1);
+        if let MyOption::Some(val) = my_option.as_mut() {
+            val.push('b');
+        }
+        assert_eq!(my_option.unwrap(), "ab");
+    }
+
+    #[test]
+    fn lazy_initialization() {
+        let mut cache: MyOption<String> = MyOption::None;
+        cache
+            .get_or_insert_with(|| "computed".to_string())
+            .push('!');
+        cache.get_or_insert_with(|| unreachable!("computed twice"));
+        assert_eq!(cache.unwrap_or_default(), "computed!");
+        assert_eq!(MyOption::<String>::None.unwrap_or_default(), "");
+        assert_eq!(MyOption::None.unwrap_or_else(|| 5), 5);
+    }
+
+    #[test]
+    fn zip_and_ok_or() {
+        let pair: Option<(u16, char)> =
+            parse_port("a:1").zip(MyOption::Some('x')).into();
+        assert_eq!(pair, Some((1, 'x')));
+        assert!(parse_port("a").zip(MyOption::Some('x')).is_none());
+        assert_eq!(MyOption::<u8>::None.ok_or("missing"), Err("missing"));
+        assert_eq!(MyOption::Some(1).ok_or("missing"), Ok(1));
+    }
+
+    #[test]
+    #[should_panic(expected = "called `MyOption::unwrap()` on a `None` value")]
+    fn unwrap_none_panics() {
+        MyOption::<u8>::None.unwrap();
+    }
+
+    #[test]
+    #[should_panic(expected = "a custom message")]
+    fn expect_none_panics() {
+        MyOption::<u8>::None.expect("a custom message");
+    }
+
+    /** @return the hash of a value with the std hasher. */
+    fn hash<T: std::hash::Hash>(val: &T) -> u64 {
+        use std::hash::Hasher;
+        let mut hasher: std::collections::hash_map::DefaultHasher =
+            std::collections::hash_map::DefaultHasher::new();
+        val.hash(&mut hasher);
+        return hasher.finish();
+    }
+
+    #[test]
+    fn equality_and_order() {
+        assert_eq!(MyOption::Some(1), MyOption::Some(1));
+        assert_ne!(MyOption::Some(1), MyOption::Some(2));
+        assert_ne!(MyOption::Some(1), MyOption::None);
+        /* None is less than any Some, like Option. */
+        assert!(MyOption::None < MyOption::Some(i32::MIN));
+        assert!(MyOption::Some(-1) < MyOption::Some(3));
+        assert_eq!(
+            MyOption::Some(2).cmp(&MyOption::Some(2)),
+            std::cmp::Ordering::Equal
+        );
+        assert_eq!(
+            MyOption::Some(f64::NAN).partial_cmp(&MyOption::Some(1.0)),
+            None
+        );
+        assert_eq!(
+            MyOption::None.partial_cmp(&MyOption::Some(f64::NAN)),
+            Some(std::cmp::Ordering::Less)
+        );
+    }
+
+    #[test]
+    fn hash_matches_equality() {
+        assert_eq!(hash(&MyOption::Some('a')), hash(&MyOption::Some('a')));
+        assert_ne!(hash(&MyOption::Some('a')), hash(&MyOption::Some('b')));
+        assert_ne!(hash(&MyOption::Some(0u8)), hash(&MyOption::<u8>::None));
+    }
+
+    #[test]
+    fn debug_clone_copy_default() {
+        assert_eq!(format!("{:?}", MyOption::Some("text")), "Some(\"text\")");
+        assert_eq!(format!("{:?}", MyOption::<u8>::None), "None");
+        let cloned: MyOption<String> = MyOption::Some("text".to_string());
+        assert_eq!(cloned.clone(), cloned);
+        let my_option: MyOption<u64> = MyOption::Some(7);
+        let copied: MyOption<u64> = my_option;
+        assert_eq!(my_option, copied);
+        assert_eq!(MyOption::<String>::default(), MyOption::None);
+    }
+
+    #[test]
+    fn into_iterator() {
+        let mut my_option: MyOption<Vec<u8>> = MyOption::Some(vec![1]);
+        for vals in &mut my_option {
+            vals.push(2);
+        }
+        let borrowed: Vec<&Vec<u8>> = (&my_option).into_iter().collect();
+        assert_eq!(borrowed, [&vec![1, 2]]);
+        let owned: Vec<Vec<u8>> = my_option.into_iter().collect();
+        assert_eq!(owned, [vec![1, 2]]);
+
+        let mut none: MyOption<u8> = MyOption::None;
+        assert_eq!((&mut none).into_iter().next(), None);
+        assert_eq!((&none).into_iter().next(), None);
+        assert_eq!(none.into_iter().next(), None);
+    }
+
+    #[test]
+    fn from_iterator() {
+        let all: MyOption<Vec<u32>> =
+            [MyOption::Some(1), MyOption::Some(2)].into_iter().collect();
+        assert_eq!(all, MyOption::Some(vec![1, 2]));
+        let empty: MyOption<Vec<u32>> = std::iter::empty().collect();
+        assert_eq!(empty, MyOption::Some(vec![]));
+
+        let mut consumed: usize = 0;
+        let failed: MyOption<Vec<u32>> =
+            [MyOption::Some(1), MyOption::None, MyOption::Some(3)]
+                .into_iter()
+                .inspect(|_| consumed += 1)
+                .collect();
+        assert_eq!(failed, MyOption::None);
+        /* Stopped at the first None. */
+        assert_eq!(consumed, 2);
+    }
+
+    #[test]
+    fn try_my() {
+        fn add(a: MyOption<u8>, b: MyOption<u8>) -> MyOption<u8> {
+            return MyOption::Some(try_my!(a) + try_my!(b));
+        }
+        assert_eq!(
+            add(MyOption::Some(1), MyOption::Some(2)),
+            MyOption::Some(3)
+        );
+        assert_eq!(add(MyOption::None, MyOption::Some(2)), MyOption::None);
+        /* The rest of the function is skipped after a None. */
+        fn first_none() -> MyOption<u8> {
+            try_my!(MyOption::<u8>::None);
+            unreachable!("try_my! did not return");
+        }
+        assert_eq!(first_none(), MyOption::None);
+    }
+
+    /** Every method against std::option::Option, see MyOptionFuzz. */
+    #[test]
+    fn matches_std_option() {
+        crate::core::fuzz::assert_agree(&MyOptionFuzz, 2000);
+    }
+}
@@ -1,13 +1,15 @@
+use crate::core::reporter::Reporter;
+use crate::pg_println;
+
 pub fn pg_ownership() {
-    println!("OWNERSHIP");
+    pg_println!("OWNERSHIP");
     pg_assignment_ownership();
     pg_function_ownership();
-    crate::core::utils::Utils::print_line_separator();
 }
 
 //_____________________________________________________________________________
 fn pg_function_ownership() {
-    println!("pg_function_ownership()");
+    Reporter::new().subsection("pg_function_ownership()");
 
     //______________________________________________________
     //Basic ownership passing - immutable to immutable.
@@ -33,13 +35,13 @@ fn pg_function_ownership() {
     //Getting back the ownership.
     let mut imt_str2: String = String::from("31415926535");
     imt_str2 = pg_function_ownership_aux3(imt_str2);
-    println!("The string can be reused. str is: {imt_str2}");
+    pg_println!("The string can be reused. str is: {imt_str2}");
 
     //______________________________________________________
     //Borrow an immutable-variable ownership using a reference.
     let str_len: u64 = pg_function_ownership_get_str_len(&imt_str2);
     //can still use the string as it was borrowed by the method.
-    println!("The string is: {imt_str2} and its length is {str_len}");
+    pg_println!("The string is: {imt_str2} and its length is {str_len}");
 
     //______________________________________________________
     //Borrow a mutable reference.
@@ -50,25 +52,24 @@ fn pg_function_ownership() {
     let mut mt_str3: String = String::from("3.1415926535");
     let len_after_push: u64 =
         pg_function_ownership_push_and_get_len(&mut mt_str3);
-    println!("str after push is: {mt_str3}, its length is: {len_after_push}");
-    crate::core::utils::Utils::print_line_separator();
+    pg_println!("str after push is: {mt_str3}, its length is: {len_after_push}");
 }
 
 //The string ownership is moved to the method.
 //Note that the passed string cannot be mutate since the
 //parameter is not explicitly mutable.
 fn pg_function_ownership_aux1(a_string: String) {
-    println!("owned string is: {a_string}");
+    pg_println!("owned string is: {a_string}");
 }
 
 fn pg_function_ownership_aux2(mut a_string: String) {
     a_string.push_str("8979323");
-    println!("The string is: {a_string}");
+    pg_println!("The string is: {a_string}");
 }
 
 fn pg_function_ownership_aux3(a_string: String) -> String {
-    println!("input string is: {a_string}");
-    println!("return the string ownership to the caller");
+    pg_println!("input string is: {a_string}");
+    pg_println!("return the string ownership to the caller");
     return a_string;
 }
 
@@ -85,7 +86,7 @@ fn pg_function_ownership_push_and_get_len(
 
 //_____________________________________________________________________________
 fn pg_assignment_ownership() {
-    println!("pg_assignment_ownership()");
+    Reporter::new().subsection("pg_assignment_ownership()");
 
     //Each value in rust has an owner
     //(a heap-memory-location is a better term?).
@@ -108,7 +109,7 @@ fn pg_assignment_ownership() {
         let mut str2: String = str1;
         //from now on, str1 cannot be used and it's enforced
         //by the compiler.
-        println!("str2 is: {str2}"); //doing the same with
+        pg_println!("str2 is: {str2}"); //doing the same with
                                      //str1 would lead to
                                      //compilation error!.
         str2.push_str("8979323");
@@ -118,8 +119,29 @@ fn pg_assignment_ownership() {
         //To allow deep copy of a string, use clone.
         let immut_str1: String = String::from("3.14159265358979323");
         let immut_str2: String = immut_str1.clone();
-        println!("str1: {immut_str1}");
-        println!("str2: {immut_str2}");
+        pg_println!("str1: {immut_str1}");
+        pg_println!("str2: {immut_str2}");
+    }
+}
+
+//_____________________________________________________________________________
+pub struct PgOwnership;
+
+impl crate::core::example::Example for PgOwnership {
+    fn name(&self) -> &'static str {
+        return "ownership";
+    }
+
+    fn category(&self) -> crate::core::example::Category {
+        return crate::core::example::Category::Examples;
+    }
+
+    fn description(&self) -> &'static str {
+        return "Moves, clones and borrows through assignments and calls.";
+    }
+
+    fn run(&self) -> Result<(), crate::core::return_code::RC> {
+        pg_ownership();
+        return Ok(());
     }
-    crate::core::utils::Utils::print_line_separator();
 }
@@ -1,8 +1,10 @@
+use crate::pg_assert_eq;
+use crate::pg_println;
+
 pub fn pg_pointers() {
-    println!("POINTERS");
+    pg_println!("POINTERS");
     pg_box();
     pg_raw_pointers();
-    crate::core::utils::Utils::print_line_separator();
 }
 
 //_____________________________________________________________________________
@@ -13,27 +15,27 @@ fn pg_box() {
 }
 
 fn pg_box_basic() {
-    println!("box basics");
+    pg_println!("box basics");
     let u64_box: Box<u64> = Box::new(2);
     //note that the u64_box is not dereferenced like in c.
-    println!("value in u64 box is: {u64_box}");
+    pg_println!("value in u64 box is: {u64_box}");
     //When u64_box goes out of scope, drop() is called and
     //the memory is deallocated from the heap.
 }
 
 fn pg_box_deref() {
-    println!("box deref");
+    pg_println!("box deref");
     pg_box_deref_part1();
     pg_box_deref_part2();
 }
 
 fn pg_box_deref_part1() {
-    println!("box deref part1:");
+    pg_println!("box deref part1:");
     let x: u64 = 2;
     let box_y: Box<u64> = Box::new(2);
     //assert_eq!(x, box_y);//doesn't compile since u64 and
     //Box<u64> are not the same type.
-    assert_eq!(x, *box_y); //derefer the u64 in the Box<u64>
+    pg_assert_eq!(x, *box_y); //derefer the u64 in the Box<u64>
 }
 
 //______________________________________________________________________________
@@ -69,11 +71,11 @@ impl<T> Deref for MyBox<T> {
 }
 
 fn pg_box_deref_part2() {
-    println!("box_deref_part2");
+    pg_println!("box_deref_part2");
     let my_box: MyBox<u64> = MyBox::new(2);
     let x: u64 = 2;
-    assert_eq!(x, *my_box);
-    println!(
+    pg_assert_eq!(x, *my_box);
+    pg_println!(
         "x value is: {x}, my_box dereferenced value is: {}",
         *my_box
     );
@@ -95,7 +97,7 @@ impl Drop for CustomString {
      * only once.
      */
     fn drop(&mut self) {
-        println!(
+        pg_println!(
             "The dropped data from custom string is: {}",
             self.data
         );
@@ -111,7 +113,7 @@ fn pg_box_simple_drop() {
     let custom_string: CustomString = CustomString {
         data: String::from("3.1415"),
     };
-    println!("custom string data is: {}", custom_string.data);
+    pg_println!("custom string data is: {}", custom_string.data);
     //Now it's gonna drop.
 }
 
@@ -128,7 +130,7 @@ fn pg_box_early_drop() {
     // function is in the prelude, so it can be used as
     // follows:
     drop(custom_str);
-    println!("custom str was dropped before the scope ended");
+    pg_println!("custom str was dropped before the scope ended");
 }
 
 fn pg_raw_pointers() {
@@ -150,11 +152,11 @@ fn pg_raw_pointers() {
          * memory address (stack address) which is the
          * pointers' values.
          */
-        println!(
+        pg_println!(
             "imt_ptr value is {:p} and it points to {}",
             imt_ptr, *imt_ptr
         );
-        println!(
+        pg_println!(
             "mut_ptr value is {:p} and it points to {}",
             mut_ptr, *mut_ptr
         );
@@ -166,8 +168,35 @@ fn pg_raw_pointers() {
      */
     let mem_addr: u64 = 0x12345;
     let imt_mem_ptr: *const u64 = mem_addr as *const u64;
-    println!(
+    pg_println!(
         "imt_mem_ptr value is: {:p}, might not be an accessible address",
         imt_mem_ptr
     );
 }
+
+//_____________________________________________________________________________
+pub struct PgPointers;
+
+impl crate::core::example::Example for PgPointers {
+    fn name(&self) -> &'static str {
+        return "pointers";
+    }
+
+    fn category(&self) -> crate::core::example::Category {
+        return crate::core::example::Category::Examples;
+    }
+
+    fn description(&self) -> &'static str {
+        return "Box, Deref, Drop and raw pointers.";
+    }
+
+    fn run(&self) -> Result<(), crate::core::return_code::RC> {
+        pg_pointers();
+        return Ok(());
+    }
+
+    /** The raw pointers example prints stack addresses. */
+    fn is_deterministic(&self) -> bool {
+        return false;
+    }
+}
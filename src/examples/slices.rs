@@ -1,8 +1,10 @@
+use crate::pg_println;
+
 pub struct PgSlices;
 
 impl PgSlices {
     pub fn example() {
-        println!("SLICES");
+        pg_println!("SLICES");
         PgSlices::basics();
         PgSlices::range_syntax();
         PgSlices::slice_reference_ownership();
@@ -10,7 +12,6 @@ impl PgSlices {
         PgSlices::string_literals();
         PgSlices::string_slice_deref_coercion();
         PgSlices::arr_slices();
-        crate::core::utils::Utils::print_line_separator();
     }
 
     fn basics() {
@@ -26,7 +27,7 @@ impl PgSlices {
          * slices can co-exist and used together since they are
          * all immutable (read-only).
          */
-        println!(
+        pg_println!(
             "\"{pi_slice} {is_slice} {not_slice}\" is a \
          prefix of \"{example_str_full_slice}\""
         );
@@ -44,11 +45,11 @@ impl PgSlices {
         let first_word_slice2: &str = &example_str[..4];
         let last_word_slice1: &str = &example_str[8..12];
         let last_word_slice2: &str = &example_str[8..];
-        println!("{slice_it_all}");
-        println!("{first_word_slice1}");
-        println!("{first_word_slice2}");
-        println!("{last_word_slice1}");
-        println!("{last_word_slice2}");
+        pg_println!("{slice_it_all}");
+        pg_println!("{first_word_slice1}");
+        pg_println!("{first_word_slice2}");
+        pg_println!("{last_word_slice1}");
+        pg_println!("{last_word_slice2}");
     }
 
     fn slice_reference_ownership() {
@@ -98,8 +99,8 @@ impl PgSlices {
          * Ownership was not lost since the str_example was
          * passed by reference.
          */
-        println!("String is: {str_example}");
-        println!("First word is: {first_word}");
+        pg_println!("String is: {str_example}");
+        pg_println!("First word is: {first_word}");
     }
 
     /**
@@ -140,7 +141,7 @@ impl PgSlices {
          * so it's at most 16 bytes (as the maximal length
          * is at most 2^64 - 1, so 8 bytes are enough).
          */
-        println!(
+        pg_println!(
             "The string literal is: {str_literal} and size \
             of string slice is {} bytes",
             std::mem::size_of::<&str>()
@@ -170,7 +171,7 @@ impl PgSlices {
          * but just the ptr, let's validate it using the
          * size_of method.
          */
-        println!(
+        pg_println!(
             "String ref (&String) size in bytes is: {} \n\
             String slice (&str) size in bytes is {}",
             std::mem::size_of::<&String>(),
@@ -193,18 +194,38 @@ impl PgSlices {
     }
 
     fn print_string_reference(string_ref: &String) {
-        println!("The string is: {string_ref}");
+        pg_println!("The string is: {string_ref}");
     }
 
     fn print_string_slice(string_slice: &str) {
-        println!("The string is: {string_slice}");
+        pg_println!("The string is: {string_slice}");
     }
 
     fn arr_slices() {
         let arr: [u64; 5] = [2, 3, 5, 7, 11];
         let arr_slice: &[u64] = &arr[0..3];
         /* The array slice type is &[data_type] */
-        println!("The size of array slice is {}", std::mem::size_of::<&u64>());
-        println!("The array is {:?} and the slice is {:?}", arr, arr_slice);
+        pg_println!("The size of array slice is {}", std::mem::size_of::<&u64>());
+        pg_println!("The array is {:?} and the slice is {:?}", arr, arr_slice);
+    }
+}
+
+//_____________________________________________________________________________
+impl crate::core::example::Example for PgSlices {
+    fn name(&self) -> &'static str {
+        return "slices";
+    }
+
+    fn category(&self) -> crate::core::example::Category {
+        return crate::core::example::Category::Examples;
+    }
+
+    fn description(&self) -> &'static str {
+        return "String and array slices, range syntax and deref coercion.";
+    }
+
+    fn run(&self) -> Result<(), crate::core::return_code::RC> {
+        PgSlices::example();
+        return Ok(());
     }
 }
@@ -1,3 +1,5 @@
+use crate::pg_println;
+
 /**
  * Complex num struct.
  */
@@ -157,7 +159,6 @@ impl TraitImpl {
     pub fn run_example() {
         TraitImpl::partial_eq_and_display_example();
         TraitImpl::clone_example();
-        crate::core::utils::Utils::print_line_separator();
     }
 
     fn partial_eq_and_display_example() {
@@ -179,18 +180,18 @@ impl TraitImpl {
          * syntaxes.
          */
         if comp1 != comp2 {
-            println!("{:?} != {:?}", comp1, comp2);
+            pg_println!("{:?} != {:?}", comp1, comp2);
         }
         if comp1.ne(&comp2) {
-            println!("{comp1:?} ne {comp2:?}");
+            pg_println!("{comp1:?} ne {comp2:?}");
         }
 
         if comp1 == comp3 {
-            println!("{comp1:?} == {comp3:?}");
+            pg_println!("{comp1:?} == {comp3:?}");
         }
 
         if comp1.eq(&comp3) {
-            println!("{:?} eq {:?}", comp1, comp3)
+            pg_println!("{:?} eq {:?}", comp1, comp3)
         }
     }
 
@@ -205,7 +206,7 @@ impl TraitImpl {
             m_img: 0,
         };
         comp2.clone_from(&comp0);
-        println!("Cloned comps are the same! {comp0:?}, {comp1:?}, {comp2:?}");
+        pg_println!("Cloned comps are the same! {comp0:?}, {comp1:?}, {comp2:?}");
 
         /*
          * Note: clone() is needed in rust (unlike
@@ -221,3 +222,23 @@ impl TraitImpl {
         
     }
 }
+
+//_____________________________________________________________________________
+impl crate::core::example::Example for TraitImpl {
+    fn name(&self) -> &'static str {
+        return "trait_impl";
+    }
+
+    fn category(&self) -> crate::core::example::Category {
+        return crate::core::example::Category::Examples;
+    }
+
+    fn description(&self) -> &'static str {
+        return "Hand-written PartialEq, Debug and Clone for a complex number.";
+    }
+
+    fn run(&self) -> Result<(), crate::core::return_code::RC> {
+        TraitImpl::run_example();
+        return Ok(());
+    }
+}
@@ -1,3 +1,8 @@
+use crate::core::big_uint::BigUint;
+use crate::core::return_code::RC;
+use crate::pg_assert;
+use crate::pg_assert_eq;
+use crate::pg_println;
 use std::num::ParseIntError;
 
 pub struct PgTurboFish;
@@ -35,7 +40,8 @@ impl PgTurboFish {
          * of Result<T,Err>.
          *
          * Note - in case an error is returned and
-         * Result::unwrap() is used the system panics.
+         * Result::unwrap() is used the system panics, see
+         * PgTurboFish::unwrap_parse_error().
          */
         let _u64_pi: u64 = "31415826535".parse().unwrap();
 
@@ -44,8 +50,7 @@ impl PgTurboFish {
          * type, this way, if parse fails the code can
          * try and handle it.
          */
-        let _res_u64_pi: Result<u64, ParseIntError> =
-            "31415926535".parse();
+        let _res_u64_pi: Result<u64, ParseIntError> = "31415926535".parse();
         /*
          * The drawback* (see note below) of this way is
          * that one needs to be familiar with the parse
@@ -69,6 +74,57 @@ impl PgTurboFish {
          */
         let _turbofish_res_u64_pi = "31415926535".parse::<u64>();
 
-        crate::core::utils::Utils::print_line_separator();
+        /*
+         * parse works for any type implementing FromStr, so
+         * the turbofish selects the parsing as well. For
+         * example BigUint (see core::big_uint), whose
+         * values do not overflow: pi^2 * 10^20 does not fit
+         * in a u64.
+         */
+        let big_pi: Result<BigUint, RC> = "31415926535".parse::<BigUint>();
+        pg_assert!(big_pi.is_ok());
+        let big_pi: BigUint = big_pi.unwrap_or_default();
+        let big_pi_square: BigUint = &big_pi * &big_pi;
+        pg_println!("{big_pi}^2 = {big_pi_square}");
+        pg_assert_eq!(big_pi_square.to_string(), "986960440052517106225");
+        pg_assert!(big_pi_square > BigUint::from(u64::MAX));
+    }
+
+    /**
+     * A negative number is not a valid u64, parse returns
+     * an error and unwrap panics.
+     */
+    fn unwrap_parse_error() {
+        let _u64_minus_pi: u64 = "-31415926535".parse().unwrap();
+    }
+}
+
+//_____________________________________________________________________________
+impl crate::core::example::Example for PgTurboFish {
+    fn name(&self) -> &'static str {
+        return "turbo_fish";
+    }
+
+    fn category(&self) -> crate::core::example::Category {
+        return crate::core::example::Category::Examples;
+    }
+
+    fn description(&self) -> &'static str {
+        return "Helping type inference with the turbofish syntax.";
+    }
+
+    fn run(&self) -> Result<(), crate::core::return_code::RC> {
+        PgTurboFish::run_example();
+        return Ok(());
+    }
+
+    fn panic_scenarios(
+        &self,
+    ) -> Vec<crate::core::panic_scenario::PanicScenario> {
+        return vec![crate::core::panic_scenario::PanicScenario {
+            name: "unwrap_parse_error",
+            expected: "called `Result::unwrap()` on an `Err` value",
+            scenario: PgTurboFish::unwrap_parse_error,
+        }];
     }
 }
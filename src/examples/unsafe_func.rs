@@ -1,13 +1,14 @@
+use crate::pg_println;
+
 pub fn pg_unsafe_functions() {
-    println!("UNSAFE FUNCTIONS");
+    pg_println!("UNSAFE FUNCTIONS");
     pg_unsafe_func_basic_syntax();
-    crate::core::utils::Utils::print_line_separator();
 }
 
 //______________________________________________________________________________
 /* Use the unsafe keyword to declare a unsage function. */
 unsafe fn dangerous() {
-    println!("Unsafe function!!!!");
+    pg_println!("Unsafe function!!!!");
 }
 
 fn pg_unsafe_func_basic_syntax() {
@@ -16,3 +17,25 @@ fn pg_unsafe_func_basic_syntax() {
         dangerous();
     }
 }
+
+//_____________________________________________________________________________
+pub struct PgUnsafeFunctions;
+
+impl crate::core::example::Example for PgUnsafeFunctions {
+    fn name(&self) -> &'static str {
+        return "unsafe_func";
+    }
+
+    fn category(&self) -> crate::core::example::Category {
+        return crate::core::example::Category::Examples;
+    }
+
+    fn description(&self) -> &'static str {
+        return "Declaring and calling an unsafe function.";
+    }
+
+    fn run(&self) -> Result<(), crate::core::return_code::RC> {
+        pg_unsafe_functions();
+        return Ok(());
+    }
+}
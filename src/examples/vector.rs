@@ -1,3 +1,7 @@
+use crate::core::reporter::Reporter;
+use crate::pg_assert_eq;
+use crate::pg_println;
+
 pub struct PgVector;
 
 impl PgVector {
@@ -12,8 +16,6 @@ impl PgVector {
         PgVector::vec_basic_methods();
         PgVector::vec_macro();
         PgVector::vec_slices();
-
-        crate::core::utils::Utils::print_line_separator();
     }
 
     fn structure() {
@@ -81,9 +83,11 @@ impl PgVector {
         vec.push(2);
         vec.push(3);
         vec.push(5);
+        let mut rows: Vec<Vec<String>> = Vec::new();
         for (idx, item) in vec.iter().enumerate() {
-            println!("vector[{}] = {}", idx, item);
+            rows.push(vec![idx.to_string(), item.to_string()]);
         }
+        Reporter::new().table(&["idx", "vector[idx]"], &rows);
         /* Note: drop is called when the scope ends */
 
         /*
@@ -105,18 +109,16 @@ impl PgVector {
         let mut vec: Vec<u64> = Vec::from([2, 3, 5, 7]);
 
         /* Access and assign element using [] */
-        println!("vec[2] = {}", vec[2]);
-        assert_eq!(vec[2], 5);
+        pg_println!("vec[2] = {}", vec[2]);
+        pg_assert_eq!(vec[2], 5);
         vec[0] = 0;
-        assert_eq!(vec[0], 0);
+        pg_assert_eq!(vec[0], 0);
         vec[0] = 2;
-        assert_eq!(vec[0], 2);
+        pg_assert_eq!(vec[0], 2);
         /*
          * Note: accessing outside the vec boundaries panics
-         * the program.
-         * Don't do that at home:
+         * the program, see PgVector::index_out_of_bounds().
          */
-        //vec[17] = 0; <= result in program panic.
 
         /* Regular iterator */
         for r_elem in &vec {
@@ -129,24 +131,26 @@ impl PgVector {
              * demand at least 8 bytes. But for bigger
              * structs, it's important to use reference.
              */
-            println!("{r_elem}");
+            pg_println!("{r_elem}");
         }
         /* vec.len() -> usize */
         let vec_len: usize = vec.len();
-        println!("Vec length is: {vec_len}");
+        pg_println!("Vec length is: {vec_len}");
 
         /* vec.pop() */
         let last_vec_elem: Option<u64> = vec.pop();
         match last_vec_elem {
-            Some(elem) => println!("Popped {elem} from vec"),
-            None => println!("Tried to pop an empty vector"),
+            Some(elem) => pg_println!("Popped {elem} from vec"),
+            None => pg_println!("Tried to pop an empty vector"),
         }
 
         /* vec.extend */
         vec.extend([7, 11, 13, 17]);
+        let mut rows: Vec<Vec<String>> = Vec::new();
         for (idx, &elem) in vec.iter().enumerate() {
-            println!("vec[{}] = {}", idx, elem);
+            rows.push(vec![idx.to_string(), elem.to_string()]);
         }
+        Reporter::new().table(&["idx", "vec[idx]"], &rows);
     }
 
     fn vec_macro() {
@@ -162,13 +166,13 @@ impl PgVector {
          * are all 0s.
          */
         let macro_vec: Vec<u64> = vec![0; 3];
-        assert_eq!(macro_vec, [0, 0, 0]);
+        pg_assert_eq!(macro_vec, [0, 0, 0]);
         /*
          * An equivalent but potentially slower syntax is:
          */
         let mut vec: Vec<u64> = Vec::with_capacity(3);
         vec.resize(3, 0);
-        assert_eq!(vec, macro_vec);
+        pg_assert_eq!(vec, macro_vec);
     }
 
     fn vec_slices() {
@@ -187,8 +191,51 @@ impl PgVector {
     }
 
     fn read_u64_vec(vec_slice: &[u64]) {
+        let mut rows: Vec<Vec<String>> = Vec::new();
         for (idx, &ref_elem) in vec_slice.iter().enumerate() {
-            println!("vec[{}] = {}", idx, ref_elem);
+            rows.push(vec![idx.to_string(), ref_elem.to_string()]);
         }
+        Reporter::new().table(&["idx", "vec[idx]"], &rows);
+    }
+
+    /**
+     * Accessing outside the vec boundaries panics the
+     * program. Don't do that at home.
+     */
+    fn index_out_of_bounds() {
+        let mut vec: Vec<u64> = vec![2, 3, 5];
+        /* The index is not a literal, so it compiles. */
+        let idx: usize = vec.len() + 14;
+        vec[idx] = 0; /* <= result in program panic. */
+    }
+}
+
+//_____________________________________________________________________________
+impl crate::core::example::Example for PgVector {
+    fn name(&self) -> &'static str {
+        return "vector";
+    }
+
+    fn category(&self) -> crate::core::example::Category {
+        return crate::core::example::Category::Examples;
+    }
+
+    fn description(&self) -> &'static str {
+        return "Vec construction, access, push/pop, vec! and slices.";
+    }
+
+    fn run(&self) -> Result<(), crate::core::return_code::RC> {
+        PgVector::run_example();
+        return Ok(());
+    }
+
+    fn panic_scenarios(
+        &self,
+    ) -> Vec<crate::core::panic_scenario::PanicScenario> {
+        return vec![crate::core::panic_scenario::PanicScenario {
+            name: "index_out_of_bounds",
+            expected: "index out of bounds",
+            scenario: PgVector::index_out_of_bounds,
+        }];
     }
 }
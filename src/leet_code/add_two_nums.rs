@@ -2,121 +2,450 @@
  * https://leetcode.com/problems/add-two-numbers/
  */
 
-// /*Definition for singly-linked list.*/
-
-// #[derive(PartialEq, Eq, Clone, Debug)]
-// /*
-// * Note: I would recommend to define getters and setters
-// * and define the members as private, but that's the leet
-// * code definition.
-// */
-// pub struct ListNode {
-//     /**means it can be none or a pointer to ListNode */
-//     pub next: Option<Box<ListNode>>,
-//     pub val: i32,
-// }
-
-// impl ListNode {
-//     #[inline]
-//     fn new(val: i32) -> Self {
-//         ListNode { next: None, val }
-//     }
-// }
-
-// /**
-//  * The list head.
-//  */
-// pub struct ListHead<'a> {
-//     pub m_next: &'a Option<Box<ListNode>>,
-// }
-
-// /**
-//  * Reminder:
-//  * Option<T>: optional value, either "Some" T or "None".
-//  * Box<T>: T is allocated on the heap by value/ Box is a
-//  *         smart pointer, when a box goes out of scope its
-//  *         destructor is called and the inner object is
-//  *         destroyed.
-//  */
-
-// /**
-//  * For tomorrow - create a linked list and iterate a linked
-//  * list (with optional).
-//  */
-
-// struct Solution;
-
-// impl Solution {
-//     pub fn add_two_numbers(
-//         l1: Option<Box<ListNode>>,
-//         l2: Option<Box<ListNode>>,
-//     ) -> Option<Box<ListNode>> {
-//         /* What's the idiomatic way of checking for Nones? */
-//         if l1 == None && l2 == None {
-//             return None;
-//         }
-//         let res: Option<Box<ListNode>> = Some(Box::new(ListNode::new(2)));
-//         return res;
-
-//         // if l1 == None && l2 == None {
-//         //     return None;
-//         // }
-
-//         // let mut current_node_1 = l1;
-//         // let mut current_node_2 = l2;
-
-//         // let mut current_sum: i32 = 0;
-//         // let mut current_val: i32 = 0;
-//         // let mut current_carry: i32 = 0;
-
-//         // let mut res: Option<Box<ListNode>> =
-//         //     Some(Box::new(ListNode::new(0)));
-
-//         // let mut next_res_node = res.unwrap().next;
-
-//         // while current_node_1 != None && current_node_2 != None {
-//         //     /*
-//         //      * Use unwrap as it checked the nodes are not null.
-//         //      */
-//         //     current_sum = current_carry
-//         //         + current_node_1.unwrap().val
-//         //         + current_node_2.unwrap().val;
-
-//         //     current_val = current_sum % 10;
-//         //     current_carry = (current_sum - current_val) / 10;
-
-//         //     /* Update the */
-//         //     current_res_node.unwrap().val = current_val;
-//         // }
-
-//         // return res;
-//         // // while current_list_node_1 != None && current_list_node_2 != None {
-//         // //     let mut sum: i32 = current_carry
-//         // //         + current_list_node_1.val
-//         // //         + current_list_node_2.val;
-//         // //     current_val = sum % 10;
-//         // //     current_carry = (sum - current_val) / 10;
-
-//         // //     /* Update res */
-//         // //     res.val = current_val;
-//         // //     /* Create a new node. */
-
-//         // //     /* Make a new node and move res to point it. */
-
-//         // //     /* Move current_list_node1 and current_list node2 */
-//         // // }
-//     }
-// }
-
-// pub struct TestSolution;
-
-// impl TestSolution {
-//     pub fn test_solution() {
-//         //Solution::create_and_iterate_list();
-//         // let mut num1: Option<Box<ListNode>> =
-//         //     Some(Box::new(ListNode::new(2)));
-//         // let mut num2: Option<Box<ListNode>> =
-//         //     Some(Box::new(ListNode::new(3)));
-//         // let sol = Solution::add_two_numbers(num1, num2);
-//     }
-// }
+use crate::leet_code::literal::ToLiteral;
+use crate::pg_assert_eq;
+use crate::pg_println;
+
+/*Definition for singly-linked list.*/
+#[derive(PartialEq, Eq, Clone, Debug)]
+/*
+* Note: I would recommend to define getters and setters
+* and define the members as private, but that's the leet
+* code definition.
+*/
+pub struct ListNode {
+    /**means it can be none or a pointer to ListNode */
+    pub next: Option<Box<ListNode>>,
+    pub val: i32,
+}
+
+impl ListNode {
+    #[inline]
+    pub fn new(val: i32) -> Self {
+        ListNode { next: None, val }
+    }
+
+    /**
+     * @return a list of the values, in the slice order,
+     * None for an empty slice.
+     */
+    pub fn from_slice(vals: &[i32]) -> Option<Box<ListNode>> {
+        let mut head: Option<Box<ListNode>> = None;
+        /*
+         * Built from the tail, each new node points to the
+         * list built so far.
+         */
+        for &val in vals.iter().rev() {
+            let mut node: Box<ListNode> = Box::new(ListNode::new(val));
+            node.next = head;
+            head = Some(node);
+        }
+        return head;
+    }
+
+    /**
+     * @return the digits of num in reverse order (the way
+     * the problem stores numbers), e.g. 342 => 2 -> 4 -> 3.
+     */
+    pub fn from_number(mut num: u64) -> Option<Box<ListNode>> {
+        let mut digits: Vec<i32> = Vec::new();
+        loop {
+            digits.push((num % 10) as i32);
+            num /= 10;
+            if num == 0 {
+                break;
+            }
+        }
+        return ListNode::from_slice(&digits);
+    }
+
+    pub fn to_vec(list: &Option<Box<ListNode>>) -> Vec<i32> {
+        return ListHead::new(list).copied().collect();
+    }
+
+    /**
+     * The reverse of from_number().
+     * @return None when the list has a value which is not a
+     * digit or the number does not fit in a u64. The empty
+     * list is 0.
+     */
+    pub fn to_number(list: &Option<Box<ListNode>>) -> Option<u64> {
+        let mut num: u64 = 0;
+        let mut weight: Option<u64> = Some(1);
+        for &digit in ListHead::new(list) {
+            if !(0..=9).contains(&digit) {
+                return None;
+            }
+            if digit != 0 {
+                num = num.checked_add(weight?.checked_mul(digit as u64)?)?;
+            }
+            weight = weight.and_then(|weight| weight.checked_mul(10));
+        }
+        return Some(num);
+    }
+}
+
+/**
+ * The list head.
+ *
+ * It borrows a list, so a list can be iterated and
+ * displayed without moving it:
+ * ListHead::new(&list).to_string() == "2 -> 4 -> 3"
+ */
+pub struct ListHead<'a> {
+    pub m_next: &'a Option<Box<ListNode>>,
+}
+
+impl<'a> ListHead<'a> {
+    pub fn new(list: &'a Option<Box<ListNode>>) -> ListHead<'a> {
+        return ListHead { m_next: list };
+    }
+}
+
+/**
+ * Each call to next() moves the head to the next node, the
+ * values are borrowed from the list.
+ */
+impl<'a> Iterator for ListHead<'a> {
+    type Item = &'a i32;
+
+    fn next(&mut self) -> Option<&'a i32> {
+        match *self.m_next {
+            Some(ref node) => {
+                self.m_next = &node.next;
+                return Some(&node.val);
+            }
+            None => return None,
+        }
+    }
+}
+
+/**
+ * "2 -> 4 -> 3", or "(empty)" for the empty list.
+ */
+impl std::fmt::Display for ListHead<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        if self.m_next.is_none() {
+            return write!(f, "(empty)");
+        }
+        let vals: Vec<String> = ListHead::new(self.m_next)
+            .map(|val| val.to_string())
+            .collect();
+        return write!(f, "{}", vals.join(" -> "));
+    }
+}
+
+/**
+ * Reminder:
+ * Option<T>: optional value, either "Some" T or "None".
+ * Box<T>: T is allocated on the heap by value/ Box is a
+ *         smart pointer, when a box goes out of scope its
+ *         destructor is called and the inner object is
+ *         destroyed.
+ */
+pub struct Solution;
+
+impl Solution {
+    /**
+     * Adds two numbers stored as lists of digits in reverse
+     * order, e.g. 2 -> 4 -> 3 + 5 -> 6 -> 4 = 7 -> 0 -> 8
+     * (342 + 465 = 807).
+     *
+     * The lists may have different lengths, and the empty
+     * list is 0 (leet code's lists are never empty).
+     */
+    pub fn add_two_numbers(
+        l1: Option<Box<ListNode>>,
+        l2: Option<Box<ListNode>>,
+    ) -> Option<Box<ListNode>> {
+        /*
+         * What's the idiomatic way of checking for Nones?
+         * Matching (or "while let") on the options, the
+         * node is moved out of the option in the same step,
+         * so there is no unwrap() which might panic.
+         */
+        let mut current_node_1: Option<Box<ListNode>> = l1;
+        let mut current_node_2: Option<Box<ListNode>> = l2;
+        let mut current_carry: i32 = 0;
+
+        let mut res: Option<Box<ListNode>> = None;
+        /*
+         * The tail is where the next node is stored, it
+         * starts at the head (res) and moves to the next
+         * field of each new node.
+         */
+        let mut tail: &mut Option<Box<ListNode>> = &mut res;
+        while current_node_1.is_some()
+            || current_node_2.is_some()
+            || current_carry != 0
+        {
+            let mut current_sum: i32 = current_carry;
+            if let Some(node) = current_node_1 {
+                current_sum += node.val;
+                current_node_1 = node.next;
+            }
+            if let Some(node) = current_node_2 {
+                current_sum += node.val;
+                current_node_2 = node.next;
+            }
+            let current_val: i32 = current_sum % 10;
+            current_carry = current_sum / 10;
+
+            let new_node: &mut Box<ListNode> =
+                tail.insert(Box::new(ListNode::new(current_val)));
+            tail = &mut new_node.next;
+        }
+        return res;
+    }
+}
+
+pub struct TestSolution;
+
+impl TestSolution {
+    pub fn test_solution() {
+        /* The example of the problem statement. */
+        TestSolution::check(&[2, 4, 3], &[5, 6, 4], &[7, 0, 8]);
+        /* Carries, including a carry creating a new digit. */
+        TestSolution::check(&[5], &[5], &[0, 1]);
+        TestSolution::check(
+            &[9, 9, 9, 9, 9, 9, 9],
+            &[9, 9, 9, 9],
+            &[8, 9, 9, 9, 0, 0, 0, 1],
+        );
+        /* Unequal lengths, in both orders. */
+        TestSolution::check(&[1, 8], &[0], &[1, 8]);
+        TestSolution::check(&[0], &[1, 8], &[1, 8]);
+        /* Empty lists. */
+        TestSolution::check(&[], &[7, 3], &[7, 3]);
+        TestSolution::check(&[], &[], &[]);
+
+        /* The helpers. */
+        let list: Option<Box<ListNode>> = ListNode::from_number(342);
+        pg_assert_eq!(ListNode::to_vec(&list), vec![2, 4, 3]);
+        pg_assert_eq!(ListNode::to_number(&list), Some(342));
+        pg_assert_eq!(ListNode::to_number(&None), Some(0));
+        pg_assert_eq!(
+            ListNode::to_number(&ListNode::from_number(u64::MAX)),
+            Some(u64::MAX)
+        );
+        pg_assert_eq!(
+            ListNode::to_number(&ListNode::from_slice(&[1; 21])),
+            None
+        );
+        pg_assert_eq!(ListHead::new(&list).to_string(), "2 -> 4 -> 3");
+        pg_assert_eq!(ListHead::new(&None).to_string(), "(empty)");
+
+        let sum: Option<Box<ListNode>> = Solution::add_two_numbers(
+            ListNode::from_number(342),
+            ListNode::from_number(465),
+        );
+        pg_println!(
+            "342 + 465 = {}, as lists: {} + {} = {}",
+            ListNode::to_number(&sum).unwrap_or_default(),
+            ListHead::new(&ListNode::from_number(342)),
+            ListHead::new(&ListNode::from_number(465)),
+            ListHead::new(&sum)
+        );
+    }
+
+    /**
+     * Adds l1 and l2 and asserts the sum is expected, the
+     * sum is also checked against the u64 addition.
+     */
+    fn check(l1: &[i32], l2: &[i32], expected: &[i32]) {
+        let num1: Option<Box<ListNode>> = ListNode::from_slice(l1);
+        let num2: Option<Box<ListNode>> = ListNode::from_slice(l2);
+        let expected_sum: Option<u64> = ListNode::to_number(&num1)
+            .zip(ListNode::to_number(&num2))
+            .map(|(num1, num2)| num1 + num2);
+        let line: String =
+            format!("{} + {}", ListHead::new(&num1), ListHead::new(&num2));
+        let sum: Option<Box<ListNode>> = Solution::add_two_numbers(num1, num2);
+        pg_println!("{line} = {}", ListHead::new(&sum));
+        pg_assert_eq!(ListNode::to_vec(&sum), expected);
+        pg_assert_eq!(ListNode::to_number(&sum), expected_sum);
+    }
+}
+
+//_____________________________________________________________________________
+pub struct AddTwoNums;
+
+impl crate::core::example::Example for AddTwoNums {
+    fn name(&self) -> &'static str {
+        return "add_two_nums";
+    }
+
+    fn category(&self) -> crate::core::example::Category {
+        return crate::core::example::Category::LeetCode;
+    }
+
+    fn description(&self) -> &'static str {
+        return "Adding two numbers stored as linked lists of digits.";
+    }
+
+    fn run(&self) -> Result<(), crate::core::return_code::RC> {
+        TestSolution::test_solution();
+        return Ok(());
+    }
+}
+
+impl crate::core::callable::Callable for AddTwoNums {
+    fn name(&self) -> &'static str {
+        return "add_two_numbers";
+    }
+
+    fn aliases(&self) -> &'static [&'static str] {
+        return &["add_two_nums"];
+    }
+
+    fn signature(&self) -> &'static str {
+        return "l1: ListNode, l2: ListNode";
+    }
+
+    fn call(&self, args: &str) -> Result<String, crate::core::return_code::RC> {
+        let (l1, l2): (Option<Box<ListNode>>, Option<Box<ListNode>>) =
+            crate::leet_code::literal::Literal::parse_args_as(args)?;
+        return Ok(Solution::add_two_numbers(l1, l2).to_literal());
+    }
+}
+
+impl crate::leet_code::judge::Problem for AddTwoNums {
+    type Input = (Option<Box<ListNode>>, Option<Box<ListNode>>);
+    type Output = Option<Box<ListNode>>;
+
+    fn slug(&self) -> &'static str {
+        return "add_two_nums";
+    }
+
+    fn parse_input(
+        &self,
+        text: &str,
+    ) -> Result<
+        (Option<Box<ListNode>>, Option<Box<ListNode>>),
+        crate::core::return_code::RC,
+    > {
+        return crate::leet_code::literal::Literal::parse_args_as(text);
+    }
+
+    fn parse_output(
+        &self,
+        text: &str,
+    ) -> Result<Option<Box<ListNode>>, crate::core::return_code::RC> {
+        return crate::leet_code::literal::Literal::parse_as(text);
+    }
+
+    fn solve(
+        (l1, l2): (Option<Box<ListNode>>, Option<Box<ListNode>>),
+    ) -> Option<Box<ListNode>> {
+        return Solution::add_two_numbers(l1, l2);
+    }
+
+    fn difficulty(&self) -> Option<crate::leet_code::judge::Difficulty> {
+        return Some(crate::leet_code::judge::Difficulty::Medium);
+    }
+
+    fn tags(&self) -> &'static [&'static str] {
+        return &["linked-list", "math", "recursion"];
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::return_code::RC;
+    use crate::leet_code::literal::Literal;
+
+    /** The two lists of a call. */
+    type Lists = (Option<Box<ListNode>>, Option<Box<ListNode>>);
+
+    fn add(l1: &[i32], l2: &[i32]) -> Vec<i32> {
+        let sum: Option<Box<ListNode>> = Solution::add_two_numbers(
+            ListNode::from_slice(l1),
+            ListNode::from_slice(l2),
+        );
+        return ListNode::to_vec(&sum);
+    }
+
+    /** @return the position of the RC::CParse of the text. */
+    fn parse_error_position(text: &str) -> usize {
+        let res: Result<Lists, RC> = Literal::parse_args_as(text);
+        match res {
+            Err(RC::CParse { position, .. }) => return position,
+            Err(rc) => panic!("expected a parse error, got {rc}"),
+            Ok(_) => panic!("expected a parse error for {text}"),
+        }
+    }
+
+    #[test]
+    fn problem_example() {
+        assert_eq!(add(&[2, 4, 3], &[5, 6, 4]), [7, 0, 8]);
+    }
+
+    #[test]
+    fn carries() {
+        assert_eq!(add(&[5], &[5]), [0, 1]);
+        assert_eq!(
+            add(&[9, 9, 9, 9, 9, 9, 9], &[9, 9, 9, 9]),
+            [8, 9, 9, 9, 0, 0, 0, 1]
+        );
+    }
+
+    #[test]
+    fn unequal_lengths_and_empty_lists() {
+        assert_eq!(add(&[1, 8], &[0]), [1, 8]);
+        assert_eq!(add(&[0], &[1, 8]), [1, 8]);
+        assert_eq!(add(&[], &[7, 3]), [7, 3]);
+        assert_eq!(add(&[], &[]), Vec::<i32>::new());
+    }
+
+    #[test]
+    fn matches_the_u64_addition() {
+        let mut rng: crate::core::fuzz::Rng = crate::core::fuzz::Rng::new(1);
+        for _ in 0..1000 {
+            /* Both below u64::MAX / 2, the sum cannot overflow. */
+            let num1: u64 = rng.next_u64() >> (1 + rng.below(63));
+            let num2: u64 = rng.next_u64() >> (1 + rng.below(63));
+            let sum: Option<Box<ListNode>> = Solution::add_two_numbers(
+                ListNode::from_number(num1),
+                ListNode::from_number(num2),
+            );
+            assert_eq!(ListNode::to_number(&sum), Some(num1 + num2));
+        }
+    }
+
+    #[test]
+    fn helpers() {
+        let list: Option<Box<ListNode>> = ListNode::from_number(342);
+        assert_eq!(ListNode::to_vec(&list), [2, 4, 3]);
+        assert_eq!(ListNode::to_number(&list), Some(342));
+        assert_eq!(ListNode::to_number(&None), Some(0));
+        assert_eq!(
+            ListNode::to_number(&ListNode::from_number(u64::MAX)),
+            Some(u64::MAX)
+        );
+        assert_eq!(ListNode::to_number(&ListNode::from_slice(&[1; 21])), None);
+        assert_eq!(ListNode::to_number(&ListNode::from_slice(&[12])), None);
+        assert_eq!(ListHead::new(&list).to_string(), "2 -> 4 -> 3");
+        assert_eq!(ListHead::new(&None).to_string(), "(empty)");
+    }
+
+    #[test]
+    fn parses_digit_lists() {
+        let (l1, l2): (Option<Box<ListNode>>, Option<Box<ListNode>>) =
+            match Literal::parse_args_as("[2,4,3] []") {
+                Ok(args) => args,
+                Err(rc) => panic!("{rc}"),
+            };
+        assert_eq!(ListNode::to_vec(&l1), [2, 4, 3]);
+        assert_eq!(l2, None);
+    }
+
+    #[test]
+    fn rejects_values_which_are_not_digits() {
+        assert_eq!(parse_error_position("[12] [5]"), 1);
+        assert_eq!(parse_error_position("[5] [2,-1]"), 7);
+        assert_eq!(parse_error_position("[2147483647] [1]"), 1);
+        /* Out of the i32 range. */
+        assert_eq!(parse_error_position("[0,99999999999] [1]"), 3);
+    }
+}
@@ -1,18 +1,107 @@
-pub fn calc_num_len(mut num: u64) -> u8 {
-    let mut len: u8 = 0;
-    if num == 0  {
-        len = 1;
-    } else {
-        while num != 0 {
-            num /= 10;
-            len += 1;
-        }
-    }
-    return len;
+use crate::core::digits::Digits;
+use crate::leet_code::literal::ToLiteral;
+use crate::pg_println;
+
+/**
+ * @note a u64 has at most 20 decimal digits, so the length
+ * fits in a u8. For other types and radixes use
+ * core::digits directly.
+ */
+pub fn calc_num_len(num: u64) -> u8 {
+    return Digits::new(num).len() as u8;
 }
 
-pub fn run_example(){
+pub fn run_example() {
     let num: u64 = 31415926535;
     let len: u8 = calc_num_len(num);
-    println!("num is: {num} its len is: {len}");   
-}
\ No newline at end of file
+    pg_println!("num is: {num} its len is: {len}");
+}
+
+//_____________________________________________________________________________
+pub struct CalcNumLen;
+
+impl crate::core::example::Example for CalcNumLen {
+    fn name(&self) -> &'static str {
+        return "calc_num_len";
+    }
+
+    fn category(&self) -> crate::core::example::Category {
+        return crate::core::example::Category::LeetCode;
+    }
+
+    fn description(&self) -> &'static str {
+        return "Number of decimal digits of a u64.";
+    }
+
+    fn run(&self) -> Result<(), crate::core::return_code::RC> {
+        run_example();
+        return Ok(());
+    }
+}
+
+impl crate::core::callable::Callable for CalcNumLen {
+    fn name(&self) -> &'static str {
+        return "calc_num_len";
+    }
+
+    fn signature(&self) -> &'static str {
+        return "num: u64";
+    }
+
+    fn call(&self, args: &str) -> Result<String, crate::core::return_code::RC> {
+        let (num,): (u64,) =
+            crate::leet_code::literal::Literal::parse_args_as(args)?;
+        return Ok(calc_num_len(num).to_literal());
+    }
+}
+
+impl crate::leet_code::judge::Problem for CalcNumLen {
+    type Input = u64;
+    type Output = u8;
+
+    fn slug(&self) -> &'static str {
+        return "calc_num_len";
+    }
+
+    fn parse_input(
+        &self,
+        text: &str,
+    ) -> Result<u64, crate::core::return_code::RC> {
+        return crate::leet_code::literal::Literal::parse_as(text);
+    }
+
+    fn parse_output(
+        &self,
+        text: &str,
+    ) -> Result<u8, crate::core::return_code::RC> {
+        return crate::leet_code::literal::Literal::parse_as(text);
+    }
+
+    fn solve(num: u64) -> u8 {
+        return calc_num_len(num);
+    }
+
+    fn tags(&self) -> &'static [&'static str] {
+        return &["math"];
+    }
+}
+
+impl crate::core::fuzz::Differential for CalcNumLen {
+    type Input = u64;
+    type Output = u8;
+
+    fn name(&self) -> &'static str {
+        return "calc_num_len";
+    }
+
+    /**
+     * The length of the decimal string.
+     */
+    fn reference(num: u64) -> u8 {
+        return num.to_string().len() as u8;
+    }
+
+    fn candidate(num: u64) -> u8 {
+        return calc_num_len(num);
+    }
+}
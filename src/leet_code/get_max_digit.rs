@@ -1,15 +1,123 @@
-pub fn get_max_digit(mut num : i64) -> i64{
-    let mut max_digit : i64 = 0;
-    while num != 0 {
-        max_digit = std::cmp::max(max_digit, num % 10);
-        num /= 10;
+use crate::core::digits::Digits;
+use crate::leet_code::literal::ToLiteral;
+use crate::pg_assert_eq;
+use crate::pg_println;
+
+/**
+ * @note the digits of a negative number are the digits of
+ * its absolute value, i64::MIN included (see core::digits).
+ */
+pub fn get_max_digit(num: i64) -> i64 {
+    return Digits::new(num).max() as i64;
+}
+
+/**
+ * The reference solution for the differential testing (see
+ * core::fuzz): the maximal digit character of the decimal
+ * string.
+ */
+pub fn get_max_digit_by_string(num: i64) -> i64 {
+    let max_digit: Option<u32> = num
+        .to_string()
+        .chars()
+        .filter_map(|ch| ch.to_digit(10))
+        .max();
+    return max_digit.unwrap_or(0) as i64;
+}
+
+pub fn run_example() {
+    let num1: i64 = 123456789;
+    let num2: i64 = 0;
+    let num3: i64 = -907;
+    pg_println!("num = {num1}, max digit = {}", get_max_digit(num1));
+    pg_println!("num = {num2}, max digit = {}", get_max_digit(num2));
+    pg_println!("num = {num3}, max digit = {}", get_max_digit(num3));
+    pg_assert_eq!(get_max_digit(num3), 9);
+    pg_assert_eq!(get_max_digit(i64::MIN), 9);
+}
+
+//_____________________________________________________________________________
+pub struct GetMaxDigit;
+
+impl crate::core::example::Example for GetMaxDigit {
+    fn name(&self) -> &'static str {
+        return "get_max_digit";
+    }
+
+    fn category(&self) -> crate::core::example::Category {
+        return crate::core::example::Category::LeetCode;
+    }
+
+    fn description(&self) -> &'static str {
+        return "The maximal decimal digit of an i64.";
+    }
+
+    fn run(&self) -> Result<(), crate::core::return_code::RC> {
+        run_example();
+        return Ok(());
+    }
+}
+
+impl crate::core::callable::Callable for GetMaxDigit {
+    fn name(&self) -> &'static str {
+        return "get_max_digit";
+    }
+
+    fn signature(&self) -> &'static str {
+        return "num: i64";
+    }
+
+    fn call(&self, args: &str) -> Result<String, crate::core::return_code::RC> {
+        let (num,): (i64,) =
+            crate::leet_code::literal::Literal::parse_args_as(args)?;
+        return Ok(get_max_digit(num).to_literal());
+    }
+}
+
+impl crate::leet_code::judge::Problem for GetMaxDigit {
+    type Input = i64;
+    type Output = i64;
+
+    fn slug(&self) -> &'static str {
+        return "get_max_digit";
+    }
+
+    fn parse_input(
+        &self,
+        text: &str,
+    ) -> Result<i64, crate::core::return_code::RC> {
+        return crate::leet_code::literal::Literal::parse_as(text);
+    }
+
+    fn parse_output(
+        &self,
+        text: &str,
+    ) -> Result<i64, crate::core::return_code::RC> {
+        return crate::leet_code::literal::Literal::parse_as(text);
+    }
+
+    fn solve(num: i64) -> i64 {
+        return get_max_digit(num);
+    }
+
+    fn tags(&self) -> &'static [&'static str] {
+        return &["math"];
     }
-    return max_digit;
 }
 
-pub fn run_example(){
-    let num1 : i64 = 123456789;
-    let num2 : i64 = 0;
-    println!("num = {num1}, max digit = {}", get_max_digit(num1));
-    println!("num = {num2}, max digit = {}", get_max_digit(num2));
-}
\ No newline at end of file
+impl crate::core::fuzz::Differential for GetMaxDigit {
+    type Input = i64;
+    type Output = i64;
+
+    fn name(&self) -> &'static str {
+        return "get_max_digit";
+    }
+
+    fn reference(num: i64) -> i64 {
+        return get_max_digit_by_string(num);
+    }
+
+    fn candidate(num: i64) -> i64 {
+        return get_max_digit(num);
+    }
+}
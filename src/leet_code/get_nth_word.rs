@@ -1,5 +1,7 @@
 /** Shortcut to RC */
 use crate::core::return_code::RC;
+use crate::pg_assert;
+use crate::pg_println;
 
 //_____________________________________________________________________________
 pub struct PgGetNthWord;
@@ -13,22 +15,13 @@ impl PgGetNthWord {
         let first_word_num: usize = 1;
         let forth_word_num: usize = 4;
 
-        let first_word_or_rc: Result<
-            &str,
-            crate::core::return_code::RC,
-        > = PgGetNthWord::get_nth_word(&sentence, first_word_num);
-        PgGetNthWord::display_result(
-            first_word_or_rc,
-            first_word_num,
-        );
+        let first_word_or_rc: Result<&str, crate::core::return_code::RC> =
+            PgGetNthWord::get_nth_word(&sentence, first_word_num);
+        PgGetNthWord::display_result(first_word_or_rc, first_word_num);
 
         let forth_word_or_rc: Result<&str, RC> =
             PgGetNthWord::get_nth_word(&sentence, forth_word_num);
-        PgGetNthWord::display_result(
-            forth_word_or_rc,
-            forth_word_num,
-        );
-        crate::core::utils::Utils::print_line_separator();
+        PgGetNthWord::display_result(forth_word_or_rc, forth_word_num);
     }
 
     /*Private methods */
@@ -48,7 +41,7 @@ impl PgGetNthWord {
      * a space.
      */
     fn get_nth_word(string_ref: &str, n: usize) -> Result<&str, RC> {
-        assert!(n > 0);
+        pg_assert!(n > 0);
         let space_int_val: u8 = b' ';
         let mut space_counter: usize = 0;
         /*An index has not been found yet*/
@@ -72,7 +65,10 @@ impl PgGetNthWord {
         if space_counter == 0 && n == 1 {
             return Ok(&string_ref[0..string_ref.len()]);
         }
-        return Err(RC::CInvalidArgument);
+        return Err(RC::CInvalidArgument {
+            argument: String::from("n"),
+            reason: format!("the string has less than {n} words"),
+        });
     }
 
     /**
@@ -83,14 +79,34 @@ impl PgGetNthWord {
     fn display_result(result: Result<&str, RC>, word_num: usize) {
         match result {
             Ok(nth_word) => {
-                println!("The {word_num}th word is: {nth_word}")
+                pg_println!("The {word_num}th word is: {nth_word}")
             }
             /*
-             * Note: RC implements std::fmt::Debug and doe not
-             * implement std::fmt::Display. That's why {:?} is
-             * used to print the rc value.
+             * Note: RC implements both std::fmt::Debug and
+             * std::fmt::Display. {:?} prints the variant and
+             * its fields, {} prints a readable message.
              */
-            Err(rc) => println!("The return code is {:?}", rc),
+            Err(rc) => pg_println!("The return code is {:?}: {}", rc, rc),
         }
     }
 }
+
+//_____________________________________________________________________________
+impl crate::core::example::Example for PgGetNthWord {
+    fn name(&self) -> &'static str {
+        return "get_nth_word";
+    }
+
+    fn category(&self) -> crate::core::example::Category {
+        return crate::core::example::Category::LeetCode;
+    }
+
+    fn description(&self) -> &'static str {
+        return "The nth space separated word of a string.";
+    }
+
+    fn run(&self) -> Result<(), crate::core::return_code::RC> {
+        PgGetNthWord::example();
+        return Ok(());
+    }
+}
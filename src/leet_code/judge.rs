@@ -0,0 +1,480 @@
+use crate::core::diff::{DiffLine, LineDiff};
+use crate::core::reporter::{Reporter, Style};
+use crate::core::return_code::{ResultExt, RC};
+use crate::core::runner::Runner;
+use crate::leet_code::literal::ToLiteral;
+use std::path::{Path, PathBuf};
+use std::sync::mpsc;
+use std::time::{Duration, Instant};
+
+/**
+ * A leet code problem as seen by the judge: a solution with
+ * a typed input and a typed expected output.
+ *
+ * The test cases of a problem are stored in
+ * <data dir>/<slug>.txt, e.g. data/leet_code/two_sum.txt:
+ *
+ * # A comment.
+ * input: [2,7,11,15] 9
+ * output: [0,1]
+ *
+ * Each case is an "input:" line followed by an "output:"
+ * line, empty lines and lines starting with '#' are
+ * ignored. The input and the output are leet code literals
+ * (see leet_code::literal), so they can be pasted from the
+ * site.
+ */
+pub trait Problem {
+    type Input: Send + 'static;
+    type Output: PartialEq + ToLiteral + Send + 'static;
+
+    /**
+     * The name of the data file, by convention it's the
+     * module name.
+     */
+    fn slug(&self) -> &'static str;
+
+    fn parse_input(&self, text: &str) -> Result<Self::Input, RC>;
+
+    fn parse_output(&self, text: &str) -> Result<Self::Output, RC>;
+
+    /**
+     * Calls the solution. It's an associated function (no
+     * self) since it's run by another thread, see
+     * Judge::run_case().
+     */
+    fn solve(input: Self::Input) -> Self::Output;
+
+    /**
+     * The time a single case may take, a case taking longer
+     * fails.
+     */
+    fn timeout(&self) -> Duration {
+        return Duration::from_secs(1);
+    }
+
+    /**
+     * The difficulty on the site, None for the problems
+     * which are not from leet code.
+     */
+    fn difficulty(&self) -> Option<Difficulty> {
+        return None;
+    }
+
+    /**
+     * The topics of the problem, e.g. "array", "hash-table",
+     * as on the site.
+     */
+    fn tags(&self) -> &'static [&'static str] {
+        return &[];
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Difficulty {
+    Easy,
+    Medium,
+    Hard,
+}
+
+impl Difficulty {
+    pub fn as_str(&self) -> &'static str {
+        match *self {
+            Difficulty::Easy => "easy",
+            Difficulty::Medium => "medium",
+            Difficulty::Hard => "hard",
+        }
+    }
+
+    /**
+     * @return RC::CParse when text is not easy, medium or
+     * hard.
+     */
+    pub fn parse(text: &str) -> Result<Difficulty, RC> {
+        match text {
+            "easy" => return Ok(Difficulty::Easy),
+            "medium" => return Ok(Difficulty::Medium),
+            "hard" => return Ok(Difficulty::Hard),
+            _ => {
+                return Err(RC::parse(text, 0, "expected easy, medium or hard"))
+            }
+        }
+    }
+}
+
+/**
+ * The result of a single case.
+ */
+pub enum CaseStatus {
+    Passed,
+    /** The expected and actual outputs, formatted. */
+    Failed {
+        expected: String,
+        actual: String,
+    },
+    /** The solution panicked (RC::CPanic). */
+    Panicked(RC),
+    TimedOut,
+}
+
+pub struct CaseReport {
+    /** The (1 based) line of the case in the data file. */
+    pub line: usize,
+    pub input: String,
+    pub status: CaseStatus,
+    /** The solution's run time, the timeout when timed out. */
+    pub duration: Duration,
+}
+
+impl CaseReport {
+    pub fn is_passed(&self) -> bool {
+        return matches!(self.status, CaseStatus::Passed);
+    }
+}
+
+/**
+ * The results of all the cases of a problem.
+ */
+pub struct JudgeReport {
+    pub slug: &'static str,
+    pub cases: Vec<CaseReport>,
+}
+
+impl JudgeReport {
+    pub fn passed(&self) -> usize {
+        return self.cases.iter().filter(|case| case.is_passed()).count();
+    }
+
+    pub fn is_passed(&self) -> bool {
+        return self.passed() == self.cases.len();
+    }
+}
+
+/**
+ * A Problem with its types erased, so problems with
+ * different input and output types can be registered in the
+ * same list (see leet_code::problems()).
+ */
+pub trait Judged {
+    fn slug(&self) -> &'static str;
+
+    fn difficulty(&self) -> Option<Difficulty>;
+
+    fn tags(&self) -> &'static [&'static str];
+
+    fn judge(&self, judge: &Judge) -> Result<JudgeReport, RC>;
+}
+
+impl<P: Problem> Judged for P {
+    fn slug(&self) -> &'static str {
+        return Problem::slug(self);
+    }
+
+    fn difficulty(&self) -> Option<Difficulty> {
+        return Problem::difficulty(self);
+    }
+
+    fn tags(&self) -> &'static [&'static str] {
+        return Problem::tags(self);
+    }
+
+    fn judge(&self, judge: &Judge) -> Result<JudgeReport, RC> {
+        return judge.judge(self);
+    }
+}
+
+/**
+ * A case as written in a data file, with the (1 based) line
+ * of its input and of its output.
+ */
+struct DataCase<'a> {
+    input_line: usize,
+    input: &'a str,
+    output_line: usize,
+    output: &'a str,
+}
+
+/**
+ * Runs the cases of problems and reports the results.
+ */
+pub struct Judge {
+    m_data_dir: PathBuf,
+    /** Overrides the timeout of the problems when set. */
+    m_timeout: Option<Duration>,
+}
+
+impl Judge {
+    /** Relative to the directory the binary is run from. */
+    pub const DEFAULT_DIR: &'static str = "data/leet_code";
+
+    /** Unchanged lines shown around each change of a diff. */
+    const DIFF_CONTEXT: usize = 1;
+
+    pub fn new(data_dir: &Path, timeout: Option<Duration>) -> Judge {
+        return Judge {
+            m_data_dir: data_dir.to_path_buf(),
+            m_timeout: timeout,
+        };
+    }
+
+    /**
+     * @return the data file path of a problem.
+     */
+    pub fn path(&self, slug: &str) -> PathBuf {
+        return self.m_data_dir.join(format!("{slug}.txt"));
+    }
+
+    /**
+     * Runs all the cases of a problem.
+     * @return RC::CIo when the data file cannot be read and
+     * RC::CParse when a case cannot be parsed.
+     */
+    pub fn judge<P: Problem>(&self, problem: &P) -> Result<JudgeReport, RC> {
+        let path: PathBuf = self.path(Problem::slug(problem));
+        let text: String = std::fs::read_to_string(&path)
+            .context(&format!("reading judge cases {}", path.display()))?;
+        let timeout: Duration = self.m_timeout.unwrap_or(problem.timeout());
+        let mut cases: Vec<CaseReport> = Vec::new();
+        for case in Judge::parse_cases(&path, &text)? {
+            let input_val: P::Input =
+                problem.parse_input(case.input).map_err(|rc| {
+                    rc.context(&Judge::location(&path, case.input_line))
+                })?;
+            let expected: P::Output =
+                problem.parse_output(case.output).map_err(|rc| {
+                    rc.context(&Judge::location(&path, case.output_line))
+                })?;
+            let (status, duration): (CaseStatus, Duration) =
+                Judge::run_case::<P>(input_val, expected, timeout);
+            cases.push(CaseReport {
+                line: case.input_line,
+                input: String::from(case.input),
+                status,
+                duration,
+            });
+        }
+        return Ok(JudgeReport {
+            slug: Problem::slug(problem),
+            cases,
+        });
+    }
+
+    /**
+     * Runs the solution on another thread and waits for it
+     * at most timeout.
+     *
+     * Note: a thread cannot be killed, a timed out solution
+     * keeps running in the background till it returns (or
+     * the process exits).
+     */
+    fn run_case<P: Problem>(
+        input: P::Input,
+        expected: P::Output,
+        timeout: Duration,
+    ) -> (CaseStatus, Duration) {
+        let (sender, receiver) = mpsc::channel();
+        std::thread::spawn(move || {
+            let start: Instant = Instant::now();
+            let mut output: Option<P::Output> = None;
+            let res: Result<(), RC> = Runner::catch_panic(|| {
+                output = Some(P::solve(input));
+                return Ok(());
+            });
+            let duration: Duration = start.elapsed();
+            /* The receiver is gone when the case timed out. */
+            let _ = sender.send((res.map(|()| output), duration));
+        });
+        match receiver.recv_timeout(timeout) {
+            Ok((Ok(Some(actual)), duration)) => {
+                if actual == expected {
+                    return (CaseStatus::Passed, duration);
+                }
+                let status: CaseStatus = CaseStatus::Failed {
+                    expected: expected.to_literal(),
+                    actual: actual.to_literal(),
+                };
+                return (status, duration);
+            }
+            Ok((Ok(None), duration)) => {
+                let rc: RC = RC::CPanic {
+                    message: String::from("the solution returned nothing"),
+                    location: None,
+                };
+                return (CaseStatus::Panicked(rc), duration);
+            }
+            Ok((Err(rc), duration)) => {
+                return (CaseStatus::Panicked(rc), duration);
+            }
+            Err(_) => return (CaseStatus::TimedOut, timeout),
+        }
+    }
+
+    /**
+     * @return "<path>:<line>", the context of an error in a
+     * data file.
+     */
+    fn location(path: &Path, line: usize) -> String {
+        return format!("{}:{line}", path.display());
+    }
+
+    /**
+     * @param path the data file, for the error context.
+     * @return the cases of the data file text, or RC::CParse
+     * wrapped in a "<path>:<line>" context.
+     */
+    fn parse_cases<'a>(
+        path: &Path,
+        text: &'a str,
+    ) -> Result<Vec<DataCase<'a>>, RC> {
+        let context = |line_num: usize| -> String {
+            return Judge::location(path, line_num);
+        };
+        let mut cases: Vec<DataCase<'a>> = Vec::new();
+        let mut input: Option<(usize, &str)> = None;
+        for (idx, line) in text.lines().enumerate() {
+            let trimmed: &str = line.trim();
+            if trimmed.is_empty() || trimmed.starts_with('#') {
+                continue;
+            }
+            if let Some(rest) = trimmed.strip_prefix("input:") {
+                if input.is_some() {
+                    return Err(RC::parse(line, 0, "expected an output: line")
+                        .context(&context(idx + 1)));
+                }
+                input = Some((idx + 1, rest.trim()));
+            } else if let Some(rest) = trimmed.strip_prefix("output:") {
+                match input.take() {
+                    Some((input_line, input)) => cases.push(DataCase {
+                        input_line,
+                        input,
+                        output_line: idx + 1,
+                        output: rest.trim(),
+                    }),
+                    None => {
+                        return Err(RC::parse(
+                            line,
+                            0,
+                            "expected an input: line",
+                        )
+                        .context(&context(idx + 1)))
+                    }
+                }
+            } else {
+                return Err(RC::parse(
+                    line,
+                    0,
+                    "expected an input: or an output: line",
+                )
+                .context(&context(idx + 1)));
+            }
+        }
+        if let Some((input_line, input)) = input {
+            return Err(RC::parse(input, 0, "the last input: has no output:")
+                .context(&context(input_line)));
+        }
+        return Ok(cases);
+    }
+
+    /**
+     * Prints a line per case, failures are followed by the
+     * input and a diff of the expected and actual outputs.
+     */
+    pub fn print(reporter: &Reporter, report: &JudgeReport) {
+        for case in report.cases.iter() {
+            let (status, style): (&str, Style) = match case.status {
+                CaseStatus::Passed => ("passed   ", Style::Success),
+                CaseStatus::Failed { .. } => ("failed   ", Style::Failure),
+                CaseStatus::Panicked(_) => ("panicked ", Style::Failure),
+                CaseStatus::TimedOut => ("timed out", Style::Failure),
+            };
+            reporter.line(&format!(
+                "{} line {:<4} {:>10.3} ms",
+                reporter.paint(status, style),
+                case.line,
+                case.duration.as_secs_f64() * 1000.0
+            ));
+            let nested: Reporter = reporter.nested();
+            match case.status {
+                CaseStatus::Passed => continue,
+                CaseStatus::Failed {
+                    ref expected,
+                    ref actual,
+                } => {
+                    nested.line(&format!("input: {}", case.input));
+                    let diff: Vec<DiffLine> = LineDiff::diff(expected, actual);
+                    let rendered: String =
+                        LineDiff::render(&diff, Judge::DIFF_CONTEXT);
+                    for line in rendered.lines() {
+                        nested.line(line);
+                    }
+                }
+                CaseStatus::Panicked(ref rc) => {
+                    nested.line(&format!("input: {}", case.input));
+                    nested.line(&rc.to_string());
+                }
+                CaseStatus::TimedOut => {
+                    nested.line(&format!("input: {}", case.input));
+                }
+            }
+        }
+        let summary: String = format!(
+            "{}: {}/{} cases passed",
+            report.slug,
+            report.passed(),
+            report.cases.len()
+        );
+        let style: Style = if report.is_passed() {
+            Style::Success
+        } else {
+            Style::Failure
+        };
+        reporter.line(&reporter.paint(&summary, style));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PATH: &str = "data/leet_code/x.txt";
+
+    /** @return the rendered error of parsing the data file text. */
+    fn parse_error(text: &str) -> String {
+        match Judge::parse_cases(Path::new(PATH), text) {
+            Err(rc) => return rc.render(),
+            Ok(_) => panic!("expected a parse error for {text:?}"),
+        }
+    }
+
+    #[test]
+    fn parses_the_cases_with_their_lines() {
+        let text: &str = "# two cases\n\ninput: [1] 2\noutput: 3\n\
+                          input: [4] 5\n# comment\noutput: 6\n";
+        let cases: Vec<DataCase> =
+            Judge::parse_cases(Path::new(PATH), text).unwrap();
+        let lines: Vec<(usize, &str, usize, &str)> = cases
+            .iter()
+            .map(|case| {
+                return (
+                    case.input_line,
+                    case.input,
+                    case.output_line,
+                    case.output,
+                );
+            })
+            .collect();
+        assert_eq!(lines, [(3, "[1] 2", 4, "3"), (5, "[4] 5", 7, "6")]);
+    }
+
+    #[test]
+    fn parse_errors_have_the_data_file_line() {
+        assert!(
+            parse_error("input: 1\ninput: 2\n").contains(&format!("{PATH}:2"))
+        );
+        assert!(parse_error("\n\noutput: 1\n").contains(&format!("{PATH}:3")));
+        assert!(parse_error("input: 1\noutput: 1\nfoo\n")
+            .contains(&format!("{PATH}:3")));
+        assert!(parse_error("input: 1\noutput: 1\n\ninput: 2\n")
+            .contains(&format!("{PATH}:4")));
+    }
+}
@@ -0,0 +1,729 @@
+use crate::core::return_code::RC;
+use crate::leet_code::add_two_nums::ListNode;
+
+/**
+ * Leet code input literals.
+ *
+ * The inputs on the leet code site are written as
+ * [2,7,11,15], 9, "abc", [[1,2],[3]], true or null, and an
+ * example input is usually shown with its parameter names:
+ * nums = [2,7,11,15], target = 9
+ *
+ * Literal::parse() turns such a text into a Literal, and
+ * FromLiteral converts a Literal into a rust value
+ * (Vec<i32>, String, Option<Box<ListNode>>...). ToLiteral
+ * is the reverse, it formats a rust value the way leet code
+ * does, e.g. vec![0, 1] => "[0,1]".
+ *
+ * The errors are RC::CParse with the (byte) position of the
+ * failing literal in the text.
+ */
+pub struct Literal {
+    pub value: Value,
+    /** The (byte) position of the literal in the text. */
+    pub position: usize,
+}
+
+pub enum Value {
+    Null,
+    Bool(bool),
+    /** i128 holds any i64 and any u64. */
+    Int(i128),
+    Str(String),
+    List(Vec<Literal>),
+}
+
+impl Literal {
+    /**
+     * Parses a single literal, surrounding whitespace is
+     * allowed.
+     */
+    pub fn parse(text: &str) -> Result<Literal, RC> {
+        let mut parser: Parser = Parser::new(text);
+        let literal: Literal = parser.literal()?;
+        parser.skip_whitespace();
+        if !parser.is_done() {
+            return Err(parser.error("unexpected text after the literal"));
+        }
+        return Ok(literal);
+    }
+
+    /**
+     * Parses the arguments of a call, separated by commas or
+     * whitespace, each of them optionally named, e.g.
+     * "nums = [2,7,11,15], target = 9" or "[2,7,11,15] 9".
+     */
+    pub fn parse_args(text: &str) -> Result<Vec<Literal>, RC> {
+        let mut parser: Parser = Parser::new(text);
+        let mut args: Vec<Literal> = Vec::new();
+        parser.skip_whitespace();
+        while !parser.is_done() {
+            parser.skip_name();
+            args.push(parser.literal()?);
+            parser.skip_whitespace();
+            if parser.peek() == Some(b',') {
+                parser.advance();
+                parser.skip_whitespace();
+                if parser.is_done() {
+                    return Err(parser.error("expected an argument"));
+                }
+            }
+        }
+        return Ok(args);
+    }
+
+    /**
+     * Parses a single literal and converts it.
+     * @return RC::CParse when the text is not a literal or
+     * the literal does not match T.
+     */
+    pub fn parse_as<T: FromLiteral>(text: &str) -> Result<T, RC> {
+        let literal: Literal = Literal::parse(text)?;
+        return T::from_literal(&literal, text);
+    }
+
+    /**
+     * Parses arguments (see parse_args()) and converts them,
+     * e.g. Literal::parse_args_as::<(Vec<i32>, i32)>(..).
+     */
+    pub fn parse_args_as<T: FromArgs>(text: &str) -> Result<T, RC> {
+        let args: Vec<Literal> = Literal::parse_args(text)?;
+        return T::from_args(&args, text);
+    }
+
+    /**
+     * @return the name of the literal's kind, for errors.
+     */
+    pub fn kind(&self) -> &'static str {
+        match self.value {
+            Value::Null => return "null",
+            Value::Bool(_) => return "a boolean",
+            Value::Int(_) => return "an integer",
+            Value::Str(_) => return "a string",
+            Value::List(_) => return "a list",
+        }
+    }
+
+    /**
+     * @return RC::CParse at the literal's position, e.g.
+     * "expected an integer, found a string".
+     */
+    pub fn mismatch(&self, text: &str, expected: &str) -> RC {
+        return RC::parse(
+            text,
+            self.position,
+            &format!("expected {expected}, found {}", self.kind()),
+        );
+    }
+}
+
+/**
+ * A recursive descent parser, a function per literal kind.
+ */
+struct Parser<'a> {
+    m_text: &'a str,
+    m_bytes: &'a [u8],
+    m_pos: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn new(text: &'a str) -> Parser<'a> {
+        return Parser {
+            m_text: text,
+            m_bytes: text.as_bytes(),
+            m_pos: 0,
+        };
+    }
+
+    fn error(&self, message: &str) -> RC {
+        return RC::parse(self.m_text, self.m_pos, message);
+    }
+
+    fn is_done(&self) -> bool {
+        return self.m_pos == self.m_bytes.len();
+    }
+
+    fn peek(&self) -> Option<u8> {
+        return self.m_bytes.get(self.m_pos).copied();
+    }
+
+    fn advance(&mut self) {
+        self.m_pos += 1;
+    }
+
+    fn skip_whitespace(&mut self) {
+        while let Some(byte) = self.peek() {
+            if !byte.is_ascii_whitespace() {
+                return;
+            }
+            self.advance();
+        }
+    }
+
+    /**
+     * Skips "<identifier> =" (an argument name), if any.
+     */
+    fn skip_name(&mut self) {
+        let start: usize = self.m_pos;
+        while let Some(byte) = self.peek() {
+            if !(byte.is_ascii_alphanumeric() || byte == b'_') {
+                break;
+            }
+            self.advance();
+        }
+        let name: &str = &self.m_text[start..self.m_pos];
+        self.skip_whitespace();
+        let is_name: bool = !name.is_empty()
+            && !name.as_bytes()[0].is_ascii_digit()
+            && self.peek() == Some(b'=');
+        if is_name {
+            self.advance();
+            self.skip_whitespace();
+        } else {
+            self.m_pos = start;
+        }
+    }
+
+    fn literal(&mut self) -> Result<Literal, RC> {
+        self.skip_whitespace();
+        let position: usize = self.m_pos;
+        let value: Value = match self.peek() {
+            None => return Err(self.error("expected a literal")),
+            Some(b'[') => self.list()?,
+            Some(b'"') => Value::Str(self.string()?),
+            Some(b'-') | Some(b'0'..=b'9') => Value::Int(self.int()?),
+            Some(_) => self.word()?,
+        };
+        return Ok(Literal { value, position });
+    }
+
+    fn list(&mut self) -> Result<Value, RC> {
+        /* Skip the '['. */
+        self.advance();
+        let mut items: Vec<Literal> = Vec::new();
+        self.skip_whitespace();
+        if self.peek() == Some(b']') {
+            self.advance();
+            return Ok(Value::List(items));
+        }
+        loop {
+            items.push(self.literal()?);
+            self.skip_whitespace();
+            match self.peek() {
+                Some(b',') => self.advance(),
+                Some(b']') => {
+                    self.advance();
+                    return Ok(Value::List(items));
+                }
+                Some(_) => return Err(self.error("expected ',' or ']'")),
+                None => return Err(self.error("unterminated list")),
+            }
+        }
+    }
+
+    /**
+     * A string in double quotes, the escapes are the JSON
+     * ones: \" \\ \/ \n \t \r and \uXXXX. Like in JSON, a
+     * character outside the BMP is a UTF-16 surrogate pair,
+     * e.g. "\ud83d\ude00".
+     */
+    fn string(&mut self) -> Result<String, RC> {
+        let start: usize = self.m_pos;
+        /* Skip the opening '"'. */
+        self.advance();
+        let mut res: String = String::new();
+        loop {
+            let rest: &str = &self.m_text[self.m_pos..];
+            let ch: char = match rest.chars().next() {
+                Some(ch) => ch,
+                None => {
+                    self.m_pos = start;
+                    return Err(self.error("unterminated string"));
+                }
+            };
+            self.m_pos += ch.len_utf8();
+            match ch {
+                '"' => return Ok(res),
+                '\\' => res.push(self.escape()?),
+                _ => res.push(ch),
+            }
+        }
+    }
+
+    fn escape(&mut self) -> Result<char, RC> {
+        let escaped: u8 = match self.peek() {
+            Some(byte) => byte,
+            None => return Err(self.error("unterminated escape")),
+        };
+        self.advance();
+        match escaped {
+            b'"' => return Ok('"'),
+            b'\\' => return Ok('\\'),
+            b'/' => return Ok('/'),
+            b'n' => return Ok('\n'),
+            b't' => return Ok('\t'),
+            b'r' => return Ok('\r'),
+            b'u' => {
+                let start: usize = self.m_pos;
+                let mut code: u32 = self.hex4()?;
+                if (0xD800..0xDC00).contains(&code) {
+                    /* A high surrogate, the low one must follow. */
+                    let low: Option<u32> =
+                        if self.m_text[self.m_pos..].starts_with("\\u") {
+                            self.m_pos += 2;
+                            Some(self.hex4()?)
+                        } else {
+                            None
+                        };
+                    match low {
+                        Some(low) if (0xDC00..0xE000).contains(&low) => {
+                            code = 0x10000
+                                + ((code - 0xD800) << 10)
+                                + (low - 0xDC00);
+                        }
+                        _ => {
+                            self.m_pos = start;
+                            return Err(self.error("unpaired surrogate"));
+                        }
+                    }
+                }
+                match char::from_u32(code) {
+                    Some(ch) => return Ok(ch),
+                    None => {
+                        /* A low surrogate without a high one. */
+                        self.m_pos = start;
+                        return Err(self.error("unpaired surrogate"));
+                    }
+                }
+            }
+            _ => {
+                self.m_pos -= 1;
+                return Err(self.error("unknown escape"));
+            }
+        }
+    }
+
+    /**
+     * The 4 hex digits of a \u escape.
+     */
+    fn hex4(&mut self) -> Result<u32, RC> {
+        let hex: &str =
+            self.m_text.get(self.m_pos..self.m_pos + 4).unwrap_or("");
+        if hex.len() != 4 || !hex.bytes().all(|byte| byte.is_ascii_hexdigit()) {
+            return Err(self.error("invalid \\u escape"));
+        }
+        self.m_pos += 4;
+        return u32::from_str_radix(hex, 16)
+            .map_err(|_| self.error("invalid \\u escape"));
+    }
+
+    fn int(&mut self) -> Result<i128, RC> {
+        let start: usize = self.m_pos;
+        if self.peek() == Some(b'-') {
+            self.advance();
+        }
+        while let Some(b'0'..=b'9') = self.peek() {
+            self.advance();
+        }
+        return self.m_text[start..self.m_pos]
+            .parse::<i128>()
+            .map_err(|err| {
+                return RC::parse(self.m_text, start, &err.to_string());
+            });
+    }
+
+    /**
+     * true, false or null.
+     */
+    fn word(&mut self) -> Result<Value, RC> {
+        let rest: &str = &self.m_text[self.m_pos..];
+        for (word, value) in [
+            ("true", Value::Bool(true)),
+            ("false", Value::Bool(false)),
+            ("null", Value::Null),
+        ] {
+            if rest.starts_with(word) {
+                self.m_pos += word.len();
+                return Ok(value);
+            }
+        }
+        return Err(self.error("expected a literal"));
+    }
+}
+
+//_____________________________________________________________________________
+/**
+ * Converts a literal into a rust value.
+ */
+pub trait FromLiteral: Sized {
+    /**
+     * @param literal the parsed literal.
+     * @param text the parsed text, for the errors.
+     */
+    fn from_literal(literal: &Literal, text: &str) -> Result<Self, RC>;
+}
+
+macro_rules! impl_from_literal_for_int {
+    ($($int:ty),*) => {
+        $(
+            impl FromLiteral for $int {
+                fn from_literal(
+                    literal: &Literal,
+                    text: &str,
+                ) -> Result<$int, RC> {
+                    match literal.value {
+                        Value::Int(val) => {
+                            return <$int>::try_from(val).map_err(|_| {
+                                RC::parse(
+                                    text,
+                                    literal.position,
+                                    &format!(
+                                        "{val} is out of the {} range",
+                                        stringify!($int)
+                                    ),
+                                )
+                            });
+                        }
+                        _ => return Err(literal.mismatch(text, "an integer")),
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_from_literal_for_int!(i8, i16, i32, i64, u8, u16, u32, u64, usize);
+
+impl FromLiteral for bool {
+    fn from_literal(literal: &Literal, text: &str) -> Result<bool, RC> {
+        match literal.value {
+            Value::Bool(val) => return Ok(val),
+            _ => return Err(literal.mismatch(text, "a boolean")),
+        }
+    }
+}
+
+impl FromLiteral for String {
+    fn from_literal(literal: &Literal, text: &str) -> Result<String, RC> {
+        match literal.value {
+            Value::Str(ref val) => return Ok(val.clone()),
+            _ => return Err(literal.mismatch(text, "a string")),
+        }
+    }
+}
+
+/**
+ * A char is written as a single character string, e.g.
+ * [["5","3"],[".","9"]].
+ */
+impl FromLiteral for char {
+    fn from_literal(literal: &Literal, text: &str) -> Result<char, RC> {
+        if let Value::Str(ref val) = literal.value {
+            let mut chars = val.chars();
+            if let (Some(ch), None) = (chars.next(), chars.next()) {
+                return Ok(ch);
+            }
+        }
+        return Err(literal.mismatch(text, "a single character string"));
+    }
+}
+
+impl<T: FromLiteral> FromLiteral for Vec<T> {
+    fn from_literal(literal: &Literal, text: &str) -> Result<Vec<T>, RC> {
+        match literal.value {
+            Value::List(ref items) => {
+                return items
+                    .iter()
+                    .map(|item| T::from_literal(item, text))
+                    .collect();
+            }
+            _ => return Err(literal.mismatch(text, "a list")),
+        }
+    }
+}
+
+/**
+ * A linked list is written as the list of its values, e.g.
+ * [2,4,3] is 2 -> 4 -> 3, and [] is the empty list (None).
+ *
+ * The lists are numbers stored digit by digit (see
+ * add_two_nums), so a value outside 0..=9 is rejected with
+ * RC::CParse at its position: [12] would silently carry
+ * into the next digit, and large values overflow the sum.
+ */
+impl FromLiteral for Option<Box<ListNode>> {
+    fn from_literal(
+        literal: &Literal,
+        text: &str,
+    ) -> Result<Option<Box<ListNode>>, RC> {
+        let items: &[Literal] = match literal.value {
+            Value::List(ref items) => items,
+            _ => return Err(literal.mismatch(text, "a list")),
+        };
+        let mut vals: Vec<i32> = Vec::with_capacity(items.len());
+        for item in items.iter() {
+            let val: i32 = i32::from_literal(item, text)?;
+            if !(0..=9).contains(&val) {
+                return Err(RC::parse(
+                    text,
+                    item.position,
+                    &format!("{val} is not a digit (0..=9)"),
+                ));
+            }
+            vals.push(val);
+        }
+        return Ok(ListNode::from_slice(&vals));
+    }
+}
+
+/**
+ * Converts the arguments of a call, implemented for the
+ * tuples of FromLiteral types.
+ */
+pub trait FromArgs: Sized {
+    fn from_args(args: &[Literal], text: &str) -> Result<Self, RC>;
+}
+
+macro_rules! impl_from_args_for_tuple {
+    ($count:expr, $($name:ident: $idx:tt),*) => {
+        impl<$($name: FromLiteral),*> FromArgs for ($($name,)*) {
+            fn from_args(
+                args: &[Literal],
+                text: &str,
+            ) -> Result<($($name,)*), RC> {
+                if args.len() != $count {
+                    return Err(RC::parse(
+                        text,
+                        0,
+                        &format!(
+                            "expected {} arguments, found {}",
+                            $count,
+                            args.len()
+                        ),
+                    ));
+                }
+                return Ok(($($name::from_literal(&args[$idx], text)?,)*));
+            }
+        }
+    };
+}
+
+impl_from_args_for_tuple!(1, A: 0);
+impl_from_args_for_tuple!(2, A: 0, B: 1);
+impl_from_args_for_tuple!(3, A: 0, B: 1, C: 2);
+
+//_____________________________________________________________________________
+/**
+ * Formats a rust value as a leet code literal, the reverse
+ * of FromLiteral.
+ */
+pub trait ToLiteral {
+    fn to_literal(&self) -> String;
+}
+
+macro_rules! impl_to_literal_for_int {
+    ($($int:ty),*) => {
+        $(
+            impl ToLiteral for $int {
+                fn to_literal(&self) -> String {
+                    return self.to_string();
+                }
+            }
+        )*
+    };
+}
+
+impl_to_literal_for_int!(i8, i16, i32, i64, u8, u16, u32, u64, usize);
+
+impl ToLiteral for bool {
+    fn to_literal(&self) -> String {
+        return self.to_string();
+    }
+}
+
+impl ToLiteral for str {
+    fn to_literal(&self) -> String {
+        let mut res: String = String::from("\"");
+        for ch in self.chars() {
+            match ch {
+                '"' => res.push_str("\\\""),
+                '\\' => res.push_str("\\\\"),
+                '\n' => res.push_str("\\n"),
+                '\t' => res.push_str("\\t"),
+                '\r' => res.push_str("\\r"),
+                ch if ch.is_control() => {
+                    res.push_str(&format!("\\u{:04x}", ch as u32))
+                }
+                ch => res.push(ch),
+            }
+        }
+        res.push('"');
+        return res;
+    }
+}
+
+impl ToLiteral for String {
+    fn to_literal(&self) -> String {
+        return self.as_str().to_literal();
+    }
+}
+
+impl ToLiteral for char {
+    fn to_literal(&self) -> String {
+        return self.to_string().to_literal();
+    }
+}
+
+impl<T: ToLiteral> ToLiteral for [T] {
+    fn to_literal(&self) -> String {
+        let items: Vec<String> =
+            self.iter().map(|item| item.to_literal()).collect();
+        return format!("[{}]", items.join(","));
+    }
+}
+
+impl<T: ToLiteral> ToLiteral for Vec<T> {
+    fn to_literal(&self) -> String {
+        return self.as_slice().to_literal();
+    }
+}
+
+impl ToLiteral for Option<Box<ListNode>> {
+    fn to_literal(&self) -> String {
+        return ListNode::to_vec(self).to_literal();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /** @return the position and the message of the RC::CParse. */
+    fn parse_error<T: FromLiteral>(text: &str) -> (usize, String) {
+        match Literal::parse_as::<T>(text) {
+            Err(RC::CParse {
+                position, message, ..
+            }) => return (position, message),
+            Err(rc) => panic!("expected a parse error, got {rc}"),
+            Ok(_) => panic!("expected a parse error for {text}"),
+        }
+    }
+
+    #[test]
+    fn numbers() {
+        assert_eq!(Literal::parse_as::<i32>(" -42 ").unwrap(), -42);
+        assert_eq!(
+            Literal::parse_as::<u64>("18446744073709551615").unwrap(),
+            u64::MAX
+        );
+        assert_eq!(
+            Literal::parse_as::<i64>("-9223372036854775808").unwrap(),
+            i64::MIN
+        );
+        assert_eq!(
+            parse_error::<i32>("2147483648"),
+            (0, String::from("2147483648 is out of the i32 range"))
+        );
+        assert_eq!(parse_error::<u8>("[1, -1]").0, 0);
+        assert_eq!(
+            parse_error::<i32>("\"1\""),
+            (0, String::from("expected an integer, found a string"))
+        );
+    }
+
+    #[test]
+    fn booleans_and_null() {
+        assert!(Literal::parse_as::<bool>("true").unwrap());
+        assert!(!Literal::parse_as::<bool>("false").unwrap());
+        assert_eq!(
+            Literal::parse_as::<Option<Box<ListNode>>>("[]").unwrap(),
+            None
+        );
+        assert!(matches!(Literal::parse("null").unwrap().value, Value::Null));
+    }
+
+    #[test]
+    fn strings() {
+        assert_eq!(Literal::parse_as::<String>("\"abc\"").unwrap(), "abc");
+        assert_eq!(
+            Literal::parse_as::<String>(r#""a\"b\\c\/d\ne\tf\r""#).unwrap(),
+            "a\"b\\c/d\ne\tf\r"
+        );
+        assert_eq!(Literal::parse_as::<String>(r#""éé""#).unwrap(), "éé");
+        assert_eq!(Literal::parse_as::<char>("\".\"").unwrap(), '.');
+        assert_eq!(parse_error::<String>(r#""abc"#).0, 0);
+        assert_eq!(parse_error::<String>(r#""a\qb""#).0, 3);
+        assert_eq!(parse_error::<String>(r#""a\u12""#).0, 4);
+        assert_eq!(parse_error::<String>(r#""a\u+123""#).0, 4);
+        assert_eq!(parse_error::<char>("\"ab\"").0, 0);
+    }
+
+    #[test]
+    fn surrogate_pairs() {
+        assert_eq!(
+            Literal::parse_as::<String>(r#""\ud83d\ude00!""#).unwrap(),
+            "\u{1f600}!"
+        );
+        assert_eq!(
+            Literal::parse_as::<String>(r#""\uD83D\uDE00""#).unwrap(),
+            "\u{1f600}"
+        );
+        /* The position is the one of the first escape's digits. */
+        assert_eq!(
+            parse_error::<String>(r#""\ud83d""#),
+            (3, String::from("unpaired surrogate"))
+        );
+        assert_eq!(parse_error::<String>(r#""\ud83dx""#).0, 3);
+        assert_eq!(parse_error::<String>(r#""\ud83dA""#).0, 3);
+        assert_eq!(parse_error::<String>(r#""\ude00""#).0, 3);
+    }
+
+    #[test]
+    fn nested_lists() {
+        assert_eq!(
+            Literal::parse_as::<Vec<Vec<i32>>>("[[1,2], [], [ 3 ]]").unwrap(),
+            vec![vec![1, 2], vec![], vec![3]]
+        );
+        assert_eq!(
+            Literal::parse_as::<Vec<Vec<char>>>(r#"[["5","3"],[".","9"]]"#)
+                .unwrap(),
+            vec![vec!['5', '3'], vec!['.', '9']]
+        );
+        /* The position of the failing item, not of the list. */
+        assert_eq!(parse_error::<Vec<Vec<i32>>>("[[1],[2,true]]").0, 8);
+        assert_eq!(parse_error::<Vec<i32>>("[1,2").0, 4);
+        assert_eq!(parse_error::<Vec<i32>>("[1 2]").0, 3);
+        assert_eq!(parse_error::<Vec<i32>>("[1] 2").0, 4);
+        assert_eq!(parse_error::<Vec<i32>>("").0, 0);
+    }
+
+    #[test]
+    fn argument_separators() {
+        let expected: (Vec<i32>, i32) = (vec![2, 7, 11, 15], 9);
+        for text in [
+            "[2,7,11,15] 9",
+            "[2,7,11,15],9",
+            " [2,7,11,15] ,\t9 ",
+            "nums = [2,7,11,15], target = 9",
+            "nums=[2,7,11,15] target=9",
+        ] {
+            let args: (Vec<i32>, i32) = Literal::parse_args_as(text).unwrap();
+            assert_eq!(args, expected, "{text}");
+        }
+        let res: Result<(Vec<i32>, i32), RC> =
+            Literal::parse_args_as("[1], 2,");
+        assert!(matches!(res, Err(RC::CParse { position: 7, .. })));
+        let res: Result<(Vec<i32>, i32), RC> = Literal::parse_args_as("[1]");
+        assert!(matches!(res, Err(RC::CParse { .. })));
+    }
+
+    #[test]
+    fn to_literal() {
+        assert_eq!(vec![0, 1].to_literal(), "[0,1]");
+        assert_eq!(Vec::<Vec<i32>>::new().to_literal(), "[]");
+        assert_eq!(vec![vec![1], vec![2, 3]].to_literal(), "[[1],[2,3]]");
+        assert_eq!("a\"\n\u{1}".to_literal(), r#""a\"\n\u0001""#);
+        let text: String = "é\t\u{1f600}".to_literal();
+        assert_eq!(Literal::parse_as::<String>(&text).unwrap(), "é\t\u{1f600}");
+    }
+}
@@ -1,5 +1,79 @@
 pub mod add_two_nums;
+pub mod calc_num_len;
+pub mod get_max_digit;
 pub mod get_nth_word;
+pub mod judge;
+pub mod literal;
+pub mod progress;
+pub mod scaffold;
 pub mod two_sum;
-pub mod calc_num_len;
-pub mod get_max_digit;
\ No newline at end of file
+
+/**
+ * @return the leet code problems which can be run by the
+ * runner binary.
+ */
+pub fn examples() -> Vec<Box<dyn crate::core::example::Example>> {
+    return vec![
+        Box::new(calc_num_len::CalcNumLen),
+        Box::new(get_max_digit::GetMaxDigit),
+        Box::new(two_sum::TwoSum),
+        Box::new(get_nth_word::PgGetNthWord),
+        Box::new(add_two_nums::AddTwoNums),
+    ];
+}
+
+/**
+ * @return the leet code functions which can be called from
+ * the REPL.
+ */
+pub fn callables() -> Vec<Box<dyn crate::core::callable::Callable>> {
+    return vec![
+        Box::new(calc_num_len::CalcNumLen),
+        Box::new(get_max_digit::GetMaxDigit),
+        Box::new(two_sum::TwoSum),
+        Box::new(add_two_nums::AddTwoNums),
+    ];
+}
+
+/**
+ * @return the leet code problems which can be judged, their
+ * cases are in data/leet_code/<slug>.txt.
+ */
+pub fn problems() -> Vec<Box<dyn judge::Judged>> {
+    return vec![
+        Box::new(calc_num_len::CalcNumLen),
+        Box::new(get_max_digit::GetMaxDigit),
+        Box::new(two_sum::TwoSum),
+        Box::new(add_two_nums::AddTwoNums),
+    ];
+}
+
+/**
+ * @return the leet code solutions which are compared with a
+ * reference solution on random inputs (see core::fuzz).
+ */
+pub fn fuzz_targets() -> Vec<Box<dyn crate::core::fuzz::FuzzTarget>> {
+    return vec![
+        Box::new(calc_num_len::CalcNumLen),
+        Box::new(get_max_digit::GetMaxDigit),
+        Box::new(two_sum::TwoSum),
+        Box::new(crate::core::digits::DigitsFuzz),
+        Box::new(crate::core::big_uint::BigUintFuzz),
+    ];
+}
+
+/**
+ * @return the leet code solutions whose running time is
+ * measured by rust_pg complexity (see core::complexity).
+ */
+pub fn complexity_targets(
+) -> Vec<Box<dyn crate::core::complexity::ComplexityTarget>> {
+    return two_sum::Strategy::all()
+        .into_iter()
+        .map(|strategy| {
+            let target: Box<dyn crate::core::complexity::ComplexityTarget> =
+                Box::new(two_sum::TwoSumComplexity(strategy));
+            return target;
+        })
+        .collect();
+}
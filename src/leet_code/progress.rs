@@ -0,0 +1,539 @@
+use crate::core::reporter::Reporter;
+use crate::core::return_code::{ResultExt, RC};
+use crate::leet_code::judge::{Difficulty, JudgeReport, Judged};
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+/*
+ * The progress of a person on the leet code problems: each
+ * judge run is recorded, so the progress report shows what
+ * was solved, what is still failing and what regressed
+ * (passed once, fails now).
+ *
+ * The store is a plain text file, by default in the user
+ * data directory (each person has their own progress):
+ * $XDG_DATA_HOME/rust_pg/progress.txt, or
+ * ~/.local/share/rust_pg/progress.txt. A record per line,
+ * tab separated, the first field is the problem slug:
+ *
+ * two_sum status=solved attempts=3 passed=6 cases=6 ...
+ *
+ * The times are Unix timestamps (seconds, UTC).
+ */
+
+/**
+ * solved -> regressed when a solved problem fails, and
+ * regressed -> solved when it passes again.
+ */
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum ProgressStatus {
+    /** Judged, never passed. */
+    Attempted,
+    Solved,
+    /** Passed before, the last run failed. */
+    Regressed,
+}
+
+impl ProgressStatus {
+    pub fn as_str(&self) -> &'static str {
+        match *self {
+            ProgressStatus::Attempted => "attempted",
+            ProgressStatus::Solved => "solved",
+            ProgressStatus::Regressed => "regressed",
+        }
+    }
+
+    fn parse(text: &str) -> Result<ProgressStatus, RC> {
+        match text {
+            "attempted" => return Ok(ProgressStatus::Attempted),
+            "solved" => return Ok(ProgressStatus::Solved),
+            "regressed" => return Ok(ProgressStatus::Regressed),
+            _ => {
+                return Err(RC::parse(
+                    text,
+                    0,
+                    "expected attempted, solved or regressed",
+                ))
+            }
+        }
+    }
+}
+
+pub struct ProblemRecord {
+    pub slug: String,
+    pub status: ProgressStatus,
+    /** The number of judge runs. */
+    pub attempts: u32,
+    /** The result of the last run: passed cases of cases. */
+    pub passed: usize,
+    pub cases: usize,
+    pub first_attempt: u64,
+    pub last_attempt: u64,
+    /** The first run all the cases passed. */
+    pub solved_at: Option<u64>,
+    pub difficulty: Option<Difficulty>,
+    pub tags: Vec<String>,
+}
+
+impl ProblemRecord {
+    fn new(slug: &str, now: u64) -> ProblemRecord {
+        return ProblemRecord {
+            slug: String::from(slug),
+            status: ProgressStatus::Attempted,
+            attempts: 0,
+            passed: 0,
+            cases: 0,
+            first_attempt: now,
+            last_attempt: now,
+            solved_at: None,
+            difficulty: None,
+            tags: Vec::new(),
+        };
+    }
+
+    fn to_line(&self) -> String {
+        let mut fields: Vec<String> = vec![
+            self.slug.clone(),
+            format!("status={}", self.status.as_str()),
+            format!("attempts={}", self.attempts),
+            format!("passed={}", self.passed),
+            format!("cases={}", self.cases),
+            format!("first_attempt={}", self.first_attempt),
+            format!("last_attempt={}", self.last_attempt),
+        ];
+        if let Some(solved_at) = self.solved_at {
+            fields.push(format!("solved_at={solved_at}"));
+        }
+        if let Some(difficulty) = self.difficulty {
+            fields.push(format!("difficulty={}", difficulty.as_str()));
+        }
+        if !self.tags.is_empty() {
+            fields.push(format!("tags={}", self.tags.join(",")));
+        }
+        return fields.join("\t");
+    }
+
+    /**
+     * @return RC::CParse when a field is malformed or
+     * unknown (e.g. a typo in a hand edited file).
+     */
+    fn parse(line: &str) -> Result<ProblemRecord, RC> {
+        let mut fields: std::str::Split<char> = line.split('\t');
+        let slug: &str = fields.next().unwrap_or("");
+        if slug.is_empty() {
+            return Err(RC::parse(line, 0, "expected a problem slug"));
+        }
+        let mut record: ProblemRecord = ProblemRecord::new(slug, 0);
+        for field in fields {
+            let position: usize =
+                field.as_ptr() as usize - line.as_ptr() as usize;
+            let (key, value): (&str, &str) =
+                field.split_once('=').ok_or_else(|| {
+                    RC::parse(line, position, "expected key=value")
+                })?;
+            let res: Result<(), RC> = match key {
+                "status" => ProgressStatus::parse(value)
+                    .map(|status| record.status = status),
+                "attempts" => ProblemRecord::number(value)
+                    .map(|attempts| record.attempts = attempts),
+                "passed" => ProblemRecord::number(value)
+                    .map(|passed| record.passed = passed),
+                "cases" => ProblemRecord::number(value)
+                    .map(|cases| record.cases = cases),
+                "first_attempt" => ProblemRecord::number(value)
+                    .map(|time| record.first_attempt = time),
+                "last_attempt" => ProblemRecord::number(value)
+                    .map(|time| record.last_attempt = time),
+                "solved_at" => ProblemRecord::number(value)
+                    .map(|time| record.solved_at = Some(time)),
+                "difficulty" => Difficulty::parse(value)
+                    .map(|difficulty| record.difficulty = Some(difficulty)),
+                "tags" => {
+                    record.tags = value.split(',').map(String::from).collect();
+                    Ok(())
+                }
+                _ => Err(RC::parse(key, 0, "unknown key")),
+            };
+            res.map_err(|rc| {
+                RC::parse(line, position, &format!("{key}: {rc}"))
+            })?;
+        }
+        return Ok(record);
+    }
+
+    fn number<T: std::str::FromStr>(text: &str) -> Result<T, RC> {
+        return text
+            .parse::<T>()
+            .map_err(|_| RC::parse(text, 0, "expected a number"));
+    }
+}
+
+pub struct Progress {
+    m_path: PathBuf,
+    /** By slug, so the file and the report are sorted. */
+    m_records: BTreeMap<String, ProblemRecord>,
+}
+
+impl Progress {
+    const HEADER: &'static str =
+        "# rust_pg progress, see src/leet_code/progress.rs\n";
+
+    /**
+     * @return the file in the user data directory, or
+     * data/progress.txt (in the project) when there is no
+     * home directory.
+     */
+    pub fn default_path() -> PathBuf {
+        let data_dir: Option<PathBuf> = std::env::var_os("XDG_DATA_HOME")
+            .filter(|dir| !dir.is_empty())
+            .map(PathBuf::from)
+            .or_else(|| {
+                std::env::var_os("HOME")
+                    .filter(|dir| !dir.is_empty())
+                    .map(|home| PathBuf::from(home).join(".local/share"))
+            });
+        match data_dir {
+            Some(dir) => return dir.join("rust_pg").join("progress.txt"),
+            None => return PathBuf::from("data/progress.txt"),
+        }
+    }
+
+    /**
+     * A missing file is an empty progress (it's created by
+     * save).
+     * @return RC::CParse (with the file and the line) for a
+     * malformed record.
+     */
+    pub fn load(path: &Path) -> Result<Progress, RC> {
+        let mut progress: Progress = Progress {
+            m_path: path.to_path_buf(),
+            m_records: BTreeMap::new(),
+        };
+        if !path.exists() {
+            return Ok(progress);
+        }
+        let text: String = std::fs::read_to_string(path)
+            .context(&format!("reading progress {}", path.display()))?;
+        for (idx, line) in text.lines().enumerate() {
+            if line.trim().is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let record: ProblemRecord =
+                ProblemRecord::parse(line).map_err(|rc| {
+                    rc.context(&format!("{}:{}", path.display(), idx + 1))
+                })?;
+            progress.m_records.insert(record.slug.clone(), record);
+        }
+        return Ok(progress);
+    }
+
+    pub fn save(&self) -> Result<(), RC> {
+        if let Some(parent) = self.m_path.parent() {
+            std::fs::create_dir_all(parent)
+                .context(&format!("creating {}", parent.display()))?;
+        }
+        let mut text: String = String::from(Progress::HEADER);
+        for record in self.m_records.values() {
+            text.push_str(&record.to_line());
+            text.push('\n');
+        }
+        return std::fs::write(&self.m_path, text)
+            .context(&format!("writing progress {}", self.m_path.display()));
+    }
+
+    pub fn path(&self) -> &Path {
+        return &self.m_path;
+    }
+
+    pub fn get(&self, slug: &str) -> Option<&ProblemRecord> {
+        return self.m_records.get(slug);
+    }
+
+    /**
+     * Records a judge run. A problem is solved when it has
+     * cases and all of them passed (a new problem has no
+     * cases, it's only attempted).
+     * @param now the run's Unix timestamp.
+     */
+    pub fn record(
+        &mut self,
+        problem: &dyn Judged,
+        report: &JudgeReport,
+        now: u64,
+    ) {
+        let record: &mut ProblemRecord = self
+            .m_records
+            .entry(String::from(problem.slug()))
+            .or_insert_with(|| ProblemRecord::new(problem.slug(), now));
+        let is_passed: bool = !report.cases.is_empty() && report.is_passed();
+        record.status = match (record.status, is_passed) {
+            (_, true) => ProgressStatus::Solved,
+            (ProgressStatus::Attempted, false) => ProgressStatus::Attempted,
+            (_, false) => ProgressStatus::Regressed,
+        };
+        if is_passed && record.solved_at.is_none() {
+            record.solved_at = Some(now);
+        }
+        record.attempts += 1;
+        record.passed = report.passed();
+        record.cases = report.cases.len();
+        record.last_attempt = now;
+        record.difficulty = problem.difficulty();
+        record.tags = problem
+            .tags()
+            .iter()
+            .map(|&tag| String::from(tag))
+            .collect();
+    }
+
+    /**
+     * @return the current Unix timestamp.
+     */
+    pub fn now() -> u64 {
+        return std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_secs();
+    }
+
+    /**
+     * Prints a row per problem, the registered problems
+     * which were never judged included, then the totals.
+     * @param slugs the registered problems.
+     */
+    pub fn print(&self, reporter: &Reporter, slugs: &[&str]) {
+        let mut all_slugs: Vec<&str> = slugs.to_vec();
+        for slug in self.m_records.keys() {
+            if !all_slugs.contains(&slug.as_str()) {
+                all_slugs.push(slug);
+            }
+        }
+        all_slugs.sort();
+
+        let mut counts: BTreeMap<&str, usize> = BTreeMap::new();
+        let mut rows: Vec<Vec<String>> = Vec::new();
+        for slug in all_slugs {
+            let row: Vec<String> = match self.m_records.get(slug) {
+                Some(record) => {
+                    *counts.entry(record.status.as_str()).or_default() += 1;
+                    vec![
+                        String::from(slug),
+                        String::from(
+                            record.difficulty.map_or("", |diff| diff.as_str()),
+                        ),
+                        String::from(record.status.as_str()),
+                        record.attempts.to_string(),
+                        format!("{}/{}", record.passed, record.cases),
+                        Progress::format_time(record.last_attempt),
+                        record
+                            .solved_at
+                            .map(Progress::format_time)
+                            .unwrap_or_default(),
+                        record.tags.join(", "),
+                    ]
+                }
+                None => {
+                    *counts.entry("not attempted").or_default() += 1;
+                    vec![String::from(slug), String::new(), String::from("-")]
+                }
+            };
+            rows.push(row);
+        }
+        reporter.table(
+            &[
+                "problem",
+                "difficulty",
+                "status",
+                "attempts",
+                "last result",
+                "last attempt",
+                "solved at",
+                "tags",
+            ],
+            &rows,
+        );
+        let totals: Vec<String> = counts
+            .iter()
+            .map(|(status, count)| format!("{count} {status}"))
+            .collect();
+        reporter.line(&format!(
+            "{} ({})",
+            totals.join(", "),
+            self.m_path.display()
+        ));
+    }
+
+    /**
+     * @return "YYYY-MM-DD HH:MM" (UTC).
+     *
+     * std has no calendar, the date is computed from the
+     * number of days since 1970-01-01 (the civil_from_days
+     * algorithm of Howard Hinnant): the year is shifted to
+     * start in March, so the leap day is the last day of a
+     * year, and the days are split in 400 years eras of
+     * 146097 days.
+     */
+    pub fn format_time(timestamp: u64) -> String {
+        let days: u64 = timestamp / 86400;
+        let secs_of_day: u64 = timestamp % 86400;
+        let shifted: u64 = days + 719468;
+        let era: u64 = shifted / 146097;
+        let day_of_era: u64 = shifted % 146097;
+        let year_of_era: u64 = (day_of_era - day_of_era / 1460
+            + day_of_era / 36524
+            - day_of_era / 146096)
+            / 365;
+        let day_of_year: u64 = day_of_era
+            - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+        let month_from_march: u64 = (5 * day_of_year + 2) / 153;
+        let day: u64 = day_of_year - (153 * month_from_march + 2) / 5 + 1;
+        let month: u64 = if month_from_march < 10 {
+            month_from_march + 3
+        } else {
+            month_from_march - 9
+        };
+        let year: u64 = year_of_era + era * 400 + (month <= 2) as u64;
+        return format!(
+            "{year:04}-{month:02}-{day:02} {:02}:{:02}",
+            secs_of_day / 3600,
+            secs_of_day % 3600 / 60
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::leet_code::judge::{CaseReport, CaseStatus, Judge};
+    use std::time::Duration;
+
+    struct Problem;
+
+    impl Judged for Problem {
+        fn slug(&self) -> &'static str {
+            return "two_sum";
+        }
+
+        fn difficulty(&self) -> Option<Difficulty> {
+            return Some(Difficulty::Easy);
+        }
+
+        fn tags(&self) -> &'static [&'static str] {
+            return &["array", "hash table"];
+        }
+
+        fn judge(&self, _judge: &Judge) -> Result<JudgeReport, RC> {
+            return Ok(report(0, 0));
+        }
+    }
+
+    /** @return a report of cases, the first passed ones. */
+    fn report(passed: usize, cases: usize) -> JudgeReport {
+        let cases: Vec<CaseReport> = (0..cases)
+            .map(|idx| CaseReport {
+                line: idx + 1,
+                input: String::new(),
+                status: if idx < passed {
+                    CaseStatus::Passed
+                } else {
+                    CaseStatus::TimedOut
+                },
+                duration: Duration::ZERO,
+            })
+            .collect();
+        return JudgeReport {
+            slug: "two_sum",
+            cases,
+        };
+    }
+
+    /** @return an empty directory only used by the test. */
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir: PathBuf = std::env::temp_dir()
+            .join(format!("rust_pg_progress_{}_{name}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        return dir;
+    }
+
+    #[test]
+    fn counts_the_attempts_and_tracks_the_status() {
+        let mut progress: Progress =
+            Progress::load(Path::new("missing")).unwrap();
+        let runs: [(usize, usize, ProgressStatus); 5] = [
+            (0, 0, ProgressStatus::Attempted),
+            (1, 3, ProgressStatus::Attempted),
+            (3, 3, ProgressStatus::Solved),
+            (2, 3, ProgressStatus::Regressed),
+            (3, 3, ProgressStatus::Solved),
+        ];
+        for (idx, &(passed, cases, status)) in runs.iter().enumerate() {
+            progress.record(&Problem, &report(passed, cases), 100 + idx as u64);
+            let record: &ProblemRecord = progress.get("two_sum").unwrap();
+            assert_eq!(record.status, status, "run {idx}");
+            assert_eq!(record.attempts, idx as u32 + 1);
+            assert_eq!((record.passed, record.cases), (passed, cases));
+            assert_eq!(record.last_attempt, 100 + idx as u64);
+        }
+        let record: &ProblemRecord = progress.get("two_sum").unwrap();
+        assert_eq!(record.first_attempt, 100);
+        /* The first run all the cases passed. */
+        assert_eq!(record.solved_at, Some(102));
+        assert_eq!(record.difficulty, Some(Difficulty::Easy));
+        assert_eq!(record.tags, ["array", "hash table"]);
+        assert!(progress.get("add_two_nums").is_none());
+    }
+
+    #[test]
+    fn save_and_load_round_trip() {
+        let dir: PathBuf = temp_dir("round_trip");
+        let path: PathBuf = dir.join("nested").join("progress.txt");
+        let mut progress: Progress = Progress::load(&path).unwrap();
+        assert!(progress.get("two_sum").is_none());
+        progress.record(&Problem, &report(3, 3), 1760000000);
+        progress.record(&Problem, &report(1, 3), 1760000060);
+        progress.save().unwrap();
+
+        let loaded: Progress = Progress::load(&path).unwrap();
+        assert_eq!(loaded.path(), path);
+        let record: &ProblemRecord = loaded.get("two_sum").unwrap();
+        assert_eq!(
+            record.to_line(),
+            progress.get("two_sum").unwrap().to_line()
+        );
+        assert_eq!(
+            record.to_line(),
+            "two_sum\tstatus=regressed\tattempts=2\tpassed=1\tcases=3\t\
+             first_attempt=1760000000\tlast_attempt=1760000060\t\
+             solved_at=1760000000\tdifficulty=easy\ttags=array,hash table"
+        );
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn malformed_records_have_the_file_line() {
+        let dir: PathBuf = temp_dir("malformed");
+        std::fs::create_dir_all(&dir).unwrap();
+        let path: PathBuf = dir.join("progress.txt");
+        std::fs::write(
+            &path,
+            "# header\ntwo_sum\tstatus=solved\nadd_two_nums\tattempts=x\n",
+        )
+        .unwrap();
+        let error: String = match Progress::load(&path) {
+            Err(rc) => rc.render(),
+            Ok(_) => panic!("expected a parse error"),
+        };
+        assert!(error.contains(&format!("{}:3", path.display())), "{error}");
+        assert!(error.contains("attempts: "), "{error}");
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn format_time() {
+        assert_eq!(Progress::format_time(0), "1970-01-01 00:00");
+        assert_eq!(Progress::format_time(951782400), "2000-02-29 00:00");
+        assert_eq!(Progress::format_time(1709251199), "2024-02-29 23:59");
+        assert_eq!(Progress::format_time(1760000000), "2025-10-09 08:53");
+        assert_eq!(Progress::format_time(4102444800), "2100-01-01 00:00");
+    }
+}
@@ -0,0 +1,615 @@
+use crate::core::return_code::{ResultExt, RC};
+use std::path::{Path, PathBuf};
+
+/**
+ * The skeleton of a new problem module, in the style of
+ * two_sum.rs. The placeholders are replaced by
+ * Scaffold::render:
+ * - $URL: the problem page.
+ * - $NAME: the module name (two_sum).
+ * - $STRUCT: the unit struct of the registries (TwoSum).
+ * - $TITLE: the problem title (Two sum).
+ *
+ * The solution is a placeholder taking and returning an
+ * i32, so the new module builds before it's implemented.
+ */
+const MODULE_TEMPLATE: &str = r#"use crate::leet_code::literal::ToLiteral;
+use crate::pg_println;
+
+/**
+ * $URL
+ */
+pub struct Solution; //When submitting, remove this line.
+
+impl Solution {
+    /**
+     * TODO: replace the placeholder signature with the one
+     * of the problem (and the types of the Callable and
+     * Problem implementations below).
+     */
+    pub fn $NAME(num: i32) -> i32 {
+        return num;
+    }
+}
+
+pub fn run_example() {
+    let num: i32 = 0;
+    let res: i32 = Solution::$NAME(num);
+    pg_println!("num = {num}, res = {res}");
+}
+
+//_____________________________________________________________________________
+pub struct $STRUCT;
+
+impl crate::core::example::Example for $STRUCT {
+    fn name(&self) -> &'static str {
+        return "$NAME";
+    }
+
+    fn category(&self) -> crate::core::example::Category {
+        return crate::core::example::Category::LeetCode;
+    }
+
+    fn description(&self) -> &'static str {
+        return "$TITLE.";
+    }
+
+    fn run(&self) -> Result<(), crate::core::return_code::RC> {
+        run_example();
+        return Ok(());
+    }
+}
+
+impl crate::core::callable::Callable for $STRUCT {
+    fn name(&self) -> &'static str {
+        return "$NAME";
+    }
+
+    fn signature(&self) -> &'static str {
+        return "num: i32";
+    }
+
+    fn call(&self, args: &str) -> Result<String, crate::core::return_code::RC> {
+        let (num,): (i32,) =
+            crate::leet_code::literal::Literal::parse_args_as(args)?;
+        return Ok(Solution::$NAME(num).to_literal());
+    }
+}
+
+impl crate::leet_code::judge::Problem for $STRUCT {
+    type Input = i32;
+    type Output = i32;
+
+    fn slug(&self) -> &'static str {
+        return "$NAME";
+    }
+
+    fn parse_input(
+        &self,
+        text: &str,
+    ) -> Result<i32, crate::core::return_code::RC> {
+        return crate::leet_code::literal::Literal::parse_as(text);
+    }
+
+    fn parse_output(
+        &self,
+        text: &str,
+    ) -> Result<i32, crate::core::return_code::RC> {
+        return crate::leet_code::literal::Literal::parse_as(text);
+    }
+
+    fn solve(num: i32) -> i32 {
+        return Solution::$NAME(num);
+    }
+}
+"#;
+
+/**
+ * The header of the (empty) judge data file, see
+ * leet_code::judge for its format.
+ */
+const DATA_TEMPLATE: &str = r#"# $URL
+# Each case is an "input:" line followed by an "output:"
+# line, the values are leet code literals.
+"#;
+
+/**
+ * The registration functions of leet_code/mod.rs a new
+ * problem is added to.
+ */
+const REGISTRIES: [&str; 3] = ["examples", "callables", "problems"];
+
+const URL_PREFIX: &str = "https://leetcode.com/problems/";
+
+/**
+ * The names of the leading digits of a slug, e.g. "3sum" is
+ * the module three_sum.
+ */
+const DIGIT_NAMES: [&str; 10] = [
+    "zero", "one", "two", "three", "four", "five", "six", "seven", "eight",
+    "nine",
+];
+
+/**
+ * The rust keywords (strict and reserved), a module cannot
+ * be named after them.
+ */
+const KEYWORDS: [&str; 51] = [
+    "abstract", "as", "async", "await", "become", "box", "break", "const",
+    "continue", "crate", "do", "dyn", "else", "enum", "extern", "false",
+    "final", "fn", "for", "gen", "if", "impl", "in", "let", "loop", "macro",
+    "match", "mod", "move", "mut", "override", "priv", "pub", "ref", "return",
+    "self", "static", "struct", "super", "trait", "true", "try", "type",
+    "typeof", "unsafe", "unsized", "use", "virtual", "where", "while", "yield",
+];
+
+/**
+ * What Scaffold::create did, the paths start with the
+ * directories of the Scaffold.
+ */
+pub struct ScaffoldReport {
+    pub module: String,
+    pub created: Vec<PathBuf>,
+    pub updated: Vec<PathBuf>,
+}
+
+/**
+ * Generates the skeleton of a new leet code problem:
+ * - <src dir>/leet_code/<module>.rs, the module.
+ * - <data dir>/<module>.txt, the (empty) judge cases.
+ * - registers the module in <src dir>/leet_code/mod.rs
+ *   (examples, callables and problems) and its source in
+ *   the notes (<src dir>/core/notes.rs).
+ *
+ * Usage example:
+ * rust_pg new-problem two-sum
+ * creates src/leet_code/two_sum.rs and
+ * data/leet_code/two_sum.txt.
+ */
+pub struct Scaffold {
+    m_src_dir: PathBuf,
+    m_data_dir: PathBuf,
+}
+
+impl Scaffold {
+    pub const DEFAULT_SRC_DIR: &'static str = "src";
+
+    pub fn new(src_dir: &Path, data_dir: &Path) -> Scaffold {
+        return Scaffold {
+            m_src_dir: src_dir.to_path_buf(),
+            m_data_dir: data_dir.to_path_buf(),
+        };
+    }
+
+    /**
+     * @param slug the problem slug as in its URL, e.g.
+     * "two-sum", or the whole URL.
+     * @return RC::CInvalidArgument when the slug is not a
+     * valid module name or the module already exists. No
+     * file is written in this case.
+     */
+    pub fn create(&self, slug: &str) -> Result<ScaffoldReport, RC> {
+        let slug: &str = slug
+            .strip_prefix(URL_PREFIX)
+            .unwrap_or(slug)
+            .trim_matches('/');
+        let module: String = Scaffold::module_name(slug)?;
+        let url: String = format!("{URL_PREFIX}{slug}/");
+        let module_path: PathBuf = self
+            .m_src_dir
+            .join("leet_code")
+            .join(format!("{module}.rs"));
+        let data_path: PathBuf = self.m_data_dir.join(format!("{module}.txt"));
+        for path in [&module_path, &data_path] {
+            if path.exists() {
+                return Err(RC::invalid_argument(
+                    "slug",
+                    &format!("{} already exists", path.display()),
+                ));
+            }
+        }
+
+        /*
+         * All the edits are prepared before writing, so a
+         * mod.rs which cannot be updated leaves the tree as
+         * it was.
+         */
+        let mod_path: PathBuf = self.m_src_dir.join("leet_code").join("mod.rs");
+        let notes_path: PathBuf = self.m_src_dir.join("core").join("notes.rs");
+        let mod_source: String = Scaffold::read(&mod_path)?;
+        let mod_text: String = Scaffold::register_module(&mod_source, &module)
+            .map_err(|rc| rc.context(&format!("{}", mod_path.display())))?;
+        let notes_text: String =
+            Scaffold::register_notes(&Scaffold::read(&notes_path)?, &module)
+                .map_err(|rc| {
+                    rc.context(&format!("{}", notes_path.display()))
+                })?;
+
+        /* The module has the line endings of its siblings. */
+        let module_text: String =
+            Scaffold::render(MODULE_TEMPLATE, &module, &url)
+                .replace('\n', Scaffold::newline(&mod_source));
+        let data_text: String = Scaffold::render(DATA_TEMPLATE, &module, &url);
+        for (path, text) in
+            [(&module_path, module_text), (&data_path, data_text)]
+        {
+            if let Some(parent) = path.parent() {
+                std::fs::create_dir_all(parent)
+                    .context(&format!("creating {}", parent.display()))?;
+            }
+            std::fs::write(path, text)
+                .context(&format!("writing {}", path.display()))?;
+        }
+        std::fs::write(&mod_path, mod_text)
+            .context(&format!("writing {}", mod_path.display()))?;
+        std::fs::write(&notes_path, notes_text)
+            .context(&format!("writing {}", notes_path.display()))?;
+        return Ok(ScaffoldReport {
+            module,
+            created: vec![module_path, data_path],
+            updated: vec![mod_path, notes_path],
+        });
+    }
+
+    /**
+     * "two-sum" -> "two_sum", the slug must be made of
+     * lower case ASCII letters, digits, '-' and '_'.
+     *
+     * A module name cannot start with a digit, so the
+     * leading digits are spelled: "3sum" -> "three_sum",
+     * "01-matrix" -> "zero_one_matrix".
+     * @return RC::CInvalidArgument for another slug, or when
+     * the module name would be a rust keyword ("type").
+     */
+    fn module_name(slug: &str) -> Result<String, RC> {
+        let valid: bool = slug
+            .starts_with(|ch: char| ch.is_ascii_alphanumeric())
+            && slug.chars().all(|ch| {
+                ch.is_ascii_lowercase()
+                    || ch.is_ascii_digit()
+                    || ch == '-'
+                    || ch == '_'
+            });
+        if !valid {
+            return Err(RC::invalid_argument(
+                "slug",
+                &format!(
+                    "'{slug}' is not made of lower case letters, digits \
+                     and '-'"
+                ),
+            ));
+        }
+        let rest: &str =
+            slug.trim_start_matches(|ch: char| ch.is_ascii_digit());
+        let mut words: Vec<&str> = slug[..slug.len() - rest.len()]
+            .bytes()
+            .map(|digit| DIGIT_NAMES[(digit - b'0') as usize])
+            .collect();
+        let rest: &str = rest.trim_start_matches(['-', '_']);
+        if !rest.is_empty() {
+            words.push(rest);
+        }
+        let module: String = words.join("_").replace('-', "_");
+        if KEYWORDS.contains(&module.as_str()) {
+            return Err(RC::invalid_argument(
+                "slug",
+                &format!("'{module}' is a rust keyword"),
+            ));
+        }
+        return Ok(module);
+    }
+
+    /**
+     * "two_sum" -> "TwoSum".
+     */
+    fn struct_name(module: &str) -> String {
+        return module
+            .split('_')
+            .filter(|word| !word.is_empty())
+            .map(|word| {
+                let mut chars: std::str::Chars = word.chars();
+                let first: String = chars
+                    .next()
+                    .map(|ch| ch.to_ascii_uppercase().to_string())
+                    .unwrap_or_default();
+                return first + chars.as_str();
+            })
+            .collect();
+    }
+
+    /**
+     * "two_sum" -> "Two sum".
+     */
+    fn title(module: &str) -> String {
+        let words: String = module.replace('_', " ");
+        let mut chars: std::str::Chars = words.chars();
+        return match chars.next() {
+            Some(first) => {
+                first.to_ascii_uppercase().to_string() + chars.as_str()
+            }
+            None => words,
+        };
+    }
+
+    fn render(template: &str, module: &str, url: &str) -> String {
+        return template
+            .replace("$URL", url)
+            .replace("$NAME", module)
+            .replace("$STRUCT", &Scaffold::struct_name(module))
+            .replace("$TITLE", &Scaffold::title(module));
+    }
+
+    fn read(path: &Path) -> Result<String, RC> {
+        return std::fs::read_to_string(path)
+            .context(&format!("reading {}", path.display()));
+    }
+
+    /**
+     * Adds "pub mod <module>;" (the declarations are kept
+     * sorted) and the unit struct of the module at the end
+     * of each registration function of mod.rs.
+     */
+    fn register_module(text: &str, module: &str) -> Result<String, RC> {
+        let mut lines: Vec<String> = text.lines().map(String::from).collect();
+        let declaration: String = format!("pub mod {module};");
+        if lines.contains(&declaration) {
+            return Err(RC::invalid_argument(
+                "slug",
+                &format!("{module} is already declared"),
+            ));
+        }
+        let mods: Vec<usize> = lines
+            .iter()
+            .enumerate()
+            .filter(|(_, line)| line.starts_with("pub mod "))
+            .map(|(idx, _)| idx)
+            .collect();
+        let idx: usize =
+            match mods.iter().find(|&&idx| lines[idx] > declaration) {
+                Some(&idx) => idx,
+                None => mods.last().map_or(0, |idx| idx + 1),
+            };
+        lines.insert(idx, declaration);
+
+        let entry: String = format!(
+            "        Box::new({module}::{}),",
+            Scaffold::struct_name(module)
+        );
+        for registry in REGISTRIES {
+            Scaffold::insert_before_close(
+                &mut lines,
+                &format!("pub fn {registry}("),
+                "    ];",
+                entry.clone(),
+            )?;
+        }
+        return Ok(Scaffold::join(&lines, text));
+    }
+
+    /**
+     * Adds the source of the module at the end of the notes
+     * SOURCES, formatted as rustfmt would.
+     */
+    fn register_notes(text: &str, module: &str) -> Result<String, RC> {
+        let mut lines: Vec<String> = text.lines().map(String::from).collect();
+        let file: String = format!("\"src/leet_code/{module}.rs\"");
+        let source: String =
+            format!("include_str!(\"../leet_code/{module}.rs\")");
+        let one_line: String = format!("    ({file}, {source}),");
+        if one_line.len() <= 80 {
+            Scaffold::insert_before_close(
+                &mut lines,
+                "const SOURCES:",
+                "];",
+                one_line,
+            )?;
+        } else {
+            for entry in [
+                String::from("    ("),
+                format!("        {file},"),
+                format!("        {source},"),
+                String::from("    ),"),
+            ] {
+                Scaffold::insert_before_close(
+                    &mut lines,
+                    "const SOURCES:",
+                    "];",
+                    entry,
+                )?;
+            }
+        }
+        return Ok(Scaffold::join(&lines, text));
+    }
+
+    /**
+     * @return the line ending of text ("\r\n" or "\n"), the
+     * one of its first line.
+     */
+    fn newline(text: &str) -> &'static str {
+        match text.find('\n') {
+            Some(idx) if text[..idx].ends_with('\r') => return "\r\n",
+            _ => return "\n",
+        }
+    }
+
+    /**
+     * Joins the edited lines of text, with the line ending of
+     * text and its final line ending (if any), so an edit
+     * does not rewrite the whole file.
+     */
+    fn join(lines: &[String], text: &str) -> String {
+        let newline: &str = Scaffold::newline(text);
+        let mut res: String = lines.join(newline);
+        if text.ends_with('\n') {
+            res.push_str(newline);
+        }
+        return res;
+    }
+
+    /**
+     * Inserts line before the first close line following
+     * the line starting with start.
+     * @return RC::CNotFound when there is no such line.
+     */
+    fn insert_before_close(
+        lines: &mut Vec<String>,
+        start: &str,
+        close: &str,
+        line: String,
+    ) -> Result<(), RC> {
+        let start_idx: usize = lines
+            .iter()
+            .position(|line| line.starts_with(start))
+            .ok_or_else(|| RC::not_found(&format!("'{start}'")))?;
+        let close_idx: usize = lines[start_idx..]
+            .iter()
+            .position(|line| line == close)
+            .ok_or_else(|| {
+                RC::not_found(&format!("the '{close}' closing '{start}'"))
+            })?;
+        lines.insert(start_idx + close_idx, line);
+        return Ok(());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MOD_RS: &str = "pub mod add_two_nums;
+pub mod two_sum;
+
+pub fn examples() -> Vec<Box<dyn Example>> {
+    return vec![
+        Box::new(two_sum::TwoSum),
+    ];
+}
+
+pub fn callables() -> Vec<Box<dyn Callable>> {
+    return vec![
+        Box::new(two_sum::TwoSum),
+    ];
+}
+
+pub fn problems() -> Vec<Box<dyn judge::Judged>> {
+    return vec![
+    ];
+}
+";
+
+    const NOTES_RS: &str = "const SOURCES: &[(&str, &str)] = &[
+    (\"src/main.rs\", include_str!(\"../main.rs\")),
+];
+";
+
+    /** @return the module name of slug, or the error reason. */
+    fn module_name(slug: &str) -> String {
+        match Scaffold::module_name(slug) {
+            Ok(module) => return module,
+            Err(RC::CInvalidArgument { reason, .. }) => return reason,
+            Err(rc) => panic!("unexpected error {rc}"),
+        }
+    }
+
+    #[test]
+    fn module_names() {
+        assert_eq!(module_name("two-sum"), "two_sum");
+        assert_eq!(module_name("two_sum-ii"), "two_sum_ii");
+        assert_eq!(module_name("3sum"), "three_sum");
+        assert_eq!(module_name("3sum-closest"), "three_sum_closest");
+        assert_eq!(module_name("01-matrix"), "zero_one_matrix");
+        assert_eq!(module_name("132-pattern"), "one_three_two_pattern");
+        assert_eq!(module_name("type"), "'type' is a rust keyword");
+        assert_eq!(module_name("self"), "'self' is a rust keyword");
+        /* A contextual keyword is a valid module name. */
+        assert_eq!(module_name("union"), "union");
+        for slug in ["", "Two-sum", "-sum", "two sum", "two/sum", "é"] {
+            assert!(module_name(slug).contains("is not made of"), "{slug}");
+        }
+    }
+
+    #[test]
+    fn struct_names_and_titles() {
+        assert_eq!(Scaffold::struct_name("two_sum"), "TwoSum");
+        assert_eq!(Scaffold::struct_name("two_sum_ii"), "TwoSumIi");
+        assert_eq!(Scaffold::struct_name("three_sum_2"), "ThreeSum2");
+        assert_eq!(Scaffold::struct_name("a__b"), "AB");
+        assert_eq!(Scaffold::title("two_sum"), "Two sum");
+    }
+
+    #[test]
+    fn render() {
+        let text: String = Scaffold::render(
+            "$URL $NAME $STRUCT $TITLE $NAME",
+            "three_sum",
+            "https://leetcode.com/problems/3sum/",
+        );
+        assert_eq!(
+            text,
+            "https://leetcode.com/problems/3sum/ three_sum ThreeSum \
+             Three sum three_sum"
+        );
+        let module: String = Scaffold::render(MODULE_TEMPLATE, "x_y", "url");
+        assert!(!module.contains('$'));
+        assert!(module.contains("pub struct XY;"));
+        assert!(module.contains("pub fn x_y(num: i32) -> i32 {"));
+    }
+
+    #[test]
+    fn register_module() {
+        let text: String =
+            Scaffold::register_module(MOD_RS, "three_sum").unwrap();
+        let entry: &str = "        Box::new(three_sum::ThreeSum),\n    ];";
+        let expected: String = MOD_RS
+            .replace("pub mod two_sum;", "pub mod three_sum;\npub mod two_sum;")
+            .replace("    ];", entry);
+        assert_eq!(text, expected);
+        /* Sorted, or after the last declaration. */
+        let text: String = Scaffold::register_module(MOD_RS, "zigzag").unwrap();
+        assert!(text.starts_with(
+            "pub mod add_two_nums;\npub mod two_sum;\n\
+                                  pub mod zigzag;\n\n"
+        ));
+        let res: Result<String, RC> =
+            Scaffold::register_module(MOD_RS, "two_sum");
+        assert!(matches!(res, Err(RC::CInvalidArgument { .. })));
+        let res: Result<String, RC> =
+            Scaffold::register_module("pub mod two_sum;\n", "three_sum");
+        assert!(matches!(res, Err(RC::CNotFound { .. })));
+    }
+
+    #[test]
+    fn register_notes() {
+        let text: String =
+            Scaffold::register_notes(NOTES_RS, "three_sum").unwrap();
+        let entry: &str = "    (\"src/leet_code/three_sum.rs\", \
+                           include_str!(\"../leet_code/three_sum.rs\")),\n];";
+        assert_eq!(text, NOTES_RS.replace("];", entry));
+        /* Too long for a single line. */
+        let module: &str = "maximum_length_of_repeated_subarray";
+        let text: String = Scaffold::register_notes(NOTES_RS, module).unwrap();
+        assert!(text.contains(&format!(
+            "    (\n        \"src/leet_code/{module}.rs\",\n"
+        )));
+    }
+
+    #[test]
+    fn keeps_the_line_endings() {
+        let crlf: String = MOD_RS.replace('\n', "\r\n");
+        let text: String =
+            Scaffold::register_module(&crlf, "three_sum").unwrap();
+        assert_eq!(
+            text,
+            Scaffold::register_module(MOD_RS, "three_sum")
+                .unwrap()
+                .replace('\n', "\r\n")
+        );
+        /* No final line ending is added. */
+        let text: String =
+            Scaffold::register_notes(NOTES_RS.trim_end(), "three_sum").unwrap();
+        assert!(text.ends_with("\n];"));
+        assert_eq!(Scaffold::newline("a\r\nb\n"), "\r\n");
+        assert_eq!(Scaffold::newline("a\nb\r\n"), "\n");
+        assert_eq!(Scaffold::newline("a"), "\n");
+    }
+}
@@ -1,26 +1,234 @@
+use crate::leet_code::literal::ToLiteral;
+use crate::pg_assert;
+use crate::pg_assert_eq;
+use crate::pg_println;
+
 /**
  * https://leetcode.com/problems/two-sum/
  *
- *
+ * The problem has three solutions with different time and
+ * memory trade-offs, a Strategy selects one of them:
+ * - SquareTime: for each number, search its complement in
+ *   the rest of the vector. O(n^2) time, O(1) memory.
+ * - HashMap: a single pass, remembering the index of each
+ *   number seen so far. O(n) time, O(n) memory.
+ * - SortTwoPointers: sort the indexes by their numbers and
+ *   move two pointers from both ends towards each other.
+ *   O(n log n) time, O(n) memory (the sorted indexes keep
+ *   the original indexes).
  */
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Strategy {
+    SquareTime,
+    HashMap,
+    SortTwoPointers,
+}
 
-pub struct Solution; //When submitting, remove this line.
+impl Strategy {
+    pub fn all() -> [Strategy; 3] {
+        return [
+            Strategy::SquareTime,
+            Strategy::HashMap,
+            Strategy::SortTwoPointers,
+        ];
+    }
 
-enum RC {
-    CNotFound,
-    CNoSolution,
+    pub fn as_str(&self) -> &'static str {
+        match *self {
+            Strategy::SquareTime => "square_time",
+            Strategy::HashMap => "hash_map",
+            Strategy::SortTwoPointers => "sort_two_pointers",
+        }
+    }
+
+    /**
+     * The time complexity claimed above, checked by
+     * rust_pg complexity two_sum_<strategy>.
+     */
+    pub fn time_complexity(&self) -> crate::core::complexity::Growth {
+        match *self {
+            Strategy::SquareTime => crate::core::complexity::Growth::Quadratic,
+            Strategy::HashMap => crate::core::complexity::Growth::Linear,
+            Strategy::SortTwoPointers => {
+                crate::core::complexity::Growth::Linearithmic
+            }
+        }
+    }
 }
 
+/**
+ * The solution found by each strategy, None when it found
+ * none.
+ */
+pub type StrategySolutions = Vec<(Strategy, Option<Vec<i32>>)>;
+
+pub struct Solution; //When submitting, remove this line.
+
+/*
+ * When submitting, replace the crate's RC with a local
+ * enum, leet code accepts a single file.
+ */
+use crate::core::return_code::RC;
+
 impl Solution {
     pub fn two_sum(nums: Vec<i32>, target: i32) -> Vec<i32> {
         let res: Result<Vec<i32>, RC> =
-            Solution::two_sum_square_time_complexity(&nums, target);
+            Solution::two_sum_with(&nums, target, Strategy::SquareTime);
         match res {
             Ok(two_sum_vec) => return two_sum_vec,
             Err(_rc) => return Vec::from([-1 as i32, -1 as i32]),
         }
     }
 
+    /**
+     * @return the two indexes (ascending) of a solution
+     * found by the given strategy, RC::CNoSolution when
+     * there is none.
+     */
+    pub fn two_sum_with(
+        nums: &[i32],
+        target: i32,
+        strategy: Strategy,
+    ) -> Result<Vec<i32>, RC> {
+        match strategy {
+            Strategy::SquareTime => {
+                return Solution::two_sum_square_time_complexity(nums, target);
+            }
+            Strategy::HashMap => {
+                return Solution::two_sum_linear_time_complexity(nums, target);
+            }
+            Strategy::SortTwoPointers => {
+                return Solution::two_sum_two_pointers(nums, target);
+            }
+        }
+    }
+
+    /**
+     * Runs all the strategies and checks they agree: either
+     * none of them finds a solution, or each of them returns
+     * a valid one (two different indexes whose numbers add
+     * up to target). When there are several solutions the
+     * strategies may find different ones.
+     * @return the solution of each strategy, or
+     * RC::CMismatch when the strategies disagree.
+     */
+    pub fn cross_check(
+        nums: &[i32],
+        target: i32,
+    ) -> Result<StrategySolutions, RC> {
+        let mut res: StrategySolutions = Vec::new();
+        for strategy in Strategy::all() {
+            match Solution::two_sum_with(nums, target, strategy) {
+                Ok(indexes) => {
+                    if !Solution::is_solution(nums, target, &indexes) {
+                        return Err(RC::mismatch(
+                            "a valid solution",
+                            &format!(
+                                "{} returned {:?}",
+                                strategy.as_str(),
+                                indexes
+                            ),
+                        ));
+                    }
+                    res.push((strategy, Some(indexes)));
+                }
+                Err(RC::CNoSolution) => res.push((strategy, None)),
+                Err(rc) => return Err(rc),
+            }
+        }
+        let (first_strategy, first_indexes) = &res[0];
+        for (strategy, indexes) in res.iter() {
+            if indexes.is_some() != first_indexes.is_some() {
+                return Err(RC::mismatch(
+                    &format!("{} {:?}", first_strategy.as_str(), first_indexes),
+                    &format!("{} {:?}", strategy.as_str(), indexes),
+                ));
+            }
+        }
+        return Ok(res);
+    }
+
+    /**
+     * @return true when indexes are two different valid
+     * indexes whose numbers add up to target.
+     */
+    fn is_solution(nums: &[i32], target: i32, indexes: &[i32]) -> bool {
+        if let [idx1, idx2] = *indexes {
+            let (idx1, idx2): (usize, usize) = (idx1 as usize, idx2 as usize);
+            return idx1 != idx2
+                && idx1 < nums.len()
+                && idx2 < nums.len()
+                && nums[idx1] as i64 + nums[idx2] as i64 == target as i64;
+        }
+        return false;
+    }
+
+    /**
+     * A Vec.len time complexity solution.
+     *
+     * The map holds the index of each number seen so far,
+     * so the complement of the current number is found in
+     * O(1) (amortized) instead of searching the vector.
+     * Note: a number is inserted after searching its
+     * complement, so an element is not used twice.
+     */
+    fn two_sum_linear_time_complexity(
+        nums: &[i32],
+        target: i32,
+    ) -> Result<Vec<i32>, RC> {
+        let mut seen: std::collections::HashMap<i32, usize> =
+            std::collections::HashMap::with_capacity(nums.len());
+        for (idx, &elem) in nums.iter().enumerate() {
+            /* i64, so target - elem cannot overflow. */
+            let complement: i64 = target as i64 - elem as i64;
+            if let Ok(complement) = i32::try_from(complement) {
+                if let Some(&complement_idx) = seen.get(&complement) {
+                    return Ok(Vec::from([complement_idx as i32, idx as i32]));
+                }
+            }
+            /* Keep the first index of duplicated numbers. */
+            seen.entry(elem).or_insert(idx);
+        }
+        return Err(RC::CNoSolution);
+    }
+
+    /**
+     * A Vec.len * log(Vec.len) time complexity solution.
+     *
+     * The indexes (not the numbers) are sorted by their
+     * numbers, so the original indexes are kept. The
+     * pointers start at the smallest and the largest
+     * numbers: when the sum is too small the low pointer
+     * moves up, when it's too large the high pointer moves
+     * down.
+     */
+    fn two_sum_two_pointers(nums: &[i32], target: i32) -> Result<Vec<i32>, RC> {
+        if nums.len() < 2 {
+            return Err(RC::CNoSolution);
+        }
+        let mut sorted_idxs: Vec<usize> = (0..nums.len()).collect();
+        sorted_idxs.sort_by_key(|&idx| nums[idx]);
+        let mut low: usize = 0;
+        let mut high: usize = sorted_idxs.len() - 1;
+        while low < high {
+            let sum: i64 =
+                nums[sorted_idxs[low]] as i64 + nums[sorted_idxs[high]] as i64;
+            match sum.cmp(&(target as i64)) {
+                std::cmp::Ordering::Less => low += 1,
+                std::cmp::Ordering::Greater => high -= 1,
+                std::cmp::Ordering::Equal => {
+                    let idx1: usize = sorted_idxs[low];
+                    let idx2: usize = sorted_idxs[high];
+                    return Ok(Vec::from([
+                        std::cmp::min(idx1, idx2) as i32,
+                        std::cmp::max(idx1, idx2) as i32,
+                    ]));
+                }
+            }
+        }
+        return Err(RC::CNoSolution);
+    }
+
     /**
      * A Vec.len^2 time complexity solution.
      * @param nums the vector passed as a slice.
@@ -35,8 +243,14 @@ impl Solution {
         target: i32,
     ) -> Result<Vec<i32>, RC> {
         for (idx, &elem) in nums.iter().enumerate() {
+            /*
+             * i64, target - elem overflows an i32 for e.g.
+             * target = i32::MIN and elem = 1 (found by
+             * rust_pg fuzz two_sum).
+             */
+            let complement: i64 = target as i64 - elem as i64;
             if let Ok(complement_idx) =
-                Solution::search_complement(&nums, idx, target - elem)
+                Solution::search_complement(&nums, idx, complement)
             {
                 let res_vec: Vec<i32> =
                     Vec::from([idx as i32, complement_idx as i32]);
@@ -57,13 +271,303 @@ impl Solution {
     fn search_complement(
         nums: &[i32],
         forbidden_idx: usize,
-        target: i32,
+        target: i64,
     ) -> Result<usize, RC> {
         for (idx, &elem) in nums.iter().enumerate() {
-            if idx != forbidden_idx && elem == target {
+            if idx != forbidden_idx && elem as i64 == target {
                 return Ok(idx);
             }
         }
-        return Err(RC::CNotFound);
+        return Err(RC::not_found("complement"));
+    }
+}
+
+pub fn run_example() {
+    let nums: Vec<i32> = Vec::from([1, 2, 4]);
+    let target: i32 = 6;
+    let indexes: Vec<i32> = Solution::two_sum(nums.clone(), target);
+    pg_println!(
+        "nums = {:?}, target = {target}, indexes = {:?}",
+        nums,
+        indexes
+    );
+
+    /*
+     * All the strategies must agree, (nums, target, has a
+     * solution).
+     */
+    let cases: [(&[i32], i32, bool); 8] = [
+        (&[2, 7, 11, 15], 9, true),
+        /* Duplicates, a number cannot be used twice. */
+        (&[3, 3], 6, true),
+        (&[3, 2, 4], 6, true),
+        (&[5, 1, 5, 5], 10, true),
+        /* Negatives. */
+        (&[-1, -2, -3, -4, -5], -8, true),
+        (&[-3, 4, 3, 90], 0, true),
+        /* No solution. */
+        (&[1, 2, 3], 7, false),
+        (&[4], 8, false),
+    ];
+    for (nums, target, has_solution) in cases {
+        let res: Result<StrategySolutions, RC> =
+            Solution::cross_check(nums, target);
+        pg_assert!(res.is_ok());
+        let solutions: Vec<String> = res
+            .unwrap_or_default()
+            .into_iter()
+            .map(|(strategy, indexes)| {
+                pg_assert_eq!(indexes.is_some(), has_solution);
+                let indexes: String = match indexes {
+                    Some(indexes) => indexes.to_literal(),
+                    None => String::from("none"),
+                };
+                return format!("{} {indexes}", strategy.as_str());
+            })
+            .collect();
+        pg_println!("{:?} {target}: {}", nums, solutions.join(", "));
+    }
+}
+
+//_____________________________________________________________________________
+pub struct TwoSum;
+
+impl crate::core::example::Example for TwoSum {
+    fn name(&self) -> &'static str {
+        return "two_sum";
+    }
+
+    fn category(&self) -> crate::core::example::Category {
+        return crate::core::example::Category::LeetCode;
+    }
+
+    fn description(&self) -> &'static str {
+        return "Indexes of the two numbers which add up to a target.";
+    }
+
+    fn run(&self) -> Result<(), crate::core::return_code::RC> {
+        run_example();
+        return Ok(());
+    }
+}
+
+impl crate::core::callable::Callable for TwoSum {
+    fn name(&self) -> &'static str {
+        return "two_sum";
+    }
+
+    fn signature(&self) -> &'static str {
+        return "nums: [i32], target: i32";
+    }
+
+    fn call(&self, args: &str) -> Result<String, crate::core::return_code::RC> {
+        let (nums, target): (Vec<i32>, i32) =
+            crate::leet_code::literal::Literal::parse_args_as(args)?;
+        return Ok(Solution::two_sum(nums, target).to_literal());
+    }
+}
+
+impl crate::leet_code::judge::Problem for TwoSum {
+    type Input = (Vec<i32>, i32);
+    type Output = Vec<i32>;
+
+    fn slug(&self) -> &'static str {
+        return "two_sum";
+    }
+
+    fn parse_input(
+        &self,
+        text: &str,
+    ) -> Result<(Vec<i32>, i32), crate::core::return_code::RC> {
+        return crate::leet_code::literal::Literal::parse_args_as(text);
+    }
+
+    fn parse_output(
+        &self,
+        text: &str,
+    ) -> Result<Vec<i32>, crate::core::return_code::RC> {
+        return crate::leet_code::literal::Literal::parse_as(text);
+    }
+
+    fn solve((nums, target): (Vec<i32>, i32)) -> Vec<i32> {
+        return Solution::two_sum(nums, target);
+    }
+
+    fn difficulty(&self) -> Option<crate::leet_code::judge::Difficulty> {
+        return Some(crate::leet_code::judge::Difficulty::Easy);
+    }
+
+    fn tags(&self) -> &'static [&'static str] {
+        return &["array", "hash-table"];
+    }
+}
+
+/**
+ * The reference only tells whether a solution exists
+ * (checking all the pairs), the candidate runs all the
+ * strategies (Solution::cross_check()), which also checks
+ * each returned solution is valid.
+ */
+impl crate::core::fuzz::Differential for TwoSum {
+    type Input = (Vec<i32>, i32);
+    type Output = String;
+
+    fn name(&self) -> &'static str {
+        return "two_sum";
+    }
+
+    fn reference((nums, target): (Vec<i32>, i32)) -> String {
+        for idx1 in 0..nums.len() {
+            for idx2 in idx1 + 1..nums.len() {
+                if nums[idx1] as i64 + nums[idx2] as i64 == target as i64 {
+                    return String::from("solution");
+                }
+            }
+        }
+        return String::from("none");
+    }
+
+    fn candidate((nums, target): (Vec<i32>, i32)) -> String {
+        match Solution::cross_check(&nums, target) {
+            Ok(solutions) => match solutions[0].1 {
+                Some(_) => return String::from("solution"),
+                None => return String::from("none"),
+            },
+            Err(rc) => return rc.to_string(),
+        }
+    }
+}
+
+/**
+ * A strategy of two sum as a complexity target.
+ */
+pub struct TwoSumComplexity(pub Strategy);
+
+impl crate::core::complexity::Scalable for TwoSumComplexity {
+    type Input = Vec<i32>;
+
+    fn name(&self) -> &'static str {
+        match self.0 {
+            Strategy::SquareTime => "two_sum_square_time",
+            Strategy::HashMap => "two_sum_hash_map",
+            Strategy::SortTwoPointers => "two_sum_sort_two_pointers",
+        }
+    }
+
+    fn claimed(&self) -> crate::core::complexity::Growth {
+        return self.0.time_complexity();
+    }
+
+    /**
+     * Even numbers, the target (1) is odd, so there is no
+     * solution and the whole vector is searched.
+     */
+    fn input(&self, size: usize, rng: &mut crate::core::fuzz::Rng) -> Vec<i32> {
+        return (0..size)
+            .map(|_| 2 * rng.range_i128(-(1 << 29), 1 << 29) as i32)
+            .collect();
+    }
+
+    fn run(&self, nums: Vec<i32>) {
+        std::hint::black_box(Solution::two_sum_with(&nums, 1, self.0)).ok();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::fuzz::Rng;
+
+    /** @return the solution of each strategy. */
+    fn solve_all(nums: &[i32], target: i32) -> Vec<Result<Vec<i32>, RC>> {
+        return Strategy::all()
+            .iter()
+            .map(|&strategy| Solution::two_sum_with(nums, target, strategy))
+            .collect();
+    }
+
+    fn has_solution(nums: &[i32], target: i32) -> bool {
+        for idx1 in 0..nums.len() {
+            for idx2 in idx1 + 1..nums.len() {
+                if nums[idx1] as i64 + nums[idx2] as i64 == target as i64 {
+                    return true;
+                }
+            }
+        }
+        return false;
+    }
+
+    #[test]
+    fn problem_example() {
+        for res in solve_all(&[2, 7, 11, 15], 9) {
+            assert_eq!(res.unwrap(), [0, 1]);
+        }
+        assert_eq!(Solution::two_sum(vec![3, 2, 4], 6), [1, 2]);
+    }
+
+    #[test]
+    fn no_solution() {
+        for (nums, target) in [
+            (&[][..], 0),
+            (&[4][..], 8),
+            (&[1, 2, 3][..], 7),
+            (&[i32::MAX, 1][..], i32::MIN),
+        ] {
+            for res in solve_all(nums, target) {
+                assert!(matches!(res, Err(RC::CNoSolution)), "{nums:?}");
+            }
+            assert_eq!(Solution::two_sum(nums.to_vec(), target), [-1, -1]);
+        }
+    }
+
+    /** A number cannot be used twice. */
+    #[test]
+    fn duplicates() {
+        for res in solve_all(&[3, 3], 6) {
+            assert_eq!(res.unwrap(), [0, 1]);
+        }
+        for res in solve_all(&[3, 2, 4], 6) {
+            assert_eq!(res.unwrap(), [1, 2]);
+        }
+        for res in solve_all(&[5, 1, 5, 5], 10) {
+            let indexes: Vec<i32> = res.unwrap();
+            assert!(Solution::is_solution(&[5, 1, 5, 5], 10, &indexes));
+        }
+    }
+
+    #[test]
+    fn negatives() {
+        for res in solve_all(&[-1, -2, -3, -4, -5], -8) {
+            assert_eq!(res.unwrap(), [2, 4]);
+        }
+        for res in solve_all(&[-3, 4, 3, 90], 0) {
+            assert_eq!(res.unwrap(), [0, 2]);
+        }
+        /* target - num overflows an i32. */
+        for res in solve_all(&[1, i32::MIN, -1], i32::MIN + 1) {
+            assert_eq!(res.unwrap(), [0, 1]);
+        }
+    }
+
+    #[test]
+    fn all_strategies_agree() {
+        let mut rng: Rng = Rng::new(14);
+        for _ in 0..2000 {
+            let len: usize = rng.below(12) as usize;
+            let nums: Vec<i32> =
+                (0..len).map(|_| rng.range_i128(-20, 20) as i32).collect();
+            let target: i32 = rng.range_i128(-40, 40) as i32;
+            let solutions: StrategySolutions =
+                Solution::cross_check(&nums, target).unwrap();
+            assert_eq!(solutions.len(), Strategy::all().len());
+            for (strategy, indexes) in solutions {
+                assert_eq!(
+                    indexes.is_some(),
+                    has_solution(&nums, target),
+                    "{} {nums:?} {target}",
+                    strategy.as_str()
+                );
+            }
+        }
     }
 }
@@ -174,39 +174,41 @@
  *
  */
 
-fn main() {
-    rust_pg::leet_code::calc_num_len::run_example();
-    rust_pg::leet_code::get_max_digit::run_example();
-
-    // rust_pg::examples::first_steps::FirstSteps::run_example();
-    // rust_pg::examples::ownership::pg_ownership();
-    // rust_pg::examples::slices::PgSlices::example();
-    // rust_pg::examples::pointers::pg_pointers();
-    // rust_pg::examples::unsafe_func::pg_unsafe_functions();
-    // rust_pg::examples::first_word_len::pg_first_word_len_example();
-    // rust_pg::examples::get_nth_word::PgGetNthWord::example();
-    // rust_pg::examples::bubble_sort::BubbleSort::example();
-    // rust_pg::examples::trait_impl::TraitImpl::run_example();
-    // rust_pg::examples::vector::PgVector::run_example();
-    // rust_pg::examples::turbo_fish::PgTurboFish::run_example();
-    // rust_pg::examples::concurrency::ConcExample::run_example();
-    // rust_pg::examples::deref::DerefExample::run_example();
-    // rust_pg::examples::my_option::MyOptionExample::run_example();
-    // rust_pg::examples::matches::MatchExample::run_example();
+/**
+ * Counts the allocations when "run --stats" is used, see
+ * rust_pg::core::alloc_stats.
+ */
+#[global_allocator]
+static GLOBAL: rust_pg::core::alloc_stats::CountingAllocator =
+    rust_pg::core::alloc_stats::CountingAllocator;
 
-    // /* Needs to be extended */
-    // rust_pg::examples::core_cell::CoreCellExample::run_example();
+/*
+ * The examples are not called from here anymore, each one
+ * implements rust_pg::core::example::Example and is
+ * registered in the examples() function of its directory
+ * mod.rs. Run "rust_pg list" to see them and
+ * "rust_pg run <name>" to run one.
+ */
 
-    /*
-     * TODO remove it when I know how to create libraries
-     * without struct instantiation.
-     */
-    // rust_pg::leet_code::two_sum::Solution::two_sum(
-    //     Vec::from([1, 2, 4]),
-    //     6,
-    // );
+/**
+ * main is fallible: the runner returns a Result, and the
+ * error (if any) is rendered on stderr with its chain of
+ * causes. The process exit code is taken from the error,
+ * see RC::exit_code() for the documented codes.
+ *
+ * Note: main could return Result<(), RC> directly, but
+ * then rust prints the error with {:?} and always exits
+ * with 1. Returning ExitCode gives control over both.
+ */
+fn main() -> std::process::ExitCode {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let res: Result<(), rust_pg::core::return_code::RC> =
+        rust_pg::core::cli::Cli::run(&args);
+    if let Err(rc) = res {
+        eprint!("{}", rc.render());
+        return std::process::ExitCode::from(rc.exit_code());
+    }
 
-    //rust_pg::leet_code::add_two_nums::TestSolution::test_solution();
     /*
      * Future topics
      * 1: std::Option https://doc.rust-lang.org/std/option/
@@ -228,4 +230,5 @@ fn main() {
 
     /* option cannot be dereferenced. */
     /* reference to option can be dereferenced. */
+    return std::process::ExitCode::SUCCESS;
 }